@@ -33,7 +33,7 @@ namespace AccelerationTest {
         }
 
         public static async Task InitializePositionComponentOnAccelerationEntity(Framework framework) {
-            var initializeComponent = await Bolt.World.InitializeComponent(framework.Wallet.Account.PublicKey, framework.AccelerationEntityPda, framework.ExampleComponentPosition);
+            var initializeComponent = await Bolt.World.InitializeComponent(framework.WorldPda, framework.Wallet.Account.PublicKey, framework.AccelerationEntityPda, framework.ExampleComponentPosition);
             framework.AccelerationComponentPositionPda = initializeComponent.Pda;
             await framework.SendAndConfirmInstruction(initializeComponent.Instruction);
         }
@@ -53,6 +53,8 @@ namespace AccelerationTest {
                 var instruction = WorldProgram.Apply(apply, Bolt.World.SerializeArgs(new { direction = "Up" }));
                 instruction.Keys.Add(AccountMeta.ReadOnly(framework.ExampleComponentPosition, false));
                 instruction.Keys.Add(AccountMeta.Writable(framework.AccelerationComponentPositionPda, false));
+                instruction.Keys.Add(AccountMeta.ReadOnly(new PublicKey(WorldProgram.ID), false));
+                instruction.Keys.Add(AccountMeta.ReadOnly(WorldProgram.FindCpiAuthPda(framework.ExampleComponentPosition), false));
                 await framework.SendAndConfirmInstruction(framework.AcceleratorClient, instruction);
                 await Task.Delay(50);
             }
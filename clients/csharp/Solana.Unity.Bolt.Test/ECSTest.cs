@@ -74,25 +74,25 @@ namespace ECSTest {
         }
 
         public static async Task InitializeVelocityComponentOnEntity1WithSeed(Framework framework) {
-            var initializeComponent = await Bolt.World.InitializeComponent(framework.Wallet.Account.PublicKey, framework.Entity1Pda, framework.ExampleComponentVelocity, "component-velocity", framework.Wallet.Account.PublicKey);
+            var initializeComponent = await Bolt.World.InitializeComponent(framework.WorldPda, framework.Wallet.Account.PublicKey, framework.Entity1Pda, framework.ExampleComponentVelocity, "component-velocity", framework.Wallet.Account.PublicKey);
             framework.ComponentVelocityEntity1Pda = initializeComponent.Pda;
             await framework.SendAndConfirmInstruction(initializeComponent.Instruction);
         }
 
         public static async Task InitializePositionComponentOnEntity1(Framework framework) {
-            var initializeComponent = await Bolt.World.InitializeComponent(framework.Wallet.Account.PublicKey, framework.Entity1Pda, framework.ExampleComponentPosition);
+            var initializeComponent = await Bolt.World.InitializeComponent(framework.WorldPda, framework.Wallet.Account.PublicKey, framework.Entity1Pda, framework.ExampleComponentPosition);
             framework.ComponentPositionEntity1Pda = initializeComponent.Pda;
             await framework.SendAndConfirmInstruction(initializeComponent.Instruction);
         }
 
         public static async Task InitializePositionComponentOnEntity2(Framework framework) {
-            var initializeComponent = await Bolt.World.InitializeComponent(framework.Wallet.Account.PublicKey, framework.Entity2Pda, framework.ExampleComponentPosition);
+            var initializeComponent = await Bolt.World.InitializeComponent(framework.WorldPda, framework.Wallet.Account.PublicKey, framework.Entity2Pda, framework.ExampleComponentPosition);
             framework.ComponentPositionEntity2Pda = initializeComponent.Pda;
             await framework.SendAndConfirmInstruction(initializeComponent.Instruction);
         }
 
         public static async Task InitializePositionComponentOnEntity4(Framework framework) {
-            var initializeComponent = await Bolt.World.InitializeComponent(framework.Wallet.Account.PublicKey, framework.Entity4Pda, framework.ExampleComponentPosition);
+            var initializeComponent = await Bolt.World.InitializeComponent(framework.WorldPda, framework.Wallet.Account.PublicKey, framework.Entity4Pda, framework.ExampleComponentPosition);
             framework.ComponentPositionEntity4Pda = initializeComponent.Pda;
             await framework.SendAndConfirmInstruction(initializeComponent.Instruction);
         }
@@ -115,6 +115,8 @@ namespace ECSTest {
             var instruction = WorldProgram.Apply(apply, Bolt.World.SerializeArgs(new { direction = "Up" }));
             instruction.Keys.Add(AccountMeta.ReadOnly(framework.ExampleComponentPosition, false));
             instruction.Keys.Add(AccountMeta.Writable(framework.ComponentPositionEntity1Pda, false));
+            instruction.Keys.Add(AccountMeta.ReadOnly(new PublicKey(WorldProgram.ID), false));
+            instruction.Keys.Add(AccountMeta.ReadOnly(WorldProgram.FindCpiAuthPda(framework.ExampleComponentPosition), false));
             await framework.SendAndConfirmInstruction(instruction);
 
             var accountInfo = await framework.GetAccountInfo(framework.ComponentPositionEntity1Pda);
@@ -150,7 +152,7 @@ namespace ECSTest {
 
             var componentBalance = await framework.Client.GetBalanceAsync(framework.ComponentVelocityEntity1Pda);
 
-            var destroyComponent = await Bolt.World.DestroyComponent(framework.Wallet.Account.PublicKey, receiver.Account.PublicKey, framework.Entity1Pda, framework.ExampleComponentVelocity, "component-velocity");
+            var destroyComponent = await Bolt.World.DestroyComponent(framework.WorldPda, framework.Wallet.Account.PublicKey, receiver.Account.PublicKey, framework.Entity1Pda, framework.ExampleComponentVelocity, "component-velocity");
             await framework.SendAndConfirmInstruction(destroyComponent.Instruction);
 
             var receiverBalance = await framework.Client.GetBalanceAsync(receiver.Account.PublicKey);
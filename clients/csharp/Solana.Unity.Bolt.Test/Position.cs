@@ -139,11 +139,23 @@ namespace Position
         {
             public PublicKey Authority { get; set; }
 
+            public byte Version { get; set; }
+
+            public PublicKey World { get; set; }
+
+            public PublicKey Entity { get; set; }
+
             public int Serialize(byte[] _data, int initialOffset)
             {
                 int offset = initialOffset;
                 _data.WritePubKey(Authority, offset);
                 offset += 32;
+                _data.WriteU8(Version, offset);
+                offset += 1;
+                _data.WritePubKey(World, offset);
+                offset += 32;
+                _data.WritePubKey(Entity, offset);
+                offset += 32;
                 return offset - initialOffset;
             }
 
@@ -153,6 +165,12 @@ namespace Position
                 result = new BoltMetadata();
                 result.Authority = _data.GetPubKey(offset);
                 offset += 32;
+                result.Version = _data.GetU8(offset);
+                offset += 1;
+                result.World = _data.GetPubKey(offset);
+                offset += 32;
+                result.Entity = _data.GetPubKey(offset);
+                offset += 32;
                 return offset - initialOffset;
             }
         }
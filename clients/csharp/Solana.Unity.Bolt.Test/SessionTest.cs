@@ -37,7 +37,7 @@ namespace SessionTest {
         }
 
         public static async Task InitializePositionComponent(Framework framework) {
-            var initializeComponent = await Bolt.World.InitializeComponent(framework.SessionSigner.Account.PublicKey, framework.SessionEntityPda, framework.ExampleComponentPosition);
+            var initializeComponent = await Bolt.World.InitializeComponent(framework.WorldPda, framework.SessionSigner.Account.PublicKey, framework.SessionEntityPda, framework.ExampleComponentPosition);
             framework.SessionComponentPositionPda = initializeComponent.Pda;
             await framework.SendAndConfirmInstruction(initializeComponent.Instruction, new List<Account> { framework.SessionSigner.Account }, framework.SessionSigner.Account.PublicKey);
         }
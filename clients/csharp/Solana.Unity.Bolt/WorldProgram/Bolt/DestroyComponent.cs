@@ -12,22 +12,22 @@ namespace Bolt {
             public TransactionInstruction Instruction { get; set; }
         }
 
-        public static async Task<DestroyComponentInstruction> DestroyComponent(PublicKey authority, PublicKey receiver, PublicKey entity, PublicKey componentId, byte[] seed) {
+        public static async Task<DestroyComponentInstruction> DestroyComponent(PublicKey world, PublicKey authority, PublicKey receiver, PublicKey entity, PublicKey componentId, byte[] seed) {
             var componentPda = WorldProgram.FindComponentPda(componentId, entity, seed);
-            return await DestroyComponent(authority, receiver, entity, componentId, componentPda);
+            return await DestroyComponent(world, authority, receiver, entity, componentId, componentPda);
         }
 
-        public static async Task<DestroyComponentInstruction> DestroyComponent(PublicKey authority, PublicKey receiver, PublicKey entity, PublicKey componentId, string seed) {
+        public static async Task<DestroyComponentInstruction> DestroyComponent(PublicKey world, PublicKey authority, PublicKey receiver, PublicKey entity, PublicKey componentId, string seed) {
             var componentPda = WorldProgram.FindComponentPda(componentId, entity, seed);
-            return await DestroyComponent(authority, receiver, entity, componentId, componentPda);
+            return await DestroyComponent(world, authority, receiver, entity, componentId, componentPda);
         }
 
-        public static async Task<DestroyComponentInstruction> DestroyComponent(PublicKey authority, PublicKey receiver, PublicKey entity, PublicKey componentId) {
+        public static async Task<DestroyComponentInstruction> DestroyComponent(PublicKey world, PublicKey authority, PublicKey receiver, PublicKey entity, PublicKey componentId) {
             var componentPda = WorldProgram.FindComponentPda(componentId, entity);
-            return await DestroyComponent(authority, receiver, entity, componentId, componentPda);
+            return await DestroyComponent(world, authority, receiver, entity, componentId, componentPda);
         }
 
-        public static async Task<DestroyComponentInstruction> DestroyComponent(PublicKey authority, PublicKey receiver, PublicKey entity, PublicKey componentProgram, PublicKey componentPda) {
+        public static async Task<DestroyComponentInstruction> DestroyComponent(PublicKey world, PublicKey authority, PublicKey receiver, PublicKey entity, PublicKey componentProgram, PublicKey componentPda) {
             var componentProgramData = WorldProgram.FindComponentProgramDataPda(componentProgram);
             var destroyComponent = new DestroyComponentAccounts() {
                 Authority = authority,
@@ -35,9 +35,12 @@ namespace Bolt {
                 Entity = entity,
                 Component = componentPda,
                 ComponentProgram = componentProgram,
-                ComponentProgramData = componentProgramData
+                ComponentProgramData = componentProgramData,
+                CpiAuth = WorldProgram.FindCpiAuthPda(componentProgram),
+                ModeratorAuthority = WorldProgram.FindModeratorAuthorityPda(world),
+                World = world
             };
-            var instruction = WorldProgram.DestroyComponent(destroyComponent);
+            var instruction = WorldProgram.DestroyComponent(destroyComponent, null);
             return new DestroyComponentInstruction() {
                 Instruction = instruction
             };
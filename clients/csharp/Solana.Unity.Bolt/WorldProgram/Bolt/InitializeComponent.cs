@@ -13,30 +13,33 @@ namespace Bolt {
             public TransactionInstruction Instruction { get; set; }
         }
 
-        public static async Task<InitializeComponentInstruction> InitializeComponent(PublicKey payer, PublicKey entity, PublicKey componentId, byte[] seed, PublicKey authority = null) {
+        public static async Task<InitializeComponentInstruction> InitializeComponent(PublicKey world, PublicKey payer, PublicKey entity, PublicKey componentId, byte[] seed, PublicKey authority = null) {
             var componentPda = WorldProgram.FindComponentPda(componentId, entity, seed);
-            return await InitializeComponent(payer, entity, componentId, componentPda, authority);
+            return await InitializeComponent(world, payer, entity, componentId, componentPda, authority);
         }
 
-        public static async Task<InitializeComponentInstruction> InitializeComponent(PublicKey payer, PublicKey entity, PublicKey componentId, string seed, PublicKey authority = null) {
+        public static async Task<InitializeComponentInstruction> InitializeComponent(PublicKey world, PublicKey payer, PublicKey entity, PublicKey componentId, string seed, PublicKey authority = null) {
             var componentPda = WorldProgram.FindComponentPda(componentId, entity, seed);
-            return await InitializeComponent(payer, entity, componentId, componentPda, authority);
+            return await InitializeComponent(world, payer, entity, componentId, componentPda, authority);
         }
 
-        public static async Task<InitializeComponentInstruction> InitializeComponent(PublicKey payer, PublicKey entity, PublicKey componentId, PublicKey authority = null) {
+        public static async Task<InitializeComponentInstruction> InitializeComponent(PublicKey world, PublicKey payer, PublicKey entity, PublicKey componentId, PublicKey authority = null) {
             var componentPda = WorldProgram.FindComponentPda(componentId, entity);
-            return await InitializeComponent(payer, entity, componentId, componentPda, authority);
+            return await InitializeComponent(world, payer, entity, componentId, componentPda, authority);
         }
 
-        public static async Task<InitializeComponentInstruction> InitializeComponent(PublicKey payer, PublicKey entity, PublicKey componentId, PublicKey componentPda, PublicKey authority = null) {
+        public static async Task<InitializeComponentInstruction> InitializeComponent(PublicKey world, PublicKey payer, PublicKey entity, PublicKey componentId, PublicKey componentPda, PublicKey authority = null, PublicKey owner = null) {
             var initializeComponent = new InitializeComponentAccounts() {
                 Payer = payer,
                 Entity = entity,
+                Owner = owner ?? payer,
                 Data = componentPda,
                 ComponentProgram = componentId,
-                Authority = authority ?? new PublicKey(WorldProgram.ID)
+                CpiAuth = WorldProgram.FindCpiAuthPda(componentId),
+                Authority = authority ?? new PublicKey(WorldProgram.ID),
+                World = world
             };
-            var instruction = WorldProgram.InitializeComponent(initializeComponent);
+            var instruction = WorldProgram.InitializeComponent(initializeComponent, null);
             return new InitializeComponentInstruction() {
                 Pda = componentPda,
                 Instruction = instruction
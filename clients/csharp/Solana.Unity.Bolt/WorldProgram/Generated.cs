@@ -31,6 +31,10 @@ namespace World
             public static string ACCOUNT_DISCRIMINATOR_B58 => "8oEQa6zH67R";
             public ulong Id { get; set; }
 
+            public PublicKey Owner { get; set; }
+
+            public PublicKey World { get; set; }
+
             public static Entity Deserialize(ReadOnlySpan<byte> _data)
             {
                 int offset = 0;
@@ -44,6 +48,21 @@ namespace World
                 Entity result = new Entity();
                 result.Id = _data.GetU64(offset);
                 offset += 8;
+                // The entities created before the owner and the world only hold their id
+                if (offset >= _data.Length)
+                {
+                    result.World = new PublicKey(new byte[32]);
+                    return result;
+                }
+
+                if (_data.GetBool(offset++))
+                {
+                    result.Owner = _data.GetPubKey(offset);
+                    offset += 32;
+                }
+
+                result.World = offset + 32 <= _data.Length ? _data.GetPubKey(offset) : new PublicKey(new byte[32]);
+                offset += 32;
                 return result;
             }
         }
@@ -87,6 +106,10 @@ namespace World
 
             public byte[] Systems { get; set; }
 
+            public AuthorityRoles Roles { get; set; }
+
+            public WorldConfig Config { get; set; }
+
             public static World Deserialize(ReadOnlySpan<byte> _data)
             {
                 int offset = 0;
@@ -117,6 +140,21 @@ namespace World
                 offset += 4;
                 result.Systems = _data.GetBytes(offset, resultSystemsLength);
                 offset += resultSystemsLength;
+                // The worlds created before the roles and the configuration end with the systems
+                result.Roles = new AuthorityRoles{Value = new byte[0]};
+                result.Config = new WorldConfig{State = WorldState.Active, PausedSystems = new PublicKey[0]};
+                if (offset < _data.Length)
+                {
+                    offset += AuthorityRoles.Deserialize(_data, offset, out var resultRoles);
+                    result.Roles = resultRoles;
+                }
+
+                if (offset < _data.Length)
+                {
+                    offset += WorldConfig.Deserialize(_data, offset, out var resultConfig);
+                    result.Config = resultConfig;
+                }
+
                 return result;
             }
         }
@@ -131,12 +169,213 @@ namespace World
             WorldAccountMismatch = 6002U,
             TooManyAuthorities = 6003U,
             AuthorityNotFound = 6004U,
-            SystemNotApproved = 6005U
+            SystemNotApproved = 6005U,
+            InvalidPatch = 6006U,
+            InvalidOutputBuffer = 6007U,
+            MissingRole = 6008U,
+            LastAdmin = 6009U,
+            WorldPaused = 6010U,
+            WorldFrozen = 6011U,
+            ComponentNotAllowed = 6012U,
+            ComponentOwnerMismatch = 6013U,
+            DuplicateComponent = 6014U,
+            EntityWorldMismatch = 6015U,
+            ComponentAlreadyInitialized = 6016U,
+            WorldHasAdmin = 6017U,
+            ComponentEntityMismatch = 6018U
         }
     }
 
     namespace Types
     {
+        public partial class AuthorityRoles
+        {
+            public byte[] Value { get; set; }
+
+            public int Serialize(byte[] _data, int initialOffset)
+            {
+                int offset = initialOffset;
+                _data.WriteS32(Value.Length, offset);
+                offset += 4;
+                _data.WriteSpan(Value, offset);
+                offset += Value.Length;
+                return offset - initialOffset;
+            }
+
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out AuthorityRoles result)
+            {
+                int offset = initialOffset;
+                result = new AuthorityRoles();
+                int resultValueLength = (int)_data.GetU32(offset);
+                offset += 4;
+                result.Value = _data.GetBytes(offset, resultValueLength);
+                offset += resultValueLength;
+                return offset - initialOffset;
+            }
+        }
+
+        public partial class BatchedSystem
+        {
+            public byte Components { get; set; }
+
+            public byte ExtraAccounts { get; set; }
+
+            public byte[] Args { get; set; }
+
+            public int Serialize(byte[] _data, int initialOffset)
+            {
+                int offset = initialOffset;
+                _data.WriteU8(Components, offset);
+                offset += 1;
+                _data.WriteU8(ExtraAccounts, offset);
+                offset += 1;
+                _data.WriteS32(Args.Length, offset);
+                offset += 4;
+                _data.WriteSpan(Args, offset);
+                offset += Args.Length;
+                return offset - initialOffset;
+            }
+
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out BatchedSystem result)
+            {
+                int offset = initialOffset;
+                result = new BatchedSystem();
+                result.Components = _data.GetU8(offset);
+                offset += 1;
+                result.ExtraAccounts = _data.GetU8(offset);
+                offset += 1;
+                int resultArgsLength = (int)_data.GetU32(offset);
+                offset += 4;
+                result.Args = _data.GetBytes(offset, resultArgsLength);
+                offset += resultArgsLength;
+                return offset - initialOffset;
+            }
+        }
+
+        public partial class SystemApplied
+        {
+            public ulong WorldId { get; set; }
+
+            public PublicKey System { get; set; }
+
+            public int Serialize(byte[] _data, int initialOffset)
+            {
+                int offset = initialOffset;
+                _data.WriteU64(WorldId, offset);
+                offset += 8;
+                _data.WritePubKey(System, offset);
+                offset += 32;
+                return offset - initialOffset;
+            }
+
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out SystemApplied result)
+            {
+                int offset = initialOffset;
+                result = new SystemApplied();
+                result.WorldId = _data.GetU64(offset);
+                offset += 8;
+                result.System = _data.GetPubKey(offset);
+                offset += 32;
+                return offset - initialOffset;
+            }
+        }
+
+        public partial class TransferredComponent
+        {
+            public byte[] Seed { get; set; }
+
+            public byte[] Instance { get; set; }
+
+            public int Serialize(byte[] _data, int initialOffset)
+            {
+                int offset = initialOffset;
+                _data.WriteS32(Seed.Length, offset);
+                offset += 4;
+                _data.WriteSpan(Seed, offset);
+                offset += Seed.Length;
+                if (Instance != null)
+                {
+                    _data.WriteU8(1, offset);
+                    offset += 1;
+                    _data.WriteS32(Instance.Length, offset);
+                    offset += 4;
+                    _data.WriteSpan(Instance, offset);
+                    offset += Instance.Length;
+                }
+                else
+                {
+                    _data.WriteU8(0, offset);
+                    offset += 1;
+                }
+
+                return offset - initialOffset;
+            }
+
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out TransferredComponent result)
+            {
+                int offset = initialOffset;
+                result = new TransferredComponent();
+                int resultSeedLength = (int)_data.GetU32(offset);
+                offset += 4;
+                result.Seed = _data.GetBytes(offset, resultSeedLength);
+                offset += resultSeedLength;
+                if (_data.GetBool(offset++))
+                {
+                    int resultInstanceLength = (int)_data.GetU32(offset);
+                    offset += 4;
+                    result.Instance = _data.GetBytes(offset, resultInstanceLength);
+                    offset += resultInstanceLength;
+                }
+
+                return offset - initialOffset;
+            }
+        }
+
+        public partial class WorldConfig
+        {
+            public WorldState State { get; set; }
+
+            public PublicKey[] PausedSystems { get; set; }
+
+            public int Serialize(byte[] _data, int initialOffset)
+            {
+                int offset = initialOffset;
+                _data.WriteU8((byte)State, offset);
+                offset += 1;
+                _data.WriteS32(PausedSystems.Length, offset);
+                offset += 4;
+                foreach (var pausedSystemsElement in PausedSystems)
+                {
+                    _data.WritePubKey(pausedSystemsElement, offset);
+                    offset += 32;
+                }
+                return offset - initialOffset;
+            }
+
+            public static int Deserialize(ReadOnlySpan<byte> _data, int initialOffset, out WorldConfig result)
+            {
+                int offset = initialOffset;
+                result = new WorldConfig();
+                result.State = (WorldState)_data.GetU8(offset);
+                offset += 1;
+                int resultPausedSystemsLength = (int)_data.GetU32(offset);
+                offset += 4;
+                result.PausedSystems = new PublicKey[resultPausedSystemsLength];
+                for (uint resultPausedSystemsIdx = 0; resultPausedSystemsIdx < resultPausedSystemsLength; resultPausedSystemsIdx++)
+                {
+                    result.PausedSystems[resultPausedSystemsIdx] = _data.GetPubKey(offset);
+                    offset += 32;
+                }
+                return offset - initialOffset;
+            }
+        }
+
+        public enum WorldState : byte
+        {
+            Active,
+            Paused,
+            Frozen
+        }
     }
 
     public partial class WorldClient : TransactionalBaseClient<WorldErrorKind>
@@ -243,7 +482,7 @@ namespace World
 
         protected override Dictionary<uint, ProgramError<WorldErrorKind>> BuildErrorsDictionary()
         {
-            return new Dictionary<uint, ProgramError<WorldErrorKind>>{{6000U, new ProgramError<WorldErrorKind>(WorldErrorKind.InvalidAuthority, "Invalid authority for instruction")}, {6001U, new ProgramError<WorldErrorKind>(WorldErrorKind.InvalidSystemOutput, "Invalid system output")}, {6002U, new ProgramError<WorldErrorKind>(WorldErrorKind.WorldAccountMismatch, "The provided world account does not match the expected PDA.")}, {6003U, new ProgramError<WorldErrorKind>(WorldErrorKind.TooManyAuthorities, "Exceed the maximum number of authorities.")}, {6004U, new ProgramError<WorldErrorKind>(WorldErrorKind.AuthorityNotFound, "The provided authority not found")}, {6005U, new ProgramError<WorldErrorKind>(WorldErrorKind.SystemNotApproved, "The system is not approved in this world instance")}, };
+            return new Dictionary<uint, ProgramError<WorldErrorKind>>{{6000U, new ProgramError<WorldErrorKind>(WorldErrorKind.InvalidAuthority, "Invalid authority for instruction")}, {6001U, new ProgramError<WorldErrorKind>(WorldErrorKind.InvalidSystemOutput, "Invalid system output")}, {6002U, new ProgramError<WorldErrorKind>(WorldErrorKind.WorldAccountMismatch, "The provided world account does not match the expected PDA.")}, {6003U, new ProgramError<WorldErrorKind>(WorldErrorKind.TooManyAuthorities, "Exceed the maximum number of authorities.")}, {6004U, new ProgramError<WorldErrorKind>(WorldErrorKind.AuthorityNotFound, "The provided authority not found")}, {6005U, new ProgramError<WorldErrorKind>(WorldErrorKind.SystemNotApproved, "The system is not approved in this world instance")}, {6006U, new ProgramError<WorldErrorKind>(WorldErrorKind.InvalidPatch, "The system patch is out of the component bounds")}, {6007U, new ProgramError<WorldErrorKind>(WorldErrorKind.InvalidOutputBuffer, "The output buffer must be owned by the system and not passed to it as a component or extra account")}, {6008U, new ProgramError<WorldErrorKind>(WorldErrorKind.MissingRole, "The authority doesn't have the role required by the instruction")}, {6009U, new ProgramError<WorldErrorKind>(WorldErrorKind.LastAdmin, "The world must keep at least one admin")}, {6010U, new ProgramError<WorldErrorKind>(WorldErrorKind.WorldPaused, "The world is paused")}, {6011U, new ProgramError<WorldErrorKind>(WorldErrorKind.WorldFrozen, "The world is frozen")}, {6012U, new ProgramError<WorldErrorKind>(WorldErrorKind.ComponentNotAllowed, "The system is not allowed to write the component")}, {6013U, new ProgramError<WorldErrorKind>(WorldErrorKind.ComponentOwnerMismatch, "The component is not owned by the component program")}, {6014U, new ProgramError<WorldErrorKind>(WorldErrorKind.DuplicateComponent, "The component is passed more than once")}, {6015U, new ProgramError<WorldErrorKind>(WorldErrorKind.EntityWorldMismatch, "The entity belongs to another world")}, {6016U, new ProgramError<WorldErrorKind>(WorldErrorKind.ComponentAlreadyInitialized, "The component is already initialized")}, {6017U, new ProgramError<WorldErrorKind>(WorldErrorKind.WorldHasAdmin, "The world already has an admin")}, {6018U, new ProgramError<WorldErrorKind>(WorldErrorKind.ComponentEntityMismatch, "The component is not derived from the entity")}, };
         }
     }
 
@@ -277,8 +516,110 @@ namespace World
 
             public PublicKey Authority { get; set; }
 
-            public PublicKey InstructionSysvarAccount { get; set; } = new PublicKey("Sysvar1nstructions1111111111111111111111111");
             public PublicKey World { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class ApplyBatchAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey World { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class ApplyBatchWithSessionAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey World { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+            public PublicKey SessionToken { get; set; }
+        }
+
+        public class ApplyBufferedAccounts
+        {
+            public PublicKey BoltSystem { get; set; }
+
+            public PublicKey Authority { get; set; }
+
+            public PublicKey World { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+            public PublicKey Buffer { get; set; }
+        }
+
+        public class ApplyBufferedWithSessionAccounts
+        {
+            public PublicKey BoltSystem { get; set; }
+
+            public PublicKey Authority { get; set; }
+
+            public PublicKey World { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+            public PublicKey Buffer { get; set; }
+
+            public PublicKey SessionToken { get; set; }
+        }
+
+        public class ApplyCommandsAccounts
+        {
+            public PublicKey BoltSystem { get; set; }
+
+            public PublicKey Authority { get; set; }
+
+            public PublicKey World { get; set; }
+
+            public PublicKey Vault { get; set; }
+
+            public PublicKey SystemAuthority { get; set; }
+
+            public PublicKey WorldProgram { get; set; } = new PublicKey("WorLD15A7CrDwLcLy4fRqtaTb9fbd8o8iqiEMUDse2n");
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class ApplyCommandsWithSessionAccounts
+        {
+            public PublicKey BoltSystem { get; set; }
+
+            public PublicKey Authority { get; set; }
+
+            public PublicKey World { get; set; }
+
+            public PublicKey Vault { get; set; }
+
+            public PublicKey SystemAuthority { get; set; }
+
+            public PublicKey WorldProgram { get; set; } = new PublicKey("WorLD15A7CrDwLcLy4fRqtaTb9fbd8o8iqiEMUDse2n");
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+            public PublicKey SessionToken { get; set; }
+        }
+
+        public class ApplyPatchAccounts
+        {
+            public PublicKey BoltSystem { get; set; }
+
+            public PublicKey Authority { get; set; }
+
+            public PublicKey World { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class ApplyPatchWithSessionAccounts
+        {
+            public PublicKey BoltSystem { get; set; }
+
+            public PublicKey Authority { get; set; }
+
+            public PublicKey World { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+            public PublicKey SessionToken { get; set; }
         }
 
         public class ApplyWithSessionAccounts
@@ -287,9 +628,9 @@ namespace World
 
             public PublicKey Authority { get; set; }
 
-            public PublicKey InstructionSysvarAccount { get; set; } = new PublicKey("Sysvar1nstructions1111111111111111111111111");
             public PublicKey World { get; set; }
 
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
             public PublicKey SessionToken { get; set; }
         }
 
@@ -304,6 +645,28 @@ namespace World
             public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
         }
 
+        public class ApproveSystemWithComponentsAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey World { get; set; }
+
+            public PublicKey System { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class ClaimWorldAdminAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey World { get; set; }
+
+            public PublicKey WorldProgramData { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
         public class DestroyComponentAccounts
         {
             public PublicKey Authority { get; set; }
@@ -318,7 +681,26 @@ namespace World
 
             public PublicKey Component { get; set; }
 
-            public PublicKey InstructionSysvarAccount { get; set; } = new PublicKey("Sysvar1nstructions1111111111111111111111111");
+            public PublicKey CpiAuth { get; set; }
+
+            public PublicKey ModeratorAuthority { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+            public PublicKey World { get; set; }
+        }
+
+        public class DestroyEntityAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey Receiver { get; set; }
+
+            public PublicKey Entity { get; set; }
+
+            public PublicKey World { get; set; }
+
+            public PublicKey ModeratorAuthority { get; set; }
+
             public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
         }
 
@@ -330,12 +712,16 @@ namespace World
 
             public PublicKey Entity { get; set; }
 
+            public PublicKey Owner { get; set; }
+
             public PublicKey ComponentProgram { get; set; }
 
             public PublicKey Authority { get; set; }
 
-            public PublicKey InstructionSysvarAccount { get; set; } = new PublicKey("Sysvar1nstructions1111111111111111111111111");
+            public PublicKey CpiAuth { get; set; }
+
             public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+            public PublicKey World { get; set; }
         }
 
         public class InitializeNewWorldAccounts
@@ -358,32 +744,146 @@ namespace World
             public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
         }
 
-        public class RemoveAuthorityAccounts
+        public class InitializeResourceAccounts
         {
-            public PublicKey Authority { get; set; }
+            public PublicKey Payer { get; set; }
 
-            public PublicKey AuthorityToDelete { get; set; }
+            public PublicKey Data { get; set; }
 
-            public PublicKey World { get; set; }
+            public PublicKey ComponentProgram { get; set; }
+
+            public PublicKey Authority { get; set; }
+
+            public PublicKey CpiAuth { get; set; }
 
             public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+            public PublicKey World { get; set; }
         }
 
-        public class RemoveSystemAccounts
+        public class MigrateComponentAccounts
         {
-            public PublicKey Authority { get; set; }
+            public PublicKey Payer { get; set; }
 
-            public PublicKey World { get; set; }
+            public PublicKey Component { get; set; }
 
-            public PublicKey System { get; set; }
+            public PublicKey ComponentProgram { get; set; }
 
-            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
-        }
+            public PublicKey ComponentProgramData { get; set; }
 
-        public partial class WorldProgram
+            public PublicKey Entity { get; set; }
+
+            public PublicKey Authority { get; set; }
+
+            public PublicKey CpiAuth { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class MigrateEntityAccounts
+        {
+            public PublicKey Payer { get; set; }
+
+            public PublicKey Entity { get; set; }
+
+            public PublicKey World { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class RemoveAuthorityAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey AuthorityToDelete { get; set; }
+
+            public PublicKey World { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class RemoveSystemAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey World { get; set; }
+
+            public PublicKey System { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class SetAuthorityRolesAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey AuthorityToUpdate { get; set; }
+
+            public PublicKey World { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class SetComponentAuthorityAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey ComponentProgram { get; set; }
+
+            public PublicKey ComponentProgramData { get; set; }
+
+            public PublicKey Component { get; set; }
+
+            public PublicKey CpiAuth { get; set; }
+        }
+
+        public class SetPausedSystemsAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey World { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class SetWorldStateAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey World { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public class TransferEntityAccounts
+        {
+            public PublicKey Owner { get; set; }
+
+            public PublicKey NewOwner { get; set; }
+
+            public PublicKey Entity { get; set; }
+
+            public PublicKey World { get; set; }
+
+            public PublicKey ModeratorAuthority { get; set; }
+        }
+
+        public class WithdrawVaultAccounts
+        {
+            public PublicKey Authority { get; set; }
+
+            public PublicKey World { get; set; }
+
+            public PublicKey Vault { get; set; }
+
+            public PublicKey Receiver { get; set; }
+
+            public PublicKey SystemProgram { get; set; } = new PublicKey("11111111111111111111111111111111");
+        }
+
+        public partial class WorldProgram
         {
             public const string ID = "WorLD15A7CrDwLcLy4fRqtaTb9fbd8o8iqiEMUDse2n";
-            public static Solana.Unity.Rpc.Models.TransactionInstruction AddAuthority(AddAuthorityAccounts accounts, ulong world_id, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction AddAuthority(AddAuthorityAccounts accounts, ulong world_id, byte roles, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
@@ -394,6 +894,8 @@ namespace World
                 offset += 8;
                 _data.WriteU64(world_id, offset);
                 offset += 8;
+                _data.WriteU8(roles, offset);
+                offset += 1;
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
@@ -432,7 +934,7 @@ namespace World
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.BoltSystem, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.InstructionSysvarAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.World, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.BoltSystem, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.World, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
                 _data.WriteU64(16258613031726085112UL, offset);
@@ -446,11 +948,161 @@ namespace World
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ApplyBatch(ApplyBatchAccounts accounts, BatchedSystem[] systems, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.World, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(9215316826927257125UL, offset);
+                offset += 8;
+                _data.WriteS32(systems.Length, offset);
+                offset += 4;
+                foreach (var systemsElement in systems)
+                {
+                    offset += systemsElement.Serialize(_data, offset);
+                }
+
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ApplyBatchWithSession(ApplyBatchWithSessionAccounts accounts, BatchedSystem[] systems, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.World, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SessionToken, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(10511603674372955120UL, offset);
+                offset += 8;
+                _data.WriteS32(systems.Length, offset);
+                offset += 4;
+                foreach (var systemsElement in systems)
+                {
+                    offset += systemsElement.Serialize(_data, offset);
+                }
+
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ApplyBuffered(ApplyBufferedAccounts accounts, byte[] args, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.BoltSystem, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.World, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Buffer, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(1325304284277145683UL, offset);
+                offset += 8;
+                _data.WriteS32(args.Length, offset);
+                offset += 4;
+                _data.WriteSpan(args, offset);
+                offset += args.Length;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ApplyBufferedWithSession(ApplyBufferedWithSessionAccounts accounts, byte[] args, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.BoltSystem, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.World, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Buffer, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SessionToken, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(7565029073497189569UL, offset);
+                offset += 8;
+                _data.WriteS32(args.Length, offset);
+                offset += 4;
+                _data.WriteSpan(args, offset);
+                offset += args.Length;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ApplyCommands(ApplyCommandsAccounts accounts, byte[] args, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.BoltSystem, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.World, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Vault, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.WorldProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(14328354326166169329UL, offset);
+                offset += 8;
+                _data.WriteS32(args.Length, offset);
+                offset += 4;
+                _data.WriteSpan(args, offset);
+                offset += args.Length;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ApplyCommandsWithSession(ApplyCommandsWithSessionAccounts accounts, byte[] args, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.BoltSystem, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.World, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Vault, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.WorldProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SessionToken, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(10846434606184615890UL, offset);
+                offset += 8;
+                _data.WriteS32(args.Length, offset);
+                offset += 4;
+                _data.WriteSpan(args, offset);
+                offset += args.Length;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ApplyPatch(ApplyPatchAccounts accounts, byte[] args, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.BoltSystem, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.World, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(1984072414709850834UL, offset);
+                offset += 8;
+                _data.WriteS32(args.Length, offset);
+                offset += 4;
+                _data.WriteSpan(args, offset);
+                offset += args.Length;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ApplyPatchWithSession(ApplyPatchWithSessionAccounts accounts, byte[] args, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.BoltSystem, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.World, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SessionToken, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(6467916628959748026UL, offset);
+                offset += 8;
+                _data.WriteS32(args.Length, offset);
+                offset += 4;
+                _data.WriteSpan(args, offset);
+                offset += args.Length;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
             public static Solana.Unity.Rpc.Models.TransactionInstruction ApplyWithSession(ApplyWithSessionAccounts accounts, byte[] args, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.BoltSystem, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.InstructionSysvarAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.World, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SessionToken, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.BoltSystem, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.World, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SessionToken, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
                 _data.WriteU64(7459768094276011477UL, offset);
@@ -478,29 +1130,147 @@ namespace World
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction DestroyComponent(DestroyComponentAccounts accounts, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ApproveSystemWithComponents(ApproveSystemWithComponentsAccounts accounts, PublicKey[] component_programs, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.World, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.System, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(7213342118386922537UL, offset);
+                offset += 8;
+                _data.WriteS32(component_programs.Length, offset);
+                offset += 4;
+                foreach (var component_programsElement in component_programs)
+                {
+                    _data.WritePubKey(component_programsElement, offset);
+                    offset += 32;
+                }
+
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction ClaimWorldAdmin(ClaimWorldAdminAccounts accounts, ulong world_id, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.World, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.WorldProgramData, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(6881105406011641961UL, offset);
+                offset += 8;
+                _data.WriteU64(world_id, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction DestroyComponent(DestroyComponentAccounts accounts, byte[] instance, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Receiver, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ComponentProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ComponentProgramData, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Entity, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Component, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.InstructionSysvarAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Receiver, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ComponentProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ComponentProgramData, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Entity, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Component, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.CpiAuth, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ModeratorAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.World, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
                 _data.WriteU64(5321952129328727336UL, offset);
                 offset += 8;
+                if (instance != null)
+                {
+                    _data.WriteU8(1, offset);
+                    offset += 1;
+                    _data.WriteS32(instance.Length, offset);
+                    offset += 4;
+                    _data.WriteSpan(instance, offset);
+                    offset += instance.Length;
+                }
+                else
+                {
+                    _data.WriteU8(0, offset);
+                    offset += 1;
+                }
+
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
-            public static Solana.Unity.Rpc.Models.TransactionInstruction InitializeComponent(InitializeComponentAccounts accounts, PublicKey programId = null)
+            public static Solana.Unity.Rpc.Models.TransactionInstruction DestroyEntity(DestroyEntityAccounts accounts, byte[] extra_seed, byte[][] instances, PublicKey programId = null)
             {
                 programId ??= new(ID);
                 List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
-                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Payer, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Data, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Entity, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ComponentProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.InstructionSysvarAccount, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Receiver, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Entity, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.World, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ModeratorAuthority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(13999856986449963202UL, offset);
+                offset += 8;
+                if (extra_seed != null)
+                {
+                    _data.WriteU8(1, offset);
+                    offset += 1;
+                    _data.WriteS32(extra_seed.Length, offset);
+                    offset += 4;
+                    _data.WriteSpan(extra_seed, offset);
+                    offset += extra_seed.Length;
+                }
+                else
+                {
+                    _data.WriteU8(0, offset);
+                    offset += 1;
+                }
+
+                _data.WriteS32(instances.Length, offset);
+                offset += 4;
+                foreach (var instancesElement in instances)
+                {
+                    if (instancesElement != null)
+                    {
+                        _data.WriteU8(1, offset);
+                        offset += 1;
+                        _data.WriteS32(instancesElement.Length, offset);
+                        offset += 4;
+                        _data.WriteSpan(instancesElement, offset);
+                        offset += instancesElement.Length;
+                    }
+                    else
+                    {
+                        _data.WriteU8(0, offset);
+                        offset += 1;
+                    }
+
+                }
+
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction InitializeComponent(InitializeComponentAccounts accounts, byte[] instance, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Payer, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Data, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Entity, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Owner, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ComponentProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.CpiAuth, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.World, false)};
                 byte[] _data = new byte[1200];
                 int offset = 0;
                 _data.WriteU64(2179155133888827172UL, offset);
                 offset += 8;
+                if (instance != null)
+                {
+                    _data.WriteU8(1, offset);
+                    offset += 1;
+                    _data.WriteS32(instance.Length, offset);
+                    offset += 4;
+                    _data.WriteSpan(instance, offset);
+                    offset += instance.Length;
+                }
+                else
+                {
+                    _data.WriteU8(0, offset);
+                    offset += 1;
+                }
+
                 byte[] resultData = new byte[offset];
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
@@ -534,6 +1304,78 @@ namespace World
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
 
+            public static Solana.Unity.Rpc.Models.TransactionInstruction InitializeResource(InitializeResourceAccounts accounts, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Payer, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Data, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ComponentProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.CpiAuth, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.World, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(10437121371509111878UL, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction MigrateComponent(MigrateComponentAccounts accounts, byte[] instance, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Payer, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Component, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ComponentProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ComponentProgramData, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Entity, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.CpiAuth, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(17321787466133234357UL, offset);
+                offset += 8;
+                if (instance != null)
+                {
+                    _data.WriteU8(1, offset);
+                    offset += 1;
+                    _data.WriteS32(instance.Length, offset);
+                    offset += 4;
+                    _data.WriteSpan(instance, offset);
+                    offset += instance.Length;
+                }
+                else
+                {
+                    _data.WriteU8(0, offset);
+                    offset += 1;
+                }
+
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction MigrateEntity(MigrateEntityAccounts accounts, byte[] extra_seed, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Payer, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Entity, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.World, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(2389602881700037186UL, offset);
+                offset += 8;
+                if (extra_seed != null)
+                {
+                    _data.WriteU8(1, offset);
+                    offset += 1;
+                    _data.WriteS32(extra_seed.Length, offset);
+                    offset += 4;
+                    _data.WriteSpan(extra_seed, offset);
+                    offset += extra_seed.Length;
+                }
+                else
+                {
+                    _data.WriteU8(0, offset);
+                    offset += 1;
+                }
+
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
             public static Solana.Unity.Rpc.Models.TransactionInstruction RemoveAuthority(RemoveAuthorityAccounts accounts, ulong world_id, PublicKey programId = null)
             {
                 programId ??= new(ID);
@@ -563,6 +1405,119 @@ namespace World
                 Array.Copy(_data, resultData, offset);
                 return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
             }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction SetAuthorityRoles(SetAuthorityRolesAccounts accounts, ulong world_id, byte roles, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.AuthorityToUpdate, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.World, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(1509943241516324111UL, offset);
+                offset += 8;
+                _data.WriteU64(world_id, offset);
+                offset += 8;
+                _data.WriteU8(roles, offset);
+                offset += 1;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction SetComponentAuthority(SetComponentAuthorityAccounts accounts, PublicKey new_authority, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ComponentProgram, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ComponentProgramData, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Component, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.CpiAuth, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(6041253572053129483UL, offset);
+                offset += 8;
+                _data.WritePubKey(new_authority, offset);
+                offset += 32;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction SetPausedSystems(SetPausedSystemsAccounts accounts, ulong world_id, PublicKey[] systems, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.World, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(1447618698350734685UL, offset);
+                offset += 8;
+                _data.WriteU64(world_id, offset);
+                offset += 8;
+                _data.WriteS32(systems.Length, offset);
+                offset += 4;
+                foreach (var systemsElement in systems)
+                {
+                    _data.WritePubKey(systemsElement, offset);
+                    offset += 32;
+                }
+
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction SetWorldState(SetWorldStateAccounts accounts, ulong world_id, WorldState state, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.World, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(9997829374057654259UL, offset);
+                offset += 8;
+                _data.WriteU64(world_id, offset);
+                offset += 8;
+                _data.WriteU8((byte)state, offset);
+                offset += 1;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction TransferEntity(TransferEntityAccounts accounts, TransferredComponent[] components, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Owner, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.NewOwner, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Entity, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.World, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.ModeratorAuthority, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(17906438244923724825UL, offset);
+                offset += 8;
+                _data.WriteS32(components.Length, offset);
+                offset += 4;
+                foreach (var componentsElement in components)
+                {
+                    offset += componentsElement.Serialize(_data, offset);
+                }
+
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
+
+            public static Solana.Unity.Rpc.Models.TransactionInstruction WithdrawVault(WithdrawVaultAccounts accounts, ulong amount, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                List<Solana.Unity.Rpc.Models.AccountMeta> keys = new()
+                {Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.Authority, true), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.World, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Vault, false), Solana.Unity.Rpc.Models.AccountMeta.Writable(accounts.Receiver, false), Solana.Unity.Rpc.Models.AccountMeta.ReadOnly(accounts.SystemProgram, false)};
+                byte[] _data = new byte[1200];
+                int offset = 0;
+                _data.WriteU64(531247277124945799UL, offset);
+                offset += 8;
+                _data.WriteU64(amount, offset);
+                offset += 8;
+                byte[] resultData = new byte[offset];
+                Array.Copy(_data, resultData, offset);
+                return new Solana.Unity.Rpc.Models.TransactionInstruction{Keys = keys, ProgramId = programId.KeyBytes, Data = resultData};
+            }
         }
     }
 }
\ No newline at end of file
@@ -97,6 +97,37 @@ namespace World
                 }, new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"), out var pda, out _);
                 return pda;
             }
+
+            public static PublicKey FindModeratorAuthorityPda(PublicKey world, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                PublicKey.TryFindProgramAddress(new[]
+                {
+                    Encoding.UTF8.GetBytes("moderator"), world.KeyBytes
+                }, programId, out var pda, out _);
+                return pda;
+            }
+
+            public static PublicKey FindSystemAuthorityPda(PublicKey world, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                PublicKey.TryFindProgramAddress(new[]
+                {
+                    Encoding.UTF8.GetBytes("system"), world.KeyBytes
+                }, programId, out var pda, out _);
+                return pda;
+            }
+
+            public static PublicKey FindCpiAuthPda(PublicKey componentProgramId, PublicKey programId = null)
+            {
+                programId ??= new(ID);
+                PublicKey.TryFindProgramAddress(new[]
+                {
+                    Encoding.UTF8.GetBytes("cpi_auth"), componentProgramId.KeyBytes
+                }, programId, out var pda, out _);
+                return pda;
+            }
+
             public static PublicKey FindComponentPda(
                 PublicKey componentProgramId,
                 PublicKey entity
@@ -247,6 +278,11 @@ namespace World
                     instruction.Keys.Add(AccountMeta.ReadOnly(new PublicKey(WorldProgram.ID), false));
                 }
 
+                // the world signs the update of each component program with its own signer
+                foreach (var componentId in componentIds.Distinct()) {
+                    instruction.Keys.Add(AccountMeta.ReadOnly(FindCpiAuthPda(componentId, programId), false));
+                }
+
                 return instruction;
             }
        }
@@ -35,22 +35,20 @@ export const applyStruct = new beet.FixableBeetArgsStruct<
 /**
  * Accounts required by the _apply_ instruction
  *
- * @property [] componentProgram
  * @property [] boltSystem
- * @property [_writable_] boltComponent
- * @property [] authority
- * @property [] instructionSysvarAccount
+ * @property [_writable_, **signer**] authority
+ * @property [] cpiAuth
+ * @property [] world
  * @category Instructions
  * @category Apply
  * @category generated
  */
 export interface ApplyInstructionAccounts {
-  componentProgram: web3.PublicKey;
   boltSystem: web3.PublicKey;
-  boltComponent: web3.PublicKey;
   authority: web3.PublicKey;
-  instructionSysvarAccount: web3.PublicKey;
+  cpiAuth: web3.PublicKey;
   world: web3.PublicKey;
+  systemProgram?: web3.PublicKey;
   anchorRemainingAccounts?: web3.AccountMeta[];
 }
 
@@ -78,33 +76,28 @@ export function createApplyInstruction(
     ...args,
   });
   const keys: web3.AccountMeta[] = [
-    {
-      pubkey: accounts.componentProgram,
-      isWritable: false,
-      isSigner: false,
-    },
     {
       pubkey: accounts.boltSystem,
       isWritable: false,
       isSigner: false,
     },
     {
-      pubkey: accounts.boltComponent,
+      pubkey: accounts.authority,
       isWritable: true,
-      isSigner: false,
+      isSigner: true,
     },
     {
-      pubkey: accounts.authority,
+      pubkey: accounts.cpiAuth,
       isWritable: false,
       isSigner: false,
     },
     {
-      pubkey: accounts.instructionSysvarAccount,
+      pubkey: accounts.world,
       isWritable: false,
       isSigner: false,
     },
     {
-      pubkey: accounts.world,
+      pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
       isWritable: false,
       isSigner: false,
     },
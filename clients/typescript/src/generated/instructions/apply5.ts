@@ -1,175 +0,0 @@
-/**
- * This code was GENERATED using the solita package.
- * Please DO NOT EDIT THIS FILE, instead rerun solita to update it or write a wrapper to add functionality.
- *
- * See: https://github.com/metaplex-foundation/solita
- */
-
-import * as beet from "@metaplex-foundation/beet";
-import * as web3 from "@solana/web3.js";
-
-/**
- * @category Instructions
- * @category Apply5
- * @category generated
- */
-export interface Apply5InstructionArgs {
-  args: Uint8Array;
-}
-/**
- * @category Instructions
- * @category Apply5
- * @category generated
- */
-export const apply5Struct = new beet.FixableBeetArgsStruct<
-  Apply5InstructionArgs & {
-    instructionDiscriminator: number[] /* size: 8 */;
-  }
->(
-  [
-    ["instructionDiscriminator", beet.uniformFixedSizeArray(beet.u8, 8)],
-    ["args", beet.bytes],
-  ],
-  "Apply5InstructionArgs",
-);
-/**
- * Accounts required by the _apply5_ instruction
- *
- * @property [] boltSystem
- * @property [] componentProgram1
- * @property [_writable_] boltComponent1
- * @property [] componentProgram2
- * @property [_writable_] boltComponent2
- * @property [] componentProgram3
- * @property [_writable_] boltComponent3
- * @property [] componentProgram4
- * @property [_writable_] boltComponent4
- * @property [] componentProgram5
- * @property [_writable_] boltComponent5
- * @property [] authority
- * @property [] instructionSysvarAccount
- * @category Instructions
- * @category Apply5
- * @category generated
- */
-export interface Apply5InstructionAccounts {
-  boltSystem: web3.PublicKey;
-  componentProgram1: web3.PublicKey;
-  boltComponent1: web3.PublicKey;
-  componentProgram2: web3.PublicKey;
-  boltComponent2: web3.PublicKey;
-  componentProgram3: web3.PublicKey;
-  boltComponent3: web3.PublicKey;
-  componentProgram4: web3.PublicKey;
-  boltComponent4: web3.PublicKey;
-  componentProgram5: web3.PublicKey;
-  boltComponent5: web3.PublicKey;
-  authority: web3.PublicKey;
-  instructionSysvarAccount: web3.PublicKey;
-  anchorRemainingAccounts?: web3.AccountMeta[];
-}
-
-export const apply5InstructionDiscriminator = [
-  70, 164, 214, 28, 136, 116, 84, 153,
-];
-
-/**
- * Creates a _Apply5_ instruction.
- *
- * @param accounts that will be accessed while the instruction is processed
- * @param args to provide as instruction data to the program
- *
- * @category Instructions
- * @category Apply5
- * @category generated
- */
-export function createApply5Instruction(
-  accounts: Apply5InstructionAccounts,
-  args: Apply5InstructionArgs,
-  programId = new web3.PublicKey("WorLD15A7CrDwLcLy4fRqtaTb9fbd8o8iqiEMUDse2n"),
-) {
-  const [data] = apply5Struct.serialize({
-    instructionDiscriminator: apply5InstructionDiscriminator,
-    ...args,
-  });
-  const keys: web3.AccountMeta[] = [
-    {
-      pubkey: accounts.boltSystem,
-      isWritable: false,
-      isSigner: false,
-    },
-    {
-      pubkey: accounts.componentProgram1,
-      isWritable: false,
-      isSigner: false,
-    },
-    {
-      pubkey: accounts.boltComponent1,
-      isWritable: true,
-      isSigner: false,
-    },
-    {
-      pubkey: accounts.componentProgram2,
-      isWritable: false,
-      isSigner: false,
-    },
-    {
-      pubkey: accounts.boltComponent2,
-      isWritable: true,
-      isSigner: false,
-    },
-    {
-      pubkey: accounts.componentProgram3,
-      isWritable: false,
-      isSigner: false,
-    },
-    {
-      pubkey: accounts.boltComponent3,
-      isWritable: true,
-      isSigner: false,
-    },
-    {
-      pubkey: accounts.componentProgram4,
-      isWritable: false,
-      isSigner: false,
-    },
-    {
-      pubkey: accounts.boltComponent4,
-      isWritable: true,
-      isSigner: false,
-    },
-    {
-      pubkey: accounts.componentProgram5,
-      isWritable: false,
-      isSigner: false,
-    },
-    {
-      pubkey: accounts.boltComponent5,
-      isWritable: true,
-      isSigner: false,
-    },
-    {
-      pubkey: accounts.authority,
-      isWritable: false,
-      isSigner: false,
-    },
-    {
-      pubkey: accounts.instructionSysvarAccount,
-      isWritable: false,
-      isSigner: false,
-    },
-  ];
-
-  if (accounts.anchorRemainingAccounts != null) {
-    for (const acc of accounts.anchorRemainingAccounts) {
-      keys.push(acc);
-    }
-  }
-
-  const ix = new web3.TransactionInstruction({
-    programId,
-    keys,
-    data,
-  });
-  return ix;
-}
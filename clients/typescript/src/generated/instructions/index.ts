@@ -7,10 +7,6 @@
 
 export * from "./addEntity";
 export * from "./apply";
-export * from "./apply2";
-export * from "./apply3";
-export * from "./apply4";
-export * from "./apply5";
 export * from "./initializeComponent";
 export * from "./initializeNewWorld";
 export * from "./initializeRegistry";
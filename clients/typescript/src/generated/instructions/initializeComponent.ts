@@ -13,10 +13,23 @@ import * as web3 from "@solana/web3.js";
  * @category InitializeComponent
  * @category generated
  */
-export const initializeComponentStruct = new beet.BeetArgsStruct<{
-  instructionDiscriminator: number[] /* size: 8 */;
-}>(
-  [["instructionDiscriminator", beet.uniformFixedSizeArray(beet.u8, 8)]],
+export interface InitializeComponentInstructionArgs {
+  instance: beet.COption<Uint8Array>;
+}
+/**
+ * @category Instructions
+ * @category InitializeComponent
+ * @category generated
+ */
+export const initializeComponentStruct = new beet.FixableBeetArgsStruct<
+  InitializeComponentInstructionArgs & {
+    instructionDiscriminator: number[] /* size: 8 */;
+  }
+>(
+  [
+    ["instructionDiscriminator", beet.uniformFixedSizeArray(beet.u8, 8)],
+    ["instance", beet.coption(beet.bytes)],
+  ],
   "InitializeComponentInstructionArgs",
 );
 /**
@@ -25,9 +38,11 @@ export const initializeComponentStruct = new beet.BeetArgsStruct<{
  * @property [_writable_, **signer**] payer
  * @property [_writable_] data
  * @property [] entity
+ * @property [**signer**] owner
  * @property [] componentProgram
  * @property [] authority
- * @property [] instructionSysvarAccount
+ * @property [] cpiAuth
+ * @property [] world
  * @category Instructions
  * @category InitializeComponent
  * @category generated
@@ -36,10 +51,12 @@ export interface InitializeComponentInstructionAccounts {
   payer: web3.PublicKey;
   data: web3.PublicKey;
   entity: web3.PublicKey;
+  owner: web3.PublicKey;
   componentProgram: web3.PublicKey;
   authority: web3.PublicKey;
-  instructionSysvarAccount: web3.PublicKey;
+  cpiAuth: web3.PublicKey;
   systemProgram?: web3.PublicKey;
+  world: web3.PublicKey;
   anchorRemainingAccounts?: web3.AccountMeta[];
 }
 
@@ -51,16 +68,20 @@ export const initializeComponentInstructionDiscriminator = [
  * Creates a _InitializeComponent_ instruction.
  *
  * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ *
  * @category Instructions
  * @category InitializeComponent
  * @category generated
  */
 export function createInitializeComponentInstruction(
   accounts: InitializeComponentInstructionAccounts,
+  args: InitializeComponentInstructionArgs,
   programId = new web3.PublicKey("WorLD15A7CrDwLcLy4fRqtaTb9fbd8o8iqiEMUDse2n"),
 ) {
   const [data] = initializeComponentStruct.serialize({
     instructionDiscriminator: initializeComponentInstructionDiscriminator,
+    ...args,
   });
   const keys: web3.AccountMeta[] = [
     {
@@ -78,6 +99,11 @@ export function createInitializeComponentInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.owner,
+      isWritable: false,
+      isSigner: true,
+    },
     {
       pubkey: accounts.componentProgram,
       isWritable: false,
@@ -89,7 +115,7 @@ export function createInitializeComponentInstruction(
       isSigner: false,
     },
     {
-      pubkey: accounts.instructionSysvarAccount,
+      pubkey: accounts.cpiAuth,
       isWritable: false,
       isSigner: false,
     },
@@ -98,6 +124,11 @@ export function createInitializeComponentInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.world,
+      isWritable: false,
+      isSigner: false,
+    },
   ];
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -14,8 +14,77 @@ export type World = {
     repository: "https://github.com/magicblock-labs/bolt";
   };
   instructions: [
+    {
+      name: "initializeRegistry";
+      discriminator: [189, 181, 20, 17, 174, 57, 249, 59];
+      accounts: [
+        {
+          name: "registry";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [114, 101, 103, 105, 115, 116, 114, 121];
+              },
+            ];
+          };
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "initializeNewWorld";
+      discriminator: [23, 96, 88, 194, 200, 203, 200, 98];
+      accounts: [
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "world";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [119, 111, 114, 108, 100];
+              },
+              {
+                kind: "account";
+                path: "registry.worlds";
+                account: "registry";
+              },
+            ];
+          };
+        },
+        {
+          name: "registry";
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+      ];
+      args: [];
+    },
     {
       name: "addAuthority";
+      docs: [
+        "Adds an authority to the world with the given roles. An authority which is already part of",
+        "the world keeps its roles, which are changed with `set_authority_roles`.",
+      ];
       discriminator: [229, 9, 106, 73, 91, 213, 109, 183];
       accounts: [
         {
@@ -52,24 +121,63 @@ export type World = {
           name: "worldId";
           type: "u64";
         },
+        {
+          name: "roles";
+          type: "u8";
+        },
       ];
     },
     {
-      name: "addEntity";
-      discriminator: [163, 241, 57, 35, 244, 244, 48, 57];
+      name: "claimWorldAdmin";
+      docs: [
+        "Makes the upgrade authority of the world program the admin of a world without admin, such",
+        "as the worlds created before the roles with no authority.",
+      ];
+      discriminator: [105, 16, 88, 187, 232, 152, 126, 95];
       accounts: [
         {
-          name: "payer";
+          name: "authority";
           writable: true;
           signer: true;
         },
         {
-          name: "entity";
+          name: "world";
           writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [119, 111, 114, 108, 100];
+              },
+              {
+                kind: "arg";
+                path: "worldId";
+              },
+            ];
+          };
         },
         {
-          name: "world";
-          writable: true;
+          name: "worldProgramData";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  7, 162, 209, 73, 239, 44, 43, 188, 110, 204, 75, 46, 143, 118,
+                  195, 133, 21, 171, 92, 248, 46, 178, 200, 26, 251, 83, 166,
+                  74, 44, 215, 113, 139,
+                ];
+              },
+            ];
+            program: {
+              kind: "const";
+              value: [
+                2, 168, 246, 145, 78, 136, 161, 176, 226, 16, 21, 62, 247, 99,
+                174, 43, 0, 194, 185, 61, 22, 193, 36, 210, 192, 83, 122, 16, 4,
+                128, 0, 0,
+              ];
+            };
+          };
         },
         {
           name: "systemProgram";
@@ -78,65 +186,181 @@ export type World = {
       ];
       args: [
         {
-          name: "extraSeed";
-          type: {
-            option: "bytes";
-          };
+          name: "worldId";
+          type: "u64";
         },
       ];
     },
     {
-      name: "apply";
-      discriminator: [248, 243, 145, 24, 105, 50, 162, 225];
+      name: "removeAuthority";
+      discriminator: [242, 104, 208, 132, 190, 250, 74, 216];
       accounts: [
-        {
-          name: "boltSystem";
-        },
         {
           name: "authority";
+          writable: true;
           signer: true;
         },
         {
-          name: "instructionSysvarAccount";
-          address: "Sysvar1nstructions1111111111111111111111111";
+          name: "authorityToDelete";
         },
         {
           name: "world";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [119, 111, 114, 108, 100];
+              },
+              {
+                kind: "arg";
+                path: "worldId";
+              },
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
         },
       ];
       args: [
         {
-          name: "args";
-          type: "bytes";
+          name: "worldId";
+          type: "u64";
         },
       ];
     },
     {
-      name: "applyWithSession";
-      discriminator: [213, 69, 29, 230, 142, 107, 134, 103];
+      name: "setAuthorityRoles";
+      discriminator: [15, 21, 149, 205, 96, 101, 244, 20];
       accounts: [
         {
-          name: "boltSystem";
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "authorityToUpdate";
+        },
+        {
+          name: "world";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [119, 111, 114, 108, 100];
+              },
+              {
+                kind: "arg";
+                path: "worldId";
+              },
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+      ];
+      args: [
+        {
+          name: "worldId";
+          type: "u64";
+        },
+        {
+          name: "roles";
+          type: "u8";
         },
+      ];
+    },
+    {
+      name: "setWorldState";
+      discriminator: [243, 39, 172, 77, 216, 108, 191, 138];
+      accounts: [
         {
           name: "authority";
+          writable: true;
           signer: true;
         },
         {
-          name: "instructionSysvarAccount";
-          address: "Sysvar1nstructions1111111111111111111111111";
+          name: "world";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [119, 111, 114, 108, 100];
+              },
+              {
+                kind: "arg";
+                path: "worldId";
+              },
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+      ];
+      args: [
+        {
+          name: "worldId";
+          type: "u64";
+        },
+        {
+          name: "state";
+          type: {
+            defined: {
+              name: "worldState";
+            };
+          };
+        },
+      ];
+    },
+    {
+      name: "setPausedSystems";
+      docs: ["Sets the systems which keep running while the world is paused."];
+      discriminator: [93, 173, 104, 77, 137, 249, 22, 20];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
         },
         {
           name: "world";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [119, 111, 114, 108, 100];
+              },
+              {
+                kind: "arg";
+                path: "worldId";
+              },
+            ];
+          };
         },
         {
-          name: "sessionToken";
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
         },
       ];
       args: [
         {
-          name: "args";
-          type: "bytes";
+          name: "worldId";
+          type: "u64";
+        },
+        {
+          name: "systems";
+          type: {
+            vec: "pubkey";
+          };
         },
       ];
     },
@@ -164,8 +388,11 @@ export type World = {
       args: [];
     },
     {
-      name: "destroyComponent";
-      discriminator: [40, 197, 69, 196, 67, 95, 219, 73];
+      name: "approveSystemWithComponents";
+      docs: [
+        "Approves the system, allowing it to write only the components of the given programs.",
+      ];
+      discriminator: [41, 244, 208, 81, 112, 240, 26, 100];
       accounts: [
         {
           name: "authority";
@@ -173,158 +400,890 @@ export type World = {
           signer: true;
         },
         {
-          name: "receiver";
+          name: "world";
           writable: true;
         },
         {
-          name: "componentProgram";
+          name: "system";
         },
         {
-          name: "componentProgramData";
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+      ];
+      args: [
+        {
+          name: "componentPrograms";
+          type: {
+            vec: "pubkey";
+          };
         },
+      ];
+    },
+    {
+      name: "removeSystem";
+      discriminator: [218, 80, 71, 80, 161, 130, 149, 120];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "world";
+          writable: true;
+        },
+        {
+          name: "system";
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "addEntity";
+      discriminator: [163, 241, 57, 35, 244, 244, 48, 57];
+      accounts: [
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "entity";
+          writable: true;
+        },
+        {
+          name: "world";
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+      ];
+      args: [
+        {
+          name: "extraSeed";
+          type: {
+            option: "bytes";
+          };
+        },
+      ];
+    },
+    {
+      name: "migrateEntity";
+      docs: [
+        "Binds an entity created before the entities recorded their world to the world it is",
+        "derived from, growing it to the current entity layout. The world checks reject the entity",
+        "until then.",
+      ];
+      discriminator: [66, 2, 251, 5, 41, 147, 41, 33];
+      accounts: [
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "entity";
+          writable: true;
+        },
+        {
+          name: "world";
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+      ];
+      args: [
+        {
+          name: "extraSeed";
+          type: {
+            option: "bytes";
+          };
+        },
+      ];
+    },
+    {
+      name: "initializeComponent";
+      docs: [
+        "Initializes the component of the entity. The instance seed tells apart several components",
+        "of the same type on the entity, `None` is the single instance derived from the entity alone.",
+      ];
+      discriminator: [36, 143, 233, 113, 12, 234, 61, 30];
+      accounts: [
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "data";
+          writable: true;
+        },
+        {
+          name: "entity";
+        },
+        {
+          name: "owner";
+          docs: [
+            "The owner of the entity, any signer for an entity without owner",
+          ];
+          signer: true;
+        },
+        {
+          name: "componentProgram";
+        },
+        {
+          name: "authority";
+        },
+        {
+          name: "cpiAuth";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 112, 105, 95, 97, 117, 116, 104];
+              },
+              {
+                kind: "account";
+                path: "componentProgram";
+              },
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "world";
+          relations: ["entity"];
+        },
+      ];
+      args: [
+        {
+          name: "instance";
+          type: {
+            option: "bytes";
+          };
+        },
+      ];
+    },
+    {
+      name: "initializeResource";
+      docs: [
+        "Initializes the resource of the world: the single component of its type in the world,",
+        "derived from the world rather than from an entity. Resources are created by the operators.",
+      ];
+      discriminator: [70, 60, 112, 210, 150, 26, 216, 144];
+      accounts: [
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "data";
+          writable: true;
+        },
+        {
+          name: "componentProgram";
+        },
+        {
+          name: "authority";
+        },
+        {
+          name: "cpiAuth";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 112, 105, 95, 97, 117, 116, 104];
+              },
+              {
+                kind: "account";
+                path: "componentProgram";
+              },
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "world";
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "destroyComponent";
+      discriminator: [40, 197, 69, 196, 67, 95, 219, 73];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "receiver";
+          writable: true;
+        },
+        {
+          name: "componentProgram";
+        },
+        {
+          name: "componentProgramData";
+        },
+        {
+          name: "entity";
+        },
+        {
+          name: "component";
+          writable: true;
+        },
+        {
+          name: "cpiAuth";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 112, 105, 95, 97, 117, 116, 104];
+              },
+              {
+                kind: "account";
+                path: "componentProgram";
+              },
+            ];
+          };
+        },
+        {
+          name: "moderatorAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [109, 111, 100, 101, 114, 97, 116, 111, 114];
+              },
+              {
+                kind: "account";
+                path: "world";
+              },
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "world";
+          relations: ["entity"];
+        },
+      ];
+      args: [
+        {
+          name: "instance";
+          type: {
+            option: "bytes";
+          };
+        },
+      ];
+    },
+    {
+      name: "destroyEntity";
+      docs: [
+        "Closes the entity, after destroying the components passed as (component program, component",
+        "program data, component, signer of the component program) quadruples in the remaining",
+        "accounts. The components follow the authority rules of `destroy_component`. The instance",
+        "seeds are given in the order of the quadruples, the components without an entry are single",
+        "instances.",
+      ];
+      discriminator: [194, 232, 158, 55, 193, 123, 73, 194];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "receiver";
+          writable: true;
+        },
+        {
+          name: "entity";
+          writable: true;
+        },
+        {
+          name: "world";
+        },
+        {
+          name: "moderatorAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [109, 111, 100, 101, 114, 97, 116, 111, 114];
+              },
+              {
+                kind: "account";
+                path: "world";
+              },
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+      ];
+      args: [
+        {
+          name: "extraSeed";
+          type: {
+            option: "bytes";
+          };
+        },
+        {
+          name: "instances";
+          type: {
+            vec: {
+              option: "bytes";
+            };
+          };
+        },
+      ];
+    },
+    {
+      name: "transferEntity";
+      docs: [
+        "Transfers the entity to a new owner. The components passed as (component program,",
+        "component program data, component, signer of the component program) quadruples in the",
+        "remaining accounts are handed over to the new owner as well, each derived from the entity",
+        "with the seeds of `components`. The components must be held by the owner, or by the world",
+        "program when the owner is a world admin or moderator, for whom the world signs with its",
+        "moderator authority. An entity without owner is handed out by the world admins and",
+        "moderators.",
+      ];
+      discriminator: [25, 212, 37, 29, 182, 114, 128, 248];
+      accounts: [
+        {
+          name: "owner";
+          signer: true;
+        },
+        {
+          name: "newOwner";
+        },
+        {
+          name: "entity";
+          writable: true;
+        },
+        {
+          name: "world";
+          relations: ["entity"];
+        },
+        {
+          name: "moderatorAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [109, 111, 100, 101, 114, 97, 116, 111, 114];
+              },
+              {
+                kind: "account";
+                path: "world";
+              },
+            ];
+          };
+        },
+      ];
+      args: [
+        {
+          name: "components";
+          type: {
+            vec: {
+              defined: {
+                name: "transferredComponent";
+              };
+            };
+          };
+        },
+      ];
+    },
+    {
+      name: "setComponentAuthority";
+      discriminator: [11, 89, 73, 168, 29, 216, 214, 83];
+      accounts: [
+        {
+          name: "authority";
+          signer: true;
+        },
+        {
+          name: "componentProgram";
+        },
+        {
+          name: "componentProgramData";
+        },
+        {
+          name: "component";
+          writable: true;
+        },
+        {
+          name: "cpiAuth";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 112, 105, 95, 97, 117, 116, 104];
+              },
+              {
+                kind: "account";
+                path: "componentProgram";
+              },
+            ];
+          };
+        },
+      ];
+      args: [
+        {
+          name: "newAuthority";
+          type: "pubkey";
+        },
+      ];
+    },
+    {
+      name: "migrateComponent";
+      discriminator: [181, 98, 175, 249, 236, 89, 99, 240];
+      accounts: [
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "component";
+          writable: true;
+        },
+        {
+          name: "componentProgram";
+        },
+        {
+          name: "componentProgramData";
+        },
+        {
+          name: "entity";
+        },
+        {
+          name: "authority";
+          signer: true;
+        },
+        {
+          name: "cpiAuth";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 112, 105, 95, 97, 117, 116, 104];
+              },
+              {
+                kind: "account";
+                path: "componentProgram";
+              },
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+      ];
+      args: [
+        {
+          name: "instance";
+          type: {
+            option: "bytes";
+          };
+        },
+      ];
+    },
+    {
+      name: "apply";
+      discriminator: [248, 243, 145, 24, 105, 50, 162, 225];
+      accounts: [
+        {
+          name: "boltSystem";
+        },
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "world";
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+      ];
+      args: [
+        {
+          name: "args";
+          type: "bytes";
+        },
+      ];
+    },
+    {
+      name: "applyWithSession";
+      discriminator: [213, 69, 29, 230, 142, 107, 134, 103];
+      accounts: [
+        {
+          name: "boltSystem";
+        },
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "world";
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "sessionToken";
+        },
+      ];
+      args: [
+        {
+          name: "args";
+          type: "bytes";
+        },
+      ];
+    },
+    {
+      name: "withdrawVault";
+      docs: ["Withdraws lamports from the vault of the world to the receiver."];
+      discriminator: [135, 7, 237, 120, 149, 94, 95, 7];
+      accounts: [
+        {
+          name: "authority";
+          signer: true;
+        },
+        {
+          name: "world";
+        },
+        {
+          name: "vault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "world";
+              },
+            ];
+          };
+        },
+        {
+          name: "receiver";
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        },
+      ];
+    },
+    {
+      name: "applyCommands";
+      docs: [
+        "Applies a system which issues commands along with its component updates. The commands run",
+        "after the updates, their accounts and the signers of their component programs are passed in",
+        "the extra accounts of the system. Their component programs must be listed for the system.",
+      ];
+      discriminator: [241, 230, 16, 67, 88, 138, 216, 198];
+      accounts: [
+        {
+          name: "boltSystem";
+        },
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "world";
+          writable: true;
+        },
+        {
+          name: "vault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "world";
+              },
+            ];
+          };
+        },
+        {
+          name: "systemAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [115, 121, 115, 116, 101, 109];
+              },
+              {
+                kind: "account";
+                path: "world";
+              },
+            ];
+          };
+        },
+        {
+          name: "worldProgram";
+          docs: ["The authority of the components spawned by the commands"];
+          address: "WorLD15A7CrDwLcLy4fRqtaTb9fbd8o8iqiEMUDse2n";
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+      ];
+      args: [
+        {
+          name: "args";
+          type: "bytes";
+        },
+      ];
+    },
+    {
+      name: "applyCommandsWithSession";
+      discriminator: [210, 79, 35, 181, 206, 70, 134, 150];
+      accounts: [
+        {
+          name: "boltSystem";
+        },
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "world";
+          writable: true;
+        },
+        {
+          name: "vault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "world";
+              },
+            ];
+          };
+        },
+        {
+          name: "systemAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [115, 121, 115, 116, 101, 109];
+              },
+              {
+                kind: "account";
+                path: "world";
+              },
+            ];
+          };
+        },
+        {
+          name: "worldProgram";
+          docs: ["The authority of the components spawned by the commands"];
+          address: "WorLD15A7CrDwLcLy4fRqtaTb9fbd8o8iqiEMUDse2n";
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "sessionToken";
+        },
+      ];
+      args: [
+        {
+          name: "args";
+          type: "bytes";
+        },
+      ];
+    },
+    {
+      name: "applyPatch";
+      discriminator: [210, 162, 110, 95, 89, 215, 136, 27];
+      accounts: [
+        {
+          name: "boltSystem";
+        },
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "world";
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+      ];
+      args: [
+        {
+          name: "args";
+          type: "bytes";
+        },
+      ];
+    },
+    {
+      name: "applyPatchWithSession";
+      discriminator: [186, 223, 252, 209, 231, 167, 194, 89];
+      accounts: [
         {
-          name: "entity";
+          name: "boltSystem";
         },
         {
-          name: "component";
+          name: "authority";
           writable: true;
+          signer: true;
         },
         {
-          name: "instructionSysvarAccount";
-          address: "Sysvar1nstructions1111111111111111111111111";
+          name: "world";
         },
         {
           name: "systemProgram";
           address: "11111111111111111111111111111111";
         },
+        {
+          name: "sessionToken";
+        },
+      ];
+      args: [
+        {
+          name: "args";
+          type: "bytes";
+        },
       ];
-      args: [];
     },
     {
-      name: "initializeComponent";
-      discriminator: [36, 143, 233, 113, 12, 234, 61, 30];
+      name: "applyBuffered";
+      discriminator: [83, 128, 164, 139, 57, 109, 100, 18];
       accounts: [
         {
-          name: "payer";
-          writable: true;
-          signer: true;
+          name: "boltSystem";
         },
         {
-          name: "data";
+          name: "authority";
           writable: true;
+          signer: true;
         },
         {
-          name: "entity";
-        },
-        {
-          name: "componentProgram";
+          name: "world";
         },
         {
-          name: "authority";
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
         },
         {
-          name: "instructionSysvarAccount";
-          address: "Sysvar1nstructions1111111111111111111111111";
+          name: "buffer";
+          writable: true;
         },
+      ];
+      args: [
         {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111";
+          name: "args";
+          type: "bytes";
         },
       ];
-      args: [];
     },
     {
-      name: "initializeNewWorld";
-      discriminator: [23, 96, 88, 194, 200, 203, 200, 98];
+      name: "applyBufferedWithSession";
+      discriminator: [193, 108, 77, 113, 220, 97, 252, 104];
       accounts: [
         {
-          name: "payer";
-          writable: true;
-          signer: true;
+          name: "boltSystem";
         },
         {
-          name: "world";
+          name: "authority";
           writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [119, 111, 114, 108, 100];
-              },
-              {
-                kind: "account";
-                path: "registry.worlds";
-                account: "registry";
-              },
-            ];
-          };
+          signer: true;
         },
         {
-          name: "registry";
-          writable: true;
+          name: "world";
         },
         {
           name: "systemProgram";
           address: "11111111111111111111111111111111";
         },
-      ];
-      args: [];
-    },
-    {
-      name: "initializeRegistry";
-      discriminator: [189, 181, 20, 17, 174, 57, 249, 59];
-      accounts: [
         {
-          name: "registry";
+          name: "buffer";
           writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [114, 101, 103, 105, 115, 116, 114, 121];
-              },
-            ];
-          };
         },
         {
-          name: "payer";
-          writable: true;
-          signer: true;
+          name: "sessionToken";
         },
+      ];
+      args: [
         {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111";
+          name: "args";
+          type: "bytes";
         },
       ];
-      args: [];
     },
     {
-      name: "removeAuthority";
-      discriminator: [242, 104, 208, 132, 190, 250, 74, 216];
+      name: "applyBatch";
+      discriminator: [37, 214, 173, 73, 212, 97, 227, 127];
       accounts: [
         {
           name: "authority";
           writable: true;
           signer: true;
         },
-        {
-          name: "authorityToDelete";
-        },
         {
           name: "world";
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                value: [119, 111, 114, 108, 100];
-              },
-              {
-                kind: "arg";
-                path: "worldId";
-              },
-            ];
-          };
         },
         {
           name: "systemProgram";
@@ -333,14 +1292,20 @@ export type World = {
       ];
       args: [
         {
-          name: "worldId";
-          type: "u64";
+          name: "systems";
+          type: {
+            vec: {
+              defined: {
+                name: "batchedSystem";
+              };
+            };
+          };
         },
       ];
     },
     {
-      name: "removeSystem";
-      discriminator: [218, 80, 71, 80, 161, 130, 149, 120];
+      name: "applyBatchWithSession";
+      discriminator: [240, 219, 176, 86, 217, 183, 224, 145];
       accounts: [
         {
           name: "authority";
@@ -349,17 +1314,27 @@ export type World = {
         },
         {
           name: "world";
-          writable: true;
-        },
-        {
-          name: "system";
         },
         {
           name: "systemProgram";
           address: "11111111111111111111111111111111";
         },
+        {
+          name: "sessionToken";
+        },
+      ];
+      args: [
+        {
+          name: "systems";
+          type: {
+            vec: {
+              defined: {
+                name: "batchedSystem";
+              };
+            };
+          };
+        },
       ];
-      args: [];
     },
   ];
   accounts: [
@@ -376,6 +1351,12 @@ export type World = {
       discriminator: [145, 45, 170, 174, 122, 32, 155, 124];
     },
   ];
+  events: [
+    {
+      name: "systemApplied";
+      discriminator: [246, 141, 165, 169, 122, 4, 142, 169];
+    },
+  ];
   errors: [
     {
       code: 6000;
@@ -407,10 +1388,118 @@ export type World = {
       name: "systemNotApproved";
       msg: "The system is not approved in this world instance";
     },
+    {
+      code: 6006;
+      name: "invalidPatch";
+      msg: "The system patch is out of the component bounds";
+    },
+    {
+      code: 6007;
+      name: "invalidOutputBuffer";
+      msg: "The output buffer must be owned by the system and not passed to it as a component or extra account";
+    },
+    {
+      code: 6008;
+      name: "missingRole";
+      msg: "The authority doesn't have the role required by the instruction";
+    },
+    {
+      code: 6009;
+      name: "lastAdmin";
+      msg: "The world must keep at least one admin";
+    },
+    {
+      code: 6010;
+      name: "worldPaused";
+      msg: "The world is paused";
+    },
+    {
+      code: 6011;
+      name: "worldFrozen";
+      msg: "The world is frozen";
+    },
+    {
+      code: 6012;
+      name: "componentNotAllowed";
+      msg: "The system is not allowed to write the component";
+    },
+    {
+      code: 6013;
+      name: "componentOwnerMismatch";
+      msg: "The component is not owned by the component program";
+    },
+    {
+      code: 6014;
+      name: "duplicateComponent";
+      msg: "The component is passed more than once";
+    },
+    {
+      code: 6015;
+      name: "entityWorldMismatch";
+      msg: "The entity belongs to another world";
+    },
+    {
+      code: 6016;
+      name: "componentAlreadyInitialized";
+      msg: "The component is already initialized";
+    },
+    {
+      code: 6017;
+      name: "worldHasAdmin";
+      msg: "The world already has an admin";
+    },
+    {
+      code: 6018;
+      name: "componentEntityMismatch";
+      msg: "The component is not derived from the entity";
+    },
   ];
   types: [
+    {
+      name: "authorityRoles";
+      docs: [
+        "The role bitmask of each world authority, in the order of the authorities.",
+      ];
+      type: {
+        kind: "struct";
+        fields: ["bytes"];
+      };
+    },
+    {
+      name: "batchedSystem";
+      docs: [
+        "A system invocation of an `apply_batch` instruction.",
+        "",
+        "The remaining accounts of the instruction hold, for every batched system and in order, the",
+        "system program followed by `components` (program, component) pairs and `extra_accounts`",
+        "extra accounts. The counts take the place of the world program id ending the components, while",
+        "the pairs marking an absent optional component or the end of a component set are counted. The",
+        "signers of the programs of the written components follow the accounts of the last system.",
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "components";
+            type: "u8";
+          },
+          {
+            name: "extraAccounts";
+            type: "u8";
+          },
+          {
+            name: "args";
+            type: "bytes";
+          },
+        ];
+      };
+    },
     {
       name: "entity";
+      docs: [
+        "An entity of a world. The account traits are implemented by hand rather than with `#[account]`,",
+        "so that the entities created before the owner and the world still deserialize.",
+      ];
       type: {
         kind: "struct";
         fields: [
@@ -418,6 +1507,22 @@ export type World = {
             name: "id";
             type: "u64";
           },
+          {
+            name: "owner";
+            docs: [
+              "The player the entity belongs to. Entities created before ownership have no owner.",
+            ];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "world";
+            docs: [
+              "The world the entity belongs to. Entities created before it have the default key.",
+            ];
+            type: "pubkey";
+          },
         ];
       };
     },
@@ -433,6 +1538,47 @@ export type World = {
         ];
       };
     },
+    {
+      name: "systemApplied";
+      docs: [
+        "Emitted when a system is applied to the world, once per system of a batch.",
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "worldId";
+            type: "u64";
+          },
+          {
+            name: "system";
+            type: "pubkey";
+          },
+        ];
+      };
+    },
+    {
+      name: "transferredComponent";
+      docs: [
+        "A component handed over by `transfer_entity`, with the seeds it is derived from along with the",
+        "entity.",
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "seed";
+            type: "bytes";
+          },
+          {
+            name: "instance";
+            type: {
+              option: "bytes";
+            };
+          },
+        ];
+      };
+    },
     {
       name: "world";
       type: {
@@ -460,6 +1606,64 @@ export type World = {
             name: "systems";
             type: "bytes";
           },
+          {
+            name: "roles";
+            type: {
+              defined: {
+                name: "authorityRoles";
+              };
+            };
+          },
+          {
+            name: "config";
+            type: {
+              defined: {
+                name: "worldConfig";
+              };
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "worldConfig";
+      docs: [
+        "The state of the world, and the systems which keep running while it is paused.",
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "state";
+            type: {
+              defined: {
+                name: "worldState";
+              };
+            };
+          },
+          {
+            name: "pausedSystems";
+            type: {
+              vec: "pubkey";
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "worldState";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "active";
+          },
+          {
+            name: "paused";
+          },
+          {
+            name: "frozen";
+          },
         ];
       };
     },
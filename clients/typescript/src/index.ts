@@ -21,6 +21,16 @@ export const SYSVAR_INSTRUCTIONS_PUBKEY = new PublicKey(
   "Sysvar1nstructions1111111111111111111111111",
 );
 
+/**
+ * The roles of the world authorities, combined as a bitmask
+ */
+export const ROLES = {
+  ADMIN: 1,
+  OPERATOR: 2,
+  MODERATOR: 4,
+  ALL: 7,
+};
+
 export function FindRegistryPda({ programId }: { programId?: PublicKey }) {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("registry")],
@@ -70,6 +80,36 @@ export function FindEntityPda({
   )[0];
 }
 
+export function FindVaultPda({
+  world,
+  programId,
+}: {
+  world: PublicKey;
+  programId?: PublicKey;
+}) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("vault"), world.toBytes()],
+    programId ?? WORLD_PROGRAM_ID,
+  )[0];
+}
+
+/**
+ * The signer of the world CPIs into the component program, passed along the components of the
+ * program written by the world
+ */
+export function FindCpiAuthPda({
+  componentId,
+  programId,
+}: {
+  componentId: PublicKey;
+  programId?: PublicKey;
+}) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("cpi_auth"), componentId.toBytes()],
+    programId ?? WORLD_PROGRAM_ID,
+  )[0];
+}
+
 export function FindSessionTokenPda({
   sessionSigner,
   authority,
@@ -104,23 +144,45 @@ export function FindComponentPda({
   componentId,
   entity,
   seed,
+  instance,
 }: {
   componentId: PublicKey;
   entity: PublicKey;
   seed?: string;
+  // Tells apart several components of the same type on the entity
+  instance?: Uint8Array;
+}) {
+  const seeds = [Buffer.from(seed ?? ""), entity.toBytes()];
+  if (instance !== undefined) {
+    seeds.push(Buffer.from(instance));
+  }
+  return PublicKey.findProgramAddressSync(seeds, componentId)[0];
+}
+
+export function FindResourcePda({
+  componentId,
+  world,
+  seed,
+}: {
+  componentId: PublicKey;
+  world: PublicKey;
+  seed?: string;
 }) {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(seed ?? ""), entity.toBytes()],
+    [Buffer.from(seed ?? ""), world.toBytes()],
     componentId,
   )[0];
 }
 
 /**
  * Serialize arguments to a buffer
- * @param args
+ * @param args encoded with JSON, unless already encoded, e.g. with Borsh
  * @constructor
  */
 export function SerializeArgs(args: any = {}) {
+  if (args instanceof Uint8Array) {
+    return Buffer.from(args);
+  }
   const jsonString = JSON.stringify(args);
   const encoder = new TextEncoder();
   const binaryData = encoder.encode(jsonString);
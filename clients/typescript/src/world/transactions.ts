@@ -1,15 +1,14 @@
 import {
   createApplyInstruction,
   createAddEntityInstruction,
-  createInitializeComponentInstruction,
   createInitializeNewWorldInstruction,
   FindComponentPda,
   FindEntityPda,
+  FindResourcePda,
   FindWorldPda,
   FindRegistryPda,
   Registry,
   SerializeArgs,
-  SYSVAR_INSTRUCTIONS_PUBKEY,
   World,
   SessionProgram,
   Session,
@@ -17,12 +16,15 @@ import {
   WORLD_PROGRAM_ID,
   BN,
   FindComponentProgramDataPda,
+  FindCpiAuthPda,
+  ROLES,
 } from "../index";
 import type web3 from "@solana/web3.js";
 import {
   type Connection,
   Keypair,
   type PublicKey,
+  SystemProgram,
   Transaction,
   type TransactionInstruction,
 } from "@solana/web3.js";
@@ -133,6 +135,7 @@ export async function InitializeNewWorld({
  * @param authority
  * @param newAuthority
  * @param world
+ * @param roles the roles of the new authority, all of them by default
  * @param connection
  * @constructor
  */
@@ -140,11 +143,13 @@ export async function AddAuthority({
   authority,
   newAuthority,
   world,
+  roles,
   connection,
 }: {
   authority: PublicKey;
   newAuthority: PublicKey;
   world: PublicKey;
+  roles?: number;
   connection: Connection;
 }): Promise<{
   instruction: TransactionInstruction;
@@ -156,7 +161,7 @@ export async function AddAuthority({
   const worldInstance = await World.fromAccountAddress(connection, world);
   const worldId = new BN(worldInstance.id);
   const instruction = await program.methods
-    .addAuthority(worldId)
+    .addAuthority(worldId, roles ?? ROLES.ALL)
     .accountsPartial({
       authority,
       newAuthority,
@@ -213,20 +218,26 @@ export async function RemoveAuthority({
 }
 
 /**
- * Create the transaction to Approve a system
+ * Create the transaction to Set the roles of an authority
  * @param authority
- * @param systemToApprove
+ * @param authorityToUpdate
  * @param world
+ * @param roles
+ * @param connection
  * @constructor
  */
-export async function ApproveSystem({
+export async function SetAuthorityRoles({
   authority,
-  systemToApprove,
+  authorityToUpdate,
   world,
+  roles,
+  connection,
 }: {
   authority: PublicKey;
-  systemToApprove: PublicKey;
+  authorityToUpdate: PublicKey;
   world: PublicKey;
+  roles: number;
+  connection: Connection;
 }): Promise<{
   instruction: TransactionInstruction;
   transaction: Transaction;
@@ -234,11 +245,140 @@ export async function ApproveSystem({
   const program = new Program(
     worldIdl as Idl,
   ) as unknown as Program<WorldProgram>;
+  const worldInstance = await World.fromAccountAddress(connection, world);
+  const worldId = new BN(worldInstance.id);
   const instruction = await program.methods
-    .approveSystem()
-    .accounts({
+    .setAuthorityRoles(worldId, roles)
+    .accountsPartial({
+      authority,
+      authorityToUpdate,
+      world,
+    })
+    .instruction();
+  const transaction = new Transaction().add(instruction);
+  return {
+    instruction,
+    transaction,
+  };
+}
+
+/**
+ * Create the transaction to Claim the admin role of a world without admin, signed by the upgrade
+ * authority of the world program
+ * @param authority
+ * @param world
+ * @param connection
+ * @constructor
+ */
+export async function ClaimWorldAdmin({
+  authority,
+  world,
+  connection,
+}: {
+  authority: PublicKey;
+  world: PublicKey;
+  connection: Connection;
+}): Promise<{
+  instruction: TransactionInstruction;
+  transaction: Transaction;
+}> {
+  const program = new Program(
+    worldIdl as Idl,
+  ) as unknown as Program<WorldProgram>;
+  const worldInstance = await World.fromAccountAddress(connection, world);
+  const worldId = new BN(worldInstance.id);
+  const instruction = await program.methods
+    .claimWorldAdmin(worldId)
+    .accountsPartial({
+      authority,
+      world,
+    })
+    .instruction();
+  const transaction = new Transaction().add(instruction);
+  return {
+    instruction,
+    transaction,
+  };
+}
+
+/**
+ * Create the transaction to Set the state of a world: active, paused, where only the paused
+ * systems run, or frozen
+ * @param authority an operator of the world
+ * @param world
+ * @param state
+ * @param connection
+ * @constructor
+ */
+export async function SetWorldState({
+  authority,
+  world,
+  state,
+  connection,
+}: {
+  authority: PublicKey;
+  world: PublicKey;
+  state: "active" | "paused" | "frozen";
+  connection: Connection;
+}): Promise<{
+  instruction: TransactionInstruction;
+  transaction: Transaction;
+}> {
+  const program = new Program(
+    worldIdl as Idl,
+  ) as unknown as Program<WorldProgram>;
+  const worldInstance = await World.fromAccountAddress(connection, world);
+  const worldId = new BN(worldInstance.id);
+  const worldState = {
+    active: { active: {} },
+    paused: { paused: {} },
+    frozen: { frozen: {} },
+  }[state];
+  const instruction = await program.methods
+    .setWorldState(worldId, worldState)
+    .accountsPartial({
+      authority,
+      world,
+    })
+    .instruction();
+  const transaction = new Transaction().add(instruction);
+  return {
+    instruction,
+    transaction,
+  };
+}
+
+/**
+ * Create the transaction to Set the systems which keep running while a world is paused
+ * @param authority an operator of the world
+ * @param world
+ * @param systems
+ * @param connection
+ * @constructor
+ */
+export async function SetPausedSystems({
+  authority,
+  world,
+  systems,
+  connection,
+}: {
+  authority: PublicKey;
+  world: PublicKey;
+  systems: PublicKey[];
+  connection: Connection;
+}): Promise<{
+  instruction: TransactionInstruction;
+  transaction: Transaction;
+}> {
+  const program = new Program(
+    worldIdl as Idl,
+  ) as unknown as Program<WorldProgram>;
+  const worldInstance = await World.fromAccountAddress(connection, world);
+  const worldId = new BN(worldInstance.id);
+  const instruction = await program.methods
+    .setPausedSystems(worldId, systems)
+    .accountsPartial({
       authority,
-      system: systemToApprove,
       world,
     })
     .instruction();
@@ -249,6 +389,50 @@ export async function ApproveSystem({
   };
 }
 
+/**
+ * Create the transaction to Approve a system
+ * @param authority
+ * @param systemToApprove
+ * @param world
+ * @param components the component programs the system may write, all of them by default
+ * @constructor
+ */
+export async function ApproveSystem({
+  authority,
+  systemToApprove,
+  world,
+  components,
+}: {
+  authority: PublicKey;
+  systemToApprove: PublicKey;
+  world: PublicKey;
+  components?: PublicKey[];
+}): Promise<{
+  instruction: TransactionInstruction;
+  transaction: Transaction;
+}> {
+  const program = new Program(
+    worldIdl as Idl,
+  ) as unknown as Program<WorldProgram>;
+  const accounts = {
+    authority,
+    system: systemToApprove,
+    world,
+  };
+  const instruction =
+    components !== undefined
+      ? await program.methods
+          .approveSystemWithComponents(components)
+          .accounts(accounts)
+          .instruction()
+      : await program.methods.approveSystem().accounts(accounts).instruction();
+  const transaction = new Transaction().add(instruction);
+  return {
+    instruction,
+    transaction,
+  };
+}
+
 /**
  * Create the transaction to Remove a system
  * @param authority
@@ -331,25 +515,23 @@ export async function AddEntity({
 }
 
 /**
- * Create the transaction to Destroy a component
- * @param authority
- * @param component
+ * Create the transaction to Migrate an entity created before the entities recorded their world
+ * @param payer
+ * @param entity
  * @param world
- * @param connection
+ * @param seed the extra seed of the entity, if it was created with one
  * @constructor
  */
-export async function DestroyComponent({
-  authority,
+export async function MigrateEntity({
+  payer,
   entity,
-  componentId,
-  receiver,
+  world,
   seed,
 }: {
-  authority: PublicKey;
+  payer: PublicKey;
   entity: PublicKey;
-  componentId: PublicKey;
-  receiver: PublicKey;
-  seed?: string;
+  world: PublicKey;
+  seed?: Uint8Array;
 }): Promise<{
   instruction: TransactionInstruction;
   transaction: Transaction;
@@ -357,20 +539,12 @@ export async function DestroyComponent({
   const program = new Program(
     worldIdl as Idl,
   ) as unknown as Program<WorldProgram>;
-  const componentProgramData = FindComponentProgramDataPda({
-    programId: componentId,
-  });
-  const componentProgram = componentId;
-  const component = FindComponentPda({ componentId, entity, seed });
   const instruction = await program.methods
-    .destroyComponent()
-    .accounts({
-      authority,
-      component,
+    .migrateEntity(seed !== undefined ? Buffer.from(seed) : null)
+    .accountsPartial({
+      payer,
       entity,
-      componentProgram,
-      componentProgramData,
-      receiver,
+      world,
     })
     .instruction();
   const transaction = new Transaction().add(instruction);
@@ -381,96 +555,557 @@ export async function DestroyComponent({
 }
 
 /**
- * Create the transaction to Initialize a new component
- * @param payer
- * @param entityPda
+ * Create the transaction to Migrate a component stored with a previous schema version, or with a
+ * shorter metadata, to the current one
+ * @param payer pays for the component growing
+ * @param authority the authority of the component, or the upgrade authority of its program
+ * @param entity
  * @param componentId
- * @param seeds
- * @param authority
- * @param anchorRemainingAccounts
+ * @param seed
+ * @param instance
  * @constructor
  */
-export async function InitializeComponent({
+export async function MigrateComponent({
   payer,
+  authority,
   entity,
   componentId,
-  seed = "",
-  authority,
-  anchorRemainingAccounts,
+  seed,
+  instance,
 }: {
   payer: PublicKey;
+  authority?: PublicKey;
   entity: PublicKey;
   componentId: PublicKey;
   seed?: string;
-  authority?: web3.PublicKey;
-  anchorRemainingAccounts?: web3.AccountMeta[];
+  instance?: Uint8Array;
 }): Promise<{
   instruction: TransactionInstruction;
   transaction: Transaction;
-  componentPda: PublicKey;
 }> {
-  const componentPda = FindComponentPda({ componentId, entity, seed });
-  const instruction = createInitializeComponentInstruction({
-    payer,
-    entity,
-    data: componentPda,
-    componentProgram: componentId,
-    authority: authority ?? PROGRAM_ID,
-    instructionSysvarAccount: SYSVAR_INSTRUCTIONS_PUBKEY,
-    anchorRemainingAccounts,
-  });
+  const program = new Program(
+    worldIdl as Idl,
+  ) as unknown as Program<WorldProgram>;
+  const instruction = await program.methods
+    .migrateComponent(instance !== undefined ? Buffer.from(instance) : null)
+    .accountsPartial({
+      payer,
+      component: FindComponentPda({ componentId, entity, seed, instance }),
+      componentProgram: componentId,
+      componentProgramData: FindComponentProgramDataPda({
+        programId: componentId,
+      }),
+      entity,
+      authority: authority ?? payer,
+    })
+    .instruction();
   const transaction = new Transaction().add(instruction);
   return {
     instruction,
     transaction,
-    componentPda,
   };
 }
 
-interface ApplySystemInstruction {
-  authority: PublicKey;
-  systemId: PublicKey;
-  entities: ApplySystemEntity[];
-  world: PublicKey;
-  session?: Session;
-  extraAccounts?: web3.AccountMeta[];
-  args?: any;
-}
-async function createApplySystemInstruction({
+/**
+ * Create the transaction to Set the authority of a component
+ * @param authority the current authority of the component, or the upgrade authority of its program
+ * @param newAuthority
+ * @param entity
+ * @param componentId
+ * @param seed
+ * @param instance
+ * @constructor
+ */
+export async function SetComponentAuthority({
   authority,
-  systemId,
-  entities,
-  world,
-  session,
-  extraAccounts,
-  args,
-}: ApplySystemInstruction): Promise<web3.TransactionInstruction> {
+  newAuthority,
+  entity,
+  componentId,
+  seed,
+  instance,
+}: {
+  authority: PublicKey;
+  newAuthority: PublicKey;
+  entity: PublicKey;
+  componentId: PublicKey;
+  seed?: string;
+  instance?: Uint8Array;
+}): Promise<{
+  instruction: TransactionInstruction;
+  transaction: Transaction;
+}> {
   const program = new Program(
     worldIdl as Idl,
   ) as unknown as Program<WorldProgram>;
-  let componentCount = 0;
-  entities.forEach(function (entity) {
-    componentCount += entity.components.length;
-  });
-  if (componentCount <= 0) {
-    throw new Error("No components provided");
-  }
+  const instruction = await program.methods
+    .setComponentAuthority(newAuthority)
+    .accountsPartial({
+      authority,
+      componentProgram: componentId,
+      componentProgramData: FindComponentProgramDataPda({
+        programId: componentId,
+      }),
+      component: FindComponentPda({ componentId, entity, seed, instance }),
+    })
+    .instruction();
+  const transaction = new Transaction().add(instruction);
+  return {
+    instruction,
+    transaction,
+  };
+}
 
+/**
+ * Create the transaction to Transfer an entity, and hand its components over to the new owner
+ * @param owner the owner of the entity, or an admin or moderator for an entity without owner
+ * @param newOwner
+ * @param entity
+ * @param world
+ * @param components the components of the entity whose authority becomes the new owner, held by the
+ * owner, or by the world program when the owner is an admin or moderator of the world
+ * @constructor
+ */
+export async function TransferEntity({
+  owner,
+  newOwner,
+  entity,
+  world,
+  components,
+}: {
+  owner: PublicKey;
+  newOwner: PublicKey;
+  entity: PublicKey;
+  world: PublicKey;
+  components?: {
+    componentId: PublicKey;
+    seed?: string;
+    instance?: Uint8Array;
+  }[];
+}): Promise<{
+  instruction: TransactionInstruction;
+  transaction: Transaction;
+}> {
+  const program = new Program(
+    worldIdl as Idl,
+  ) as unknown as Program<WorldProgram>;
+  const remainingAccounts: web3.AccountMeta[] = [];
+  for (const component of components ?? []) {
+    remainingAccounts.push(
+      {
+        pubkey: component.componentId,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: FindComponentProgramDataPda({
+          programId: component.componentId,
+        }),
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: FindComponentPda({
+          componentId: component.componentId,
+          entity,
+          seed: component.seed,
+          instance: component.instance,
+        }),
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: FindCpiAuthPda({ componentId: component.componentId }),
+        isSigner: false,
+        isWritable: false,
+      },
+    );
+  }
+  // The world derives every component from the entity with its seeds
+  const seeds = (components ?? []).map((component) => ({
+    seed: Buffer.from(component.seed ?? ""),
+    instance:
+      component.instance !== undefined ? Buffer.from(component.instance) : null,
+  }));
+  const instruction = await program.methods
+    .transferEntity(seeds)
+    .accountsPartial({
+      owner,
+      newOwner,
+      entity,
+      world,
+    })
+    .remainingAccounts(remainingAccounts)
+    .instruction();
+  const transaction = new Transaction().add(instruction);
+  return {
+    instruction,
+    transaction,
+  };
+}
+
+/**
+ * Create the transaction to Destroy a component
+ * @param authority
+ * @param entity
+ * @param componentId
+ * @param receiver
+ * @param world
+ * @param seed
+ * @param instance
+ * @constructor
+ */
+export async function DestroyComponent({
+  authority,
+  entity,
+  componentId,
+  receiver,
+  world,
+  seed,
+  instance,
+}: {
+  authority: PublicKey;
+  entity: PublicKey;
+  componentId: PublicKey;
+  receiver: PublicKey;
+  world: PublicKey;
+  seed?: string;
+  instance?: Uint8Array;
+}): Promise<{
+  instruction: TransactionInstruction;
+  transaction: Transaction;
+}> {
+  const program = new Program(
+    worldIdl as Idl,
+  ) as unknown as Program<WorldProgram>;
+  const componentProgramData = FindComponentProgramDataPda({
+    programId: componentId,
+  });
+  const componentProgram = componentId;
+  const component = FindComponentPda({ componentId, entity, seed, instance });
+  const instruction = await program.methods
+    .destroyComponent(instance !== undefined ? Buffer.from(instance) : null)
+    .accounts({
+      authority,
+      component,
+      entity,
+      componentProgram,
+      componentProgramData,
+      receiver,
+      world,
+    })
+    .instruction();
+  const transaction = new Transaction().add(instruction);
+  return {
+    instruction,
+    transaction,
+  };
+}
+
+/**
+ * Create the transaction to Destroy an entity, along with its components
+ * @param authority the owner of the entity, or a moderator of the world
+ * @param entity
+ * @param receiver
+ * @param world
+ * @param seed the extra seed of the entity, if it was created with one
+ * @param components the components of the entity destroyed before it
+ * @constructor
+ */
+export async function DestroyEntity({
+  authority,
+  entity,
+  receiver,
+  world,
+  seed,
+  components,
+}: {
+  authority: PublicKey;
+  entity: PublicKey;
+  receiver: PublicKey;
+  world: PublicKey;
+  seed?: Uint8Array;
+  components?: {
+    componentId: PublicKey;
+    seed?: string;
+    instance?: Uint8Array;
+  }[];
+}): Promise<{
+  instruction: TransactionInstruction;
+  transaction: Transaction;
+}> {
+  const program = new Program(
+    worldIdl as Idl,
+  ) as unknown as Program<WorldProgram>;
+  const remainingAccounts: web3.AccountMeta[] = [];
+  for (const component of components ?? []) {
+    remainingAccounts.push(
+      {
+        pubkey: component.componentId,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: FindComponentProgramDataPda({
+          programId: component.componentId,
+        }),
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: FindComponentPda({
+          componentId: component.componentId,
+          entity,
+          seed: component.seed,
+          instance: component.instance,
+        }),
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: FindCpiAuthPda({ componentId: component.componentId }),
+        isSigner: false,
+        isWritable: false,
+      },
+    );
+  }
+  const instances = (components ?? []).map((component) =>
+    component.instance !== undefined ? Buffer.from(component.instance) : null,
+  );
+  const instruction = await program.methods
+    .destroyEntity(seed !== undefined ? Buffer.from(seed) : null, instances)
+    .accountsPartial({
+      authority,
+      receiver,
+      entity,
+      world,
+    })
+    .remainingAccounts(remainingAccounts)
+    .instruction();
+  const transaction = new Transaction().add(instruction);
+  return {
+    instruction,
+    transaction,
+  };
+}
+
+/**
+ * Create the transaction to Initialize a new component
+ * @param payer
+ * @param entity
+ * @param world
+ * @param componentId
+ * @param seed
+ * @param instance
+ * @param owner the owner of the entity, who signs the transaction, the payer by default
+ * @param authority
+ * @param anchorRemainingAccounts
+ * @constructor
+ */
+export async function InitializeComponent({
+  payer,
+  entity,
+  world,
+  componentId,
+  seed = "",
+  instance,
+  owner,
+  authority,
+  anchorRemainingAccounts,
+}: {
+  payer: PublicKey;
+  entity: PublicKey;
+  world: PublicKey;
+  componentId: PublicKey;
+  seed?: string;
+  instance?: Uint8Array;
+  owner?: PublicKey;
+  authority?: web3.PublicKey;
+  anchorRemainingAccounts?: web3.AccountMeta[];
+}): Promise<{
+  instruction: TransactionInstruction;
+  transaction: Transaction;
+  componentPda: PublicKey;
+}> {
+  const program = new Program(
+    worldIdl as Idl,
+  ) as unknown as Program<WorldProgram>;
+  const componentPda = FindComponentPda({
+    componentId,
+    entity,
+    seed,
+    instance,
+  });
+  const instruction = await program.methods
+    .initializeComponent(instance !== undefined ? Buffer.from(instance) : null)
+    .accounts({
+      payer,
+      entity,
+      owner: owner ?? payer,
+      data: componentPda,
+      componentProgram: componentId,
+      authority: authority ?? PROGRAM_ID,
+      world,
+    })
+    .remainingAccounts(anchorRemainingAccounts ?? [])
+    .instruction();
+  const transaction = new Transaction().add(instruction);
+  return {
+    instruction,
+    transaction,
+    componentPda,
+  };
+}
+
+/**
+ * Create the transaction to Initialize a resource of the world
+ * @param payer
+ * @param world
+ * @param componentId
+ * @param seed
+ * @param authority
+ * @constructor
+ */
+export async function InitializeResource({
+  payer,
+  world,
+  componentId,
+  seed = "",
+  authority,
+}: {
+  payer: PublicKey;
+  world: PublicKey;
+  componentId: PublicKey;
+  seed?: string;
+  authority?: web3.PublicKey;
+}): Promise<{
+  instruction: TransactionInstruction;
+  transaction: Transaction;
+  resourcePda: PublicKey;
+}> {
+  const program = new Program(
+    worldIdl as Idl,
+  ) as unknown as Program<WorldProgram>;
+  const resourcePda = FindResourcePda({ componentId, world, seed });
+  const instruction = await program.methods
+    .initializeResource()
+    .accounts({
+      payer,
+      data: resourcePda,
+      componentProgram: componentId,
+      authority: authority ?? PROGRAM_ID,
+      world,
+    })
+    .instruction();
+  const transaction = new Transaction().add(instruction);
+  return {
+    instruction,
+    transaction,
+    resourcePda,
+  };
+}
+
+/**
+ * Create the transaction to Withdraw lamports from the vault of the world
+ * @param authority an admin of the world
+ * @param world
+ * @param receiver
+ * @param amount
+ * @constructor
+ */
+export async function WithdrawVault({
+  authority,
+  world,
+  receiver,
+  amount,
+}: {
+  authority: PublicKey;
+  world: PublicKey;
+  receiver: PublicKey;
+  amount: BN;
+}): Promise<{
+  instruction: TransactionInstruction;
+  transaction: Transaction;
+}> {
+  const program = new Program(
+    worldIdl as Idl,
+  ) as unknown as Program<WorldProgram>;
+  const instruction = await program.methods
+    .withdrawVault(amount)
+    .accountsPartial({
+      authority,
+      world,
+      receiver,
+    })
+    .instruction();
+  const transaction = new Transaction().add(instruction);
+  return {
+    instruction,
+    transaction,
+  };
+}
+
+/**
+ * The (program, component) pairs of the components a system is applied to
+ */
+function componentPairs({
+  entities,
+  resources,
+  world,
+  worldProgramId,
+}: {
+  entities: ApplySystemEntity[];
+  resources?: ApplySystemResource[];
+  world: PublicKey;
+  worldProgramId: PublicKey;
+}): web3.AccountMeta[] {
+  let componentCount = resources?.length ?? 0;
+  entities.forEach(function (entity) {
+    componentCount += entity.components.length;
+  });
+  if (componentCount <= 0) {
+    throw new Error("No components provided");
+  }
   let remainingAccounts: web3.AccountMeta[] = [];
-  let components: { id: PublicKey; pda: PublicKey }[] = [];
+  let components: { id: PublicKey; pda: PublicKey; readOnly: boolean }[] = [];
   for (const entity of entities) {
     for (const component of entity.components) {
-      const componentPda = FindComponentPda({
-        componentId: component.componentId,
-        entity: entity.entity,
-        seed: component.seed,
-      });
+      // The world program id in place of a component marks an absent optional
+      // component
+      const componentPda = component.absent
+        ? worldProgramId
+        : FindComponentPda({
+            componentId: component.componentId,
+            entity: entity.entity,
+            seed: component.seed,
+            instance: component.instance,
+          });
       components.push({
         id: component.componentId,
         pda: componentPda,
+        readOnly: (component.readOnly ?? false) || (component.absent ?? false),
       });
+      // And ends a component set after its last component
+      if (component.endsSet) {
+        components.push({
+          id: component.componentId,
+          pda: worldProgramId,
+          readOnly: true,
+        });
+      }
     }
   }
+  // The resources follow the components of the entities
+  for (const resource of resources ?? []) {
+    components.push({
+      id: resource.componentId,
+      pda: FindResourcePda({
+        componentId: resource.componentId,
+        world,
+        seed: resource.seed,
+      }),
+      readOnly: resource.readOnly ?? false,
+    });
+  }
   for (const component of components) {
     remainingAccounts.push({
       pubkey: component.id,
@@ -480,22 +1115,172 @@ async function createApplySystemInstruction({
     remainingAccounts.push({
       pubkey: component.pda,
       isSigner: false,
-      isWritable: true,
+      isWritable: !component.readOnly,
     });
   }
+  return remainingAccounts;
+}
+
+/**
+ * The signers of the world CPIs into the programs of the written components,
+ * once per program
+ */
+function cpiAuths(
+  pairs: web3.AccountMeta[],
+  worldProgramId: PublicKey,
+): web3.AccountMeta[] {
+  const signers: web3.AccountMeta[] = [];
+  for (let i = 0; i < pairs.length; i += 2) {
+    const [program, component] = [pairs[i], pairs[i + 1]];
+    if (!component.isWritable || component.pubkey.equals(worldProgramId)) {
+      continue;
+    }
+    const pubkey = FindCpiAuthPda({
+      componentId: program.pubkey,
+      programId: worldProgramId,
+    });
+    if (!signers.some((signer) => signer.pubkey.equals(pubkey))) {
+      signers.push({ pubkey, isSigner: false, isWritable: false });
+    }
+  }
+  return signers;
+}
+
+interface ApplySystemInstruction {
+  authority: PublicKey;
+  systemId: PublicKey;
+  entities: ApplySystemEntity[];
+  resources?: ApplySystemResource[];
+  world: PublicKey;
+  session?: Session;
+  extraAccounts?: web3.AccountMeta[];
+  args?: any;
+  commands?: boolean;
+  patch?: boolean;
+  buffer?: PublicKey;
+}
+async function createApplySystemInstruction({
+  authority,
+  systemId,
+  entities,
+  resources,
+  world,
+  session,
+  extraAccounts,
+  args,
+  commands,
+  patch,
+  buffer,
+}: ApplySystemInstruction): Promise<web3.TransactionInstruction> {
+  const program = new Program(
+    worldIdl as Idl,
+  ) as unknown as Program<WorldProgram>;
+  if (commands && patch) {
+    throw new Error("The systems issuing commands can't be applied as patches");
+  }
+  if (buffer && (commands || patch)) {
+    throw new Error(
+      "The systems writing an output buffer can't issue commands or patches",
+    );
+  }
+
+  let remainingAccounts = componentPairs({
+    entities,
+    resources,
+    world,
+    worldProgramId: program.programId,
+  });
   let extraAccountsInput = extraAccounts ?? [];
-  if (extraAccountsInput.length > 0) {
+  // The signers of the programs of the written components follow the extra
+  // accounts
+  const signers = cpiAuths(remainingAccounts, program.programId).filter(
+    (signer) =>
+      !extraAccountsInput.some((account) =>
+        account.pubkey.equals(signer.pubkey),
+      ),
+  );
+  if (extraAccountsInput.length > 0 || signers.length > 0) {
+    // The program id in place of a component program ends the component list
     remainingAccounts.push({
-      pubkey: program.programId, // program id delimits the end of the component list
+      pubkey: program.programId,
       isSigner: false,
       isWritable: false,
     });
     for (const account of extraAccountsInput) {
       remainingAccounts.push(account);
     }
+    remainingAccounts.push(...signers);
   }
 
-  if (session)
+  // The systems issuing commands are applied with the vault of the world, which pays for them
+  if (commands && session)
+    return program.methods
+      .applyCommandsWithSession(SerializeArgs(args))
+      .accounts({
+        authority: authority ?? PROGRAM_ID,
+        boltSystem: systemId,
+        sessionToken: session.token,
+        world,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+  else if (commands)
+    return program.methods
+      .applyCommands(SerializeArgs(args))
+      .accounts({
+        authority: authority ?? PROGRAM_ID,
+        boltSystem: systemId,
+        world,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+  // The patched components only receive the bytes changed by the system
+  else if (patch && session)
+    return program.methods
+      .applyPatchWithSession(SerializeArgs(args))
+      .accounts({
+        authority: authority ?? PROGRAM_ID,
+        boltSystem: systemId,
+        sessionToken: session.token,
+        world,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+  else if (patch)
+    return program.methods
+      .applyPatch(SerializeArgs(args))
+      .accounts({
+        authority: authority ?? PROGRAM_ID,
+        boltSystem: systemId,
+        world,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+  // The outputs are read back from the buffer instead of the return data
+  else if (buffer && session)
+    return program.methods
+      .applyBufferedWithSession(SerializeArgs(args))
+      .accounts({
+        authority: authority ?? PROGRAM_ID,
+        boltSystem: systemId,
+        sessionToken: session.token,
+        world,
+        buffer,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+  else if (buffer)
+    return program.methods
+      .applyBuffered(SerializeArgs(args))
+      .accounts({
+        authority: authority ?? PROGRAM_ID,
+        boltSystem: systemId,
+        world,
+        buffer,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+  else if (session)
     return program.methods
       .applyWithSession(SerializeArgs(args))
       .accounts({
@@ -522,9 +1307,24 @@ interface ApplySystemEntity {
   entity: PublicKey;
   components: ApplySystemComponent[];
 }
+interface ApplySystemResource {
+  componentId: PublicKey;
+  seed?: string;
+  // Must be set for the resources marked as #[read_only] in the system input
+  readOnly?: boolean;
+}
 interface ApplySystemComponent {
   componentId: PublicKey;
   seed?: string;
+  // Must be set for the components initialized with an instance seed
+  instance?: Uint8Array;
+  // Must be set for the components marked as #[read_only] in the system input
+  readOnly?: boolean;
+  // Passes an optional component of the system input as absent, or ends an
+  // empty component set
+  absent?: boolean;
+  // Must be set on the last component of a set, a Vec of the system input
+  endsSet?: boolean;
 }
 
 /**
@@ -532,35 +1332,51 @@ interface ApplySystemComponent {
  * @param authority
  * @param systemId
  * @param entities
+ * @param resources
  * @param extraAccounts
- * @param args
+ * @param args encoded with JSON, or passed as is when already encoded, e.g. with Borsh
+ * @param commands must be set for the systems which return commands, whose accounts and the signers of their component programs, found with FindCpiAuthPda, are passed in the extra accounts. The component programs of the commands must be listed for the system
+ * @param patch writes back only the bytes of the components changed by the system
+ * @param buffer an account owned by the system, created with CreateOutputBuffer, holding outputs larger than the return data
  * @constructor
  */
 export async function ApplySystem({
   authority,
   systemId,
   entities,
+  resources,
   world,
   extraAccounts,
   args,
   session,
+  commands,
+  patch,
+  buffer,
 }: {
   authority: PublicKey;
   systemId: PublicKey;
   entities: ApplySystemEntity[];
+  resources?: ApplySystemResource[];
   world: PublicKey;
   extraAccounts?: web3.AccountMeta[];
   args?: any;
   session?: Session;
+  commands?: boolean;
+  patch?: boolean;
+  buffer?: PublicKey;
 }): Promise<{ instruction: TransactionInstruction; transaction: Transaction }> {
   const instruction = await createApplySystemInstruction({
     authority,
     systemId,
     entities,
+    resources,
     world,
     extraAccounts,
     args,
     session,
+    commands,
+    patch,
+    buffer,
   });
   const transaction = new Transaction().add(instruction);
   return {
@@ -568,3 +1384,138 @@ export async function ApplySystem({
     transaction,
   };
 }
+
+/**
+ * Create the output buffer of a system, an account owned by the system which
+ * holds its outputs when they don't fit in the return data. Only the system can
+ * write the buffer, so the world reads the outputs back from it.
+ * @param connection
+ * @param payer
+ * @param systemId
+ * @param space the size of the serialized outputs, at most
+ * @param buffer the keypair of the buffer, which signs the transaction
+ * @constructor
+ */
+export async function CreateOutputBuffer({
+  connection,
+  payer,
+  systemId,
+  space,
+  buffer,
+}: {
+  connection: Connection;
+  payer: PublicKey;
+  systemId: PublicKey;
+  space: number;
+  buffer?: Keypair;
+}): Promise<{
+  instruction: TransactionInstruction;
+  transaction: Transaction;
+  buffer: Keypair;
+}> {
+  buffer = buffer ?? Keypair.generate();
+  const lamports = await connection.getMinimumBalanceForRentExemption(space);
+  const instruction = SystemProgram.createAccount({
+    fromPubkey: payer,
+    newAccountPubkey: buffer.publicKey,
+    lamports,
+    space,
+    programId: systemId,
+  });
+  const transaction = new Transaction().add(instruction);
+  return {
+    instruction,
+    transaction,
+    buffer,
+  };
+}
+
+interface ApplyBatchSystem {
+  systemId: PublicKey;
+  entities: ApplySystemEntity[];
+  resources?: ApplySystemResource[];
+  extraAccounts?: web3.AccountMeta[];
+  args?: any;
+}
+
+/**
+ * Apply several systems in one instruction, writing back every updated
+ * component once at the end. A component written by a system of the batch may
+ * be read by another system of the batch as read-only.
+ * @param authority
+ * @param world
+ * @param systems applied in order, each one observes the updates of the systems
+ * before it
+ * @param session
+ * @constructor
+ */
+export async function ApplyBatch({
+  authority,
+  world,
+  systems,
+  session,
+}: {
+  authority: PublicKey;
+  world: PublicKey;
+  systems: ApplyBatchSystem[];
+  session?: Session;
+}): Promise<{ instruction: TransactionInstruction; transaction: Transaction }> {
+  const program = new Program(
+    worldIdl as Idl,
+  ) as unknown as Program<WorldProgram>;
+  const batchedSystems: {
+    components: number;
+    extraAccounts: number;
+    args: Buffer;
+  }[] = [];
+  const remainingAccounts: web3.AccountMeta[] = [];
+  const writtenPairs: web3.AccountMeta[] = [];
+  // Each system is followed by its component pairs and its extra accounts,
+  // which are counted instead of delimited
+  for (const system of systems) {
+    const pairs = componentPairs({
+      entities: system.entities,
+      resources: system.resources,
+      world,
+      worldProgramId: program.programId,
+    });
+    const extraAccounts = system.extraAccounts ?? [];
+    remainingAccounts.push(
+      { pubkey: system.systemId, isSigner: false, isWritable: false },
+      ...pairs,
+      ...extraAccounts,
+    );
+    writtenPairs.push(...pairs);
+    batchedSystems.push({
+      components: pairs.length / 2,
+      extraAccounts: extraAccounts.length,
+      args: SerializeArgs(system.args),
+    });
+  }
+  // The signers of the programs of the written components follow the last
+  // system
+  remainingAccounts.push(...cpiAuths(writtenPairs, program.programId));
+  const instruction = session
+    ? await program.methods
+        .applyBatchWithSession(batchedSystems)
+        .accounts({
+          authority,
+          sessionToken: session.token,
+          world,
+        })
+        .remainingAccounts(remainingAccounts)
+        .instruction()
+    : await program.methods
+        .applyBatch(batchedSystems)
+        .accounts({
+          authority,
+          world,
+        })
+        .remainingAccounts(remainingAccounts)
+        .instruction();
+  const transaction = new Transaction().add(instruction);
+  return {
+    instruction,
+    transaction,
+  };
+}
@@ -12,8 +12,21 @@ import { type World } from "../../../target/types/world";
 import { type Position } from "../../../target/types/position";
 import { type Velocity } from "../../../target/types/velocity";
 import { type SystemSimpleMovement } from "../../../target/types/system_simple_movement";
+import { type SystemBorshMovement } from "../../../target/types/system_borsh_movement";
 import { type SystemFly } from "../../../target/types/system_fly";
 import { type SystemApplyVelocity } from "../../../target/types/system_apply_velocity";
+import { type SystemFollow } from "../../../target/types/system_follow";
+import { type SystemLegacy } from "../../../target/types/system_legacy";
+import { type Counter } from "../../../target/types/counter";
+import { type SystemCounter } from "../../../target/types/system_counter";
+import { type SystemSpawn } from "../../../target/types/system_spawn";
+import { type SystemDescribe } from "../../../target/types/system_describe";
+import { type SystemDrift } from "../../../target/types/system_drift";
+import { type SystemMoveAll } from "../../../target/types/system_move_all";
+import { type Grid } from "../../../target/types/grid";
+import { type SystemPaint } from "../../../target/types/system_paint";
+import { type WorldCaller } from "../../../target/types/world_caller";
+import { type EscrowFunding } from "../../../target/types/escrow_funding";
 import { Connection, Keypair, PublicKey } from "@solana/web3.js";
 import { Small } from "../../../target/types/small";
 import { With1Component } from "../../../target/types/with_1_component";
@@ -34,8 +47,21 @@ export class Framework {
   exampleComponentPosition: anchor.Program<Position>;
   exampleComponentVelocity: anchor.Program<Velocity>;
   systemSimpleMovement: anchor.Program<SystemSimpleMovement>;
+  systemBorshMovement: anchor.Program<SystemBorshMovement>;
   systemFly: anchor.Program<SystemFly>;
   systemApplyVelocity: anchor.Program<SystemApplyVelocity>;
+  systemFollow: anchor.Program<SystemFollow>;
+  systemLegacy: anchor.Program<SystemLegacy>;
+  exampleComponentCounter: anchor.Program<Counter>;
+  systemCounter: anchor.Program<SystemCounter>;
+  systemSpawn: anchor.Program<SystemSpawn>;
+  systemDescribe: anchor.Program<SystemDescribe>;
+  systemDrift: anchor.Program<SystemDrift>;
+  systemMoveAll: anchor.Program<SystemMoveAll>;
+  componentGrid: anchor.Program<Grid>;
+  systemPaint: anchor.Program<SystemPaint>;
+  worldCaller: anchor.Program<WorldCaller>;
+  systemEscrowFunding: anchor.Program<EscrowFunding>;
   systemWith1Component: anchor.Program<With1Component>;
   systemWith2Components: anchor.Program<With2Components>;
   systemWith3Components: anchor.Program<With3Components>;
@@ -48,6 +74,7 @@ export class Framework {
   systemWith10Components: anchor.Program<With10Components>;
   componentSmall: anchor.Program<Small>;
   worldPda: PublicKey;
+  secondWorldPda: PublicKey;
   worldId: BN;
 
   secondAuthority: PublicKey;
@@ -59,8 +86,10 @@ export class Framework {
 
   acceleratedComponentPositionPda: PublicKey;
   componentPositionEntity1Pda: PublicKey;
+  componentPositionEntity2Pda: PublicKey;
   componentVelocityEntity1Pda: PublicKey;
   componentPositionEntity4Pda: PublicKey;
+  counterResourcePda: PublicKey;
 
   constructor() {
     this.secondAuthority = Keypair.generate().publicKey;
@@ -68,8 +97,21 @@ export class Framework {
     this.exampleComponentPosition = anchor.workspace.Position;
     this.exampleComponentVelocity = anchor.workspace.Velocity;
     this.systemSimpleMovement = anchor.workspace.SystemSimpleMovement;
+    this.systemBorshMovement = anchor.workspace.SystemBorshMovement;
     this.systemFly = anchor.workspace.SystemFly;
     this.systemApplyVelocity = anchor.workspace.SystemApplyVelocity;
+    this.systemFollow = anchor.workspace.SystemFollow;
+    this.systemLegacy = anchor.workspace.SystemLegacy;
+    this.exampleComponentCounter = anchor.workspace.Counter;
+    this.systemCounter = anchor.workspace.SystemCounter;
+    this.systemSpawn = anchor.workspace.SystemSpawn;
+    this.systemDescribe = anchor.workspace.SystemDescribe;
+    this.systemDrift = anchor.workspace.SystemDrift;
+    this.systemMoveAll = anchor.workspace.SystemMoveAll;
+    this.componentGrid = anchor.workspace.Grid;
+    this.systemPaint = anchor.workspace.SystemPaint;
+    this.worldCaller = anchor.workspace.WorldCaller;
+    this.systemEscrowFunding = anchor.workspace.EscrowFunding;
     this.componentSmall = anchor.workspace.Small;
     this.systemWith1Component = anchor.workspace.With1Component;
     this.systemWith2Components = anchor.workspace.With2Components;
@@ -32,6 +32,7 @@ export function acceleration(framework: Framework) {
       const createAcceleratedComponentPosition = await InitializeComponent({
         payer: framework.provider.wallet.publicKey,
         entity: framework.acceleratedEntityPda,
+        world: framework.worldPda,
         componentId: framework.exampleComponentPosition.programId,
       });
 
@@ -0,0 +1,130 @@
+import { web3, AddEntity, ApplyBatch, InitializeComponent } from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+
+export function batch(framework: Framework) {
+  describe("Batch", () => {
+    const entities: web3.PublicKey[] = [];
+    const positionPdas: web3.PublicKey[] = [];
+
+    // Moves the Position of every entity
+    function moveAll() {
+      const componentId = framework.exampleComponentPosition.programId;
+      return {
+        systemId: framework.systemMoveAll.programId,
+        entities: entities.map((entity, index) => ({
+          entity,
+          components: [{ componentId, endsSet: index === entities.length - 1 }],
+        })),
+      };
+    }
+
+    async function positionsX() {
+      const positions = await Promise.all(
+        positionPdas.map((positionPda) =>
+          framework.exampleComponentPosition.account.position.fetch(
+            positionPda,
+          ),
+        ),
+      );
+      return positions.map((position) => position.x.toNumber());
+    }
+
+    it("Add two entities with a Position", async () => {
+      for (let i = 0; i < 2; i++) {
+        const addEntity = await AddEntity({
+          payer: framework.provider.wallet.publicKey,
+          world: framework.worldPda,
+          connection: framework.provider.connection,
+        });
+        await framework.provider.sendAndConfirm(addEntity.transaction);
+        entities.push(addEntity.entityPda);
+
+        const initializeComponent = await InitializeComponent({
+          payer: framework.provider.wallet.publicKey,
+          entity: addEntity.entityPda,
+          world: framework.worldPda,
+          componentId: framework.exampleComponentPosition.programId,
+        });
+        await framework.provider.sendAndConfirm(
+          initializeComponent.transaction,
+        );
+        positionPdas.push(initializeComponent.componentPda);
+      }
+    });
+
+    it("Apply a batch with a system issuing commands should fail", async () => {
+      const applyBatch = await ApplyBatch({
+        authority: framework.provider.wallet.publicKey,
+        world: framework.worldPda,
+        systems: [
+          moveAll(),
+          {
+            systemId: framework.systemSpawn.programId,
+            entities: [
+              {
+                entity: entities[0],
+                components: [
+                  { componentId: framework.exampleComponentPosition.programId },
+                ],
+              },
+            ],
+            args: { action: "Spawn", instance: "batched" },
+          },
+        ],
+      });
+      try {
+        await framework.provider.sendAndConfirm(applyBatch.transaction);
+        assert.fail("The commands were issued in a batch");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: UnsupportedCommands",
+        );
+      }
+      expect(await positionsX()).to.deep.equal([0, 0]);
+    });
+
+    it("Apply a batch passes the updates forward to the next systems", async () => {
+      const applyBatch = await ApplyBatch({
+        authority: framework.provider.wallet.publicKey,
+        world: framework.worldPda,
+        systems: [moveAll(), moveAll(), moveAll()],
+      });
+      await framework.provider.sendAndConfirm(applyBatch.transaction);
+      expect(await positionsX()).to.deep.equal([3, 3]);
+    });
+
+    it("Apply a batch with a reader of a component written earlier in the batch", async () => {
+      const componentId = framework.exampleComponentPosition.programId;
+      // The second Position follows the first one, which it only reads, after
+      // the first one is moved
+      const applyBatch = await ApplyBatch({
+        authority: framework.provider.wallet.publicKey,
+        world: framework.worldPda,
+        systems: [
+          {
+            systemId: framework.systemMoveAll.programId,
+            entities: [
+              {
+                entity: entities[0],
+                components: [{ componentId, endsSet: true }],
+              },
+            ],
+          },
+          {
+            systemId: framework.systemFollow.programId,
+            entities: [
+              { entity: entities[1], components: [{ componentId }] },
+              {
+                entity: entities[0],
+                components: [{ componentId, readOnly: true }],
+              },
+            ],
+          },
+        ],
+      });
+      await framework.provider.sendAndConfirm(applyBatch.transaction);
+      expect(await positionsX()).to.deep.equal([4, 4]);
+    });
+  });
+}
@@ -0,0 +1,93 @@
+import {
+  anchor,
+  web3,
+  AddEntity,
+  ApplySystem,
+  InitializeComponent,
+} from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+
+export function borsh(framework: Framework) {
+  describe("Borsh arguments", () => {
+    let entityPda: web3.PublicKey;
+    let positionPda: web3.PublicKey;
+
+    // The arguments type is the argument of the system input in the IDL
+    function encodeArgs(dx: number, dy: number, dz: number) {
+      const program = framework.systemBorshMovement;
+      const input = program.idl.instructions.find(
+        (instruction) => instruction.name === "boltSystemInput",
+      );
+      const argsType = (input.args[0].type as any).defined.name;
+      return program.coder.types.encode(argsType, {
+        dx: new anchor.BN(dx),
+        dy: new anchor.BN(dy),
+        dz: new anchor.BN(dz),
+      });
+    }
+
+    async function applyMovement(args: Buffer) {
+      return ApplySystem({
+        authority: framework.provider.wallet.publicKey,
+        systemId: framework.systemBorshMovement.programId,
+        world: framework.worldPda,
+        entities: [
+          {
+            entity: entityPda,
+            components: [
+              { componentId: framework.exampleComponentPosition.programId },
+            ],
+          },
+        ],
+        args,
+      });
+    }
+
+    async function position() {
+      const position =
+        await framework.exampleComponentPosition.account.position.fetch(
+          positionPda,
+        );
+      return [position.x, position.y, position.z].map((v) => v.toNumber());
+    }
+
+    it("Add an entity with a Position", async () => {
+      const addEntity = await AddEntity({
+        payer: framework.provider.wallet.publicKey,
+        world: framework.worldPda,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(addEntity.transaction);
+      entityPda = addEntity.entityPda;
+
+      const initializeComponent = await InitializeComponent({
+        payer: framework.provider.wallet.publicKey,
+        entity: entityPda,
+        world: framework.worldPda,
+        componentId: framework.exampleComponentPosition.programId,
+      });
+      await framework.provider.sendAndConfirm(initializeComponent.transaction);
+      positionPda = initializeComponent.componentPda;
+    });
+
+    it("Apply Borsh Movement System with malformed arguments should fail", async () => {
+      const applySystem = await applyMovement(Buffer.from([1, 2, 3]));
+      try {
+        await framework.provider.sendAndConfirm(applySystem.transaction);
+        assert.fail("The malformed arguments were decoded");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: InvalidArguments",
+        );
+      }
+      expect(await position()).to.deep.equal([0, 0, 0]);
+    });
+
+    it("Apply Borsh Movement System with Borsh arguments", async () => {
+      const applySystem = await applyMovement(encodeArgs(1, 2, 3));
+      await framework.provider.sendAndConfirm(applySystem.transaction);
+      expect(await position()).to.deep.equal([1, 2, 3]);
+    });
+  });
+}
@@ -0,0 +1,113 @@
+import {
+  web3,
+  AddEntity,
+  ApplySystem,
+  CreateOutputBuffer,
+  InitializeComponent,
+} from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+
+export function buffered(framework: Framework) {
+  describe("Output buffer", () => {
+    // Enough Positions for the outputs to outgrow the 1024 bytes of return data
+    const count = 12;
+    const positionPdas: web3.PublicKey[] = [];
+    const entities: web3.PublicKey[] = [];
+
+    async function createBuffer(systemId: web3.PublicKey) {
+      const createBuffer = await CreateOutputBuffer({
+        connection: framework.provider.connection,
+        payer: framework.provider.wallet.publicKey,
+        systemId,
+        space: 2048,
+      });
+      await framework.provider.sendAndConfirm(createBuffer.transaction, [
+        createBuffer.buffer,
+      ]);
+      return createBuffer.buffer.publicKey;
+    }
+
+    async function applyMoveAll(buffer: web3.PublicKey) {
+      const componentId = framework.exampleComponentPosition.programId;
+      const applySystem = await ApplySystem({
+        authority: framework.provider.wallet.publicKey,
+        systemId: framework.systemMoveAll.programId,
+        world: framework.worldPda,
+        entities: entities.map((entity, index) => ({
+          entity,
+          components: [
+            { componentId, endsSet: index === entities.length - 1 },
+          ],
+        })),
+        buffer,
+      });
+      return new web3.Transaction()
+        .add(
+          web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 1_000_000 }),
+        )
+        .add(applySystem.instruction);
+    }
+
+    async function positionsX() {
+      const positions = await Promise.all(
+        positionPdas.map((positionPda) =>
+          framework.exampleComponentPosition.account.position.fetch(
+            positionPda,
+          ),
+        ),
+      );
+      return positions.map((position) => position.x.toNumber());
+    }
+
+    it("Add entities with a Position", async () => {
+      for (let i = 0; i < count; i++) {
+        const addEntity = await AddEntity({
+          payer: framework.provider.wallet.publicKey,
+          world: framework.worldPda,
+          connection: framework.provider.connection,
+        });
+        await framework.provider.sendAndConfirm(addEntity.transaction);
+        entities.push(addEntity.entityPda);
+
+        const initializeComponent = await InitializeComponent({
+          payer: framework.provider.wallet.publicKey,
+          entity: addEntity.entityPda,
+          world: framework.worldPda,
+          componentId: framework.exampleComponentPosition.programId,
+        });
+        await framework.provider.sendAndConfirm(
+          initializeComponent.transaction,
+        );
+        positionPdas.push(initializeComponent.componentPda);
+      }
+    });
+
+    it("Apply Move All System with a buffer of another system should fail", async () => {
+      const buffer = await createBuffer(framework.systemDescribe.programId);
+      const transaction = await applyMoveAll(buffer);
+      try {
+        await framework.provider.sendAndConfirm(transaction);
+        assert.fail("The outputs were read from a buffer of another system");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: InvalidOutputBuffer",
+        );
+      }
+      expect(await positionsX()).to.deep.equal(new Array(count).fill(0));
+    });
+
+    it("Apply Move All System reads the outputs back from its buffer", async () => {
+      const buffer = await createBuffer(framework.systemMoveAll.programId);
+      const transaction = await applyMoveAll(buffer);
+      await framework.provider.sendAndConfirm(transaction);
+      expect(await positionsX()).to.deep.equal(new Array(count).fill(1));
+
+      // The outputs didn't fit in the return data
+      const account =
+        await framework.provider.connection.getAccountInfo(buffer);
+      expect(account.data.readUInt32LE(0)).to.equal(count);
+      expect(account.data.subarray(1024).some((byte) => byte !== 0)).to.be.true;
+    });
+  });
+}
@@ -0,0 +1,201 @@
+import {
+  web3,
+  ApplySystem,
+  ApproveSystem,
+  FindComponentPda,
+  FindComponentProgramDataPda,
+  FindCpiAuthPda,
+  FindVaultPda,
+  InitializeComponent,
+  RemoveSystem,
+  WORLD_PROGRAM_ID,
+} from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+
+export function commands(framework: Framework) {
+  describe("Commands", () => {
+    // The accounts of the commands spawning or despawning the velocity of the instance on entity 1
+    function commandAccounts(instance: string): web3.AccountMeta[] {
+      const componentId = framework.exampleComponentVelocity.programId;
+      return [
+        { pubkey: componentId, isWritable: false, isSigner: false },
+        {
+          pubkey: FindComponentPda({
+            componentId,
+            entity: framework.entity1Pda,
+            seed: "component-velocity",
+            instance: Buffer.from(instance),
+          }),
+          isWritable: true,
+          isSigner: false,
+        },
+        { pubkey: framework.entity1Pda, isWritable: false, isSigner: false },
+        {
+          pubkey: FindComponentProgramDataPda({ programId: componentId }),
+          isWritable: false,
+          isSigner: false,
+        },
+        {
+          pubkey: FindCpiAuthPda({ componentId }),
+          isWritable: false,
+          isSigner: false,
+        },
+      ];
+    }
+
+    async function applySpawn(action: string, instance: string) {
+      return ApplySystem({
+        authority: framework.provider.wallet.publicKey,
+        systemId: framework.systemSpawn.programId,
+        world: framework.worldPda,
+        entities: [
+          {
+            entity: framework.entity1Pda,
+            components: [
+              { componentId: framework.exampleComponentPosition.programId },
+            ],
+          },
+        ],
+        extraAccounts: commandAccounts(instance),
+        args: { action, instance },
+        commands: true,
+      });
+    }
+
+    it("Fund the vault of the world, which pays for the commands", async () => {
+      const transaction = new web3.Transaction().add(
+        web3.SystemProgram.transfer({
+          fromPubkey: framework.provider.wallet.publicKey,
+          toPubkey: FindVaultPda({ world: framework.worldPda }),
+          lamports: web3.LAMPORTS_PER_SOL,
+        }),
+      );
+      await framework.provider.sendAndConfirm(transaction);
+    });
+
+    it("Apply Spawn System without the commands entrypoint should fail", async () => {
+      const applySystem = await ApplySystem({
+        authority: framework.provider.wallet.publicKey,
+        systemId: framework.systemSpawn.programId,
+        world: framework.worldPda,
+        entities: [
+          {
+            entity: framework.entity1Pda,
+            components: [
+              { componentId: framework.exampleComponentPosition.programId },
+            ],
+          },
+        ],
+        extraAccounts: commandAccounts("spawned"),
+        args: { action: "Spawn", instance: "spawned" },
+      });
+      try {
+        await framework.provider.sendAndConfirm(applySystem.transaction);
+        assert.fail("The commands were issued outside of apply_commands");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: UnsupportedCommands",
+        );
+      }
+    });
+
+    it("Apply Spawn System not approved for the Velocity should fail", async () => {
+      const applySystem = await applySpawn("Spawn", "spawned");
+      try {
+        await framework.provider.sendAndConfirm(applySystem.transaction);
+        assert.fail("The system spawned a component it isn't allowed to");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: ComponentNotAllowed",
+        );
+      }
+    });
+
+    it("Approve Spawn System for the Position and the Velocity", async () => {
+      const approveSystem = await ApproveSystem({
+        authority: framework.provider.wallet.publicKey,
+        systemToApprove: framework.systemSpawn.programId,
+        world: framework.worldPda,
+        components: [
+          framework.exampleComponentPosition.programId,
+          framework.exampleComponentVelocity.programId,
+        ],
+      });
+      await framework.provider.sendAndConfirm(approveSystem.transaction);
+    });
+
+    it("Apply Spawn System spawns a Velocity on Entity 1", async () => {
+      const vault = FindVaultPda({ world: framework.worldPda });
+      const vaultBalance =
+        await framework.provider.connection.getBalance(vault);
+      const applySystem = await applySpawn("Spawn", "spawned");
+      await framework.provider.sendAndConfirm(applySystem.transaction);
+
+      const velocityPda = commandAccounts("spawned")[1].pubkey;
+      const velocity =
+        await framework.exampleComponentVelocity.account.velocity.fetch(
+          velocityPda,
+        );
+      expect(velocity.x.toNumber()).to.equal(1);
+      expect(velocity.boltMetadata.authority.toBase58()).to.equal(
+        WORLD_PROGRAM_ID.toBase58(),
+      );
+      // The vault pays the rent of the Velocity
+      expect(
+        vaultBalance - (await framework.provider.connection.getBalance(vault)),
+      ).to.equal(await framework.provider.connection.getBalance(velocityPda));
+    });
+
+    it("Apply Spawn System despawns the Velocity it spawned", async () => {
+      const applySystem = await applySpawn("Despawn", "spawned");
+      await framework.provider.sendAndConfirm(applySystem.transaction);
+
+      const velocity = await framework.provider.connection.getAccountInfo(
+        commandAccounts("spawned")[1].pubkey,
+      );
+      expect(velocity).to.be.null;
+    });
+
+    it("Apply Spawn System despawning a Velocity of the player should fail", async () => {
+      const initializeComponent = await InitializeComponent({
+        payer: framework.provider.wallet.publicKey,
+        entity: framework.entity1Pda,
+        world: framework.worldPda,
+        componentId: framework.exampleComponentVelocity.programId,
+        seed: "component-velocity",
+        instance: Buffer.from("player"),
+        authority: framework.provider.wallet.publicKey,
+      });
+      await framework.provider.sendAndConfirm(initializeComponent.transaction);
+
+      const applySystem = await applySpawn("Despawn", "player");
+      try {
+        await framework.provider.sendAndConfirm(applySystem.transaction);
+        assert.fail("The system destroyed a component of the player");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: InvalidAuthority",
+        );
+      }
+      const velocity = await framework.provider.connection.getAccountInfo(
+        initializeComponent.componentPda,
+      );
+      expect(velocity).to.not.be.null;
+    });
+
+    it("Remove Spawn System, which leaves the world permissionless", async () => {
+      const removeSystem = await RemoveSystem({
+        authority: framework.provider.wallet.publicKey,
+        systemToRemove: framework.systemSpawn.programId,
+        world: framework.worldPda,
+      });
+      await framework.provider.sendAndConfirm(removeSystem.transaction);
+
+      const world = await framework.worldProgram.account.world.fetch(
+        framework.worldPda,
+      );
+      expect(world.permissionless).to.equal(true);
+    });
+  });
+}
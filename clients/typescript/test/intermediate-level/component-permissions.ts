@@ -0,0 +1,96 @@
+import {
+  web3,
+  AddEntity,
+  ApplySystem,
+  ApproveSystem,
+  InitializeComponent,
+  InitializeNewWorld,
+} from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+
+export function componentPermissions(framework: Framework) {
+  describe("Component permissions", () => {
+    const authority = framework.provider.wallet.publicKey;
+    let worldPda: web3.PublicKey;
+    let entityPda: web3.PublicKey;
+    let positionPda: web3.PublicKey;
+
+    async function approveFly(components: web3.PublicKey[]) {
+      const approveSystem = await ApproveSystem({
+        authority,
+        systemToApprove: framework.systemFly.programId,
+        world: worldPda,
+        components,
+      });
+      await framework.provider.sendAndConfirm(approveSystem.transaction);
+    }
+
+    async function applyFly() {
+      return ApplySystem({
+        authority,
+        systemId: framework.systemFly.programId,
+        world: worldPda,
+        entities: [
+          {
+            entity: entityPda,
+            components: [
+              { componentId: framework.exampleComponentPosition.programId },
+            ],
+          },
+        ],
+      });
+    }
+
+    it("Initialize a world with an entity and a Position", async () => {
+      const initializeNewWorld = await InitializeNewWorld({
+        payer: authority,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(initializeNewWorld.transaction);
+      worldPda = initializeNewWorld.worldPda;
+
+      const addEntity = await AddEntity({
+        payer: authority,
+        world: worldPda,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(addEntity.transaction);
+      entityPda = addEntity.entityPda;
+
+      const initializeComponent = await InitializeComponent({
+        payer: authority,
+        entity: entityPda,
+        world: worldPda,
+        componentId: framework.exampleComponentPosition.programId,
+      });
+      await framework.provider.sendAndConfirm(initializeComponent.transaction);
+      positionPda = initializeComponent.componentPda;
+    });
+
+    it("Apply Fly System approved for the Velocity only should fail", async () => {
+      await approveFly([framework.exampleComponentVelocity.programId]);
+      const applySystem = await applyFly();
+      try {
+        await framework.provider.sendAndConfirm(applySystem.transaction);
+        assert.fail("The system wrote a component it isn't allowed to");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: ComponentNotAllowed",
+        );
+      }
+    });
+
+    it("Apply Fly System approved for the Position", async () => {
+      await approveFly([framework.exampleComponentPosition.programId]);
+      const applySystem = await applyFly();
+      await framework.provider.sendAndConfirm(applySystem.transaction);
+
+      const position =
+        await framework.exampleComponentPosition.account.position.fetch(
+          positionPda,
+        );
+      expect(position.z.toNumber()).to.equal(1);
+    });
+  });
+}
@@ -0,0 +1,97 @@
+import { web3, AddEntity, ApplySystem, InitializeComponent } from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+
+export function componentValidation(framework: Framework) {
+  describe("Component validation", () => {
+    const entities: web3.PublicKey[] = [];
+    const positionPdas: web3.PublicKey[] = [];
+
+    async function applyMoveAll(
+      setEntities: web3.PublicKey[],
+      componentId = framework.exampleComponentPosition.programId,
+    ) {
+      return ApplySystem({
+        authority: framework.provider.wallet.publicKey,
+        systemId: framework.systemMoveAll.programId,
+        world: framework.worldPda,
+        entities: setEntities.map((entity, index) => ({
+          entity,
+          components: [
+            { componentId, endsSet: index === setEntities.length - 1 },
+          ],
+        })),
+      });
+    }
+
+    async function positionsX() {
+      const positions = await Promise.all(
+        positionPdas.map((positionPda) =>
+          framework.exampleComponentPosition.account.position.fetch(
+            positionPda,
+          ),
+        ),
+      );
+      return positions.map((position) => position.x.toNumber());
+    }
+
+    it("Add two entities with a Position", async () => {
+      for (let i = 0; i < 2; i++) {
+        const addEntity = await AddEntity({
+          payer: framework.provider.wallet.publicKey,
+          world: framework.worldPda,
+          connection: framework.provider.connection,
+        });
+        await framework.provider.sendAndConfirm(addEntity.transaction);
+        entities.push(addEntity.entityPda);
+
+        const initializeComponent = await InitializeComponent({
+          payer: framework.provider.wallet.publicKey,
+          entity: addEntity.entityPda,
+          world: framework.worldPda,
+          componentId: framework.exampleComponentPosition.programId,
+        });
+        await framework.provider.sendAndConfirm(
+          initializeComponent.transaction,
+        );
+        positionPdas.push(initializeComponent.componentPda);
+      }
+    });
+
+    it("Apply Move All System with the same Position twice should fail", async () => {
+      const applySystem = await applyMoveAll([entities[0], entities[0]]);
+      try {
+        await framework.provider.sendAndConfirm(applySystem.transaction);
+        assert.fail("The same Position was written twice");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: DuplicateComponent",
+        );
+      }
+      expect(await positionsX()).to.deep.equal([0, 0]);
+    });
+
+    it("Apply Move All System with a component of another program should fail", async () => {
+      // The Velocity program owns none of the accounts derived for the entities
+      const applySystem = await applyMoveAll(
+        entities,
+        framework.exampleComponentVelocity.programId,
+      );
+      try {
+        await framework.provider.sendAndConfirm(applySystem.transaction);
+        assert.fail("A component was passed with another program");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: ComponentOwnerMismatch",
+        );
+      }
+      expect(await positionsX()).to.deep.equal([0, 0]);
+    });
+
+    it("Apply Move All System with distinct Positions", async () => {
+      const applySystem = await applyMoveAll(entities);
+      await framework.provider.sendAndConfirm(applySystem.transaction);
+      expect(await positionsX()).to.deep.equal([1, 1]);
+    });
+  });
+}
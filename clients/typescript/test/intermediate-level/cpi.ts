@@ -0,0 +1,132 @@
+import {
+  web3,
+  AddEntity,
+  FindCpiAuthPda,
+  InitializeComponent,
+  SerializeArgs,
+} from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+
+export function cpi(framework: Framework) {
+  describe("Cross-program invocation", () => {
+    let positionPda: web3.PublicKey;
+
+    async function positionZ() {
+      const position =
+        await framework.exampleComponentPosition.account.position.fetch(
+          positionPda,
+        );
+      return position.z.toNumber();
+    }
+
+    it("Add an entity with a Position", async () => {
+      const addEntity = await AddEntity({
+        payer: framework.provider.wallet.publicKey,
+        world: framework.worldPda,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(addEntity.transaction);
+
+      const initializeComponent = await InitializeComponent({
+        payer: framework.provider.wallet.publicKey,
+        entity: addEntity.entityPda,
+        world: framework.worldPda,
+        componentId: framework.exampleComponentPosition.programId,
+      });
+      await framework.provider.sendAndConfirm(initializeComponent.transaction);
+      positionPda = initializeComponent.componentPda;
+    });
+
+    it("Update the Position without the world should fail", async () => {
+      // A signer of its own in place of the signer of the world
+      const cpiAuth = web3.Keypair.generate();
+      try {
+        await framework.exampleComponentPosition.methods
+          .update(Buffer.from(""))
+          .accounts({
+            boltComponent: positionPda,
+            authority: framework.provider.wallet.publicKey,
+            cpiAuth: cpiAuth.publicKey,
+            world: framework.worldPda,
+          })
+          .signers([cpiAuth])
+          .rpc();
+        assert.fail("The Position was updated without the world");
+      } catch (error) {
+        expect(error.message).to.contain("Error Code: InvalidCaller");
+      }
+      expect(await positionZ()).to.equal(0);
+    });
+
+    it("Apply Fly System through a program calling the world", async () => {
+      await framework.worldCaller.methods
+        .applySystem(SerializeArgs())
+        .accountsPartial({
+          boltSystem: framework.systemFly.programId,
+          authority: framework.provider.wallet.publicKey,
+          world: framework.worldPda,
+        })
+        .remainingAccounts([
+          {
+            pubkey: framework.exampleComponentPosition.programId,
+            isSigner: false,
+            isWritable: false,
+          },
+          { pubkey: positionPda, isSigner: false, isWritable: true },
+          {
+            pubkey: framework.worldProgram.programId,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: FindCpiAuthPda({
+              componentId: framework.exampleComponentPosition.programId,
+            }),
+            isSigner: false,
+            isWritable: false,
+          },
+        ])
+        .rpc();
+      expect(await positionZ()).to.equal(1);
+    });
+
+    it("Re-use the signer of the world from the program calling the world should fail", async () => {
+      // The program applies Fly through the world, then writes the Position with the signer of
+      // the Position program, which the world only signs for within its own CPIs
+      const cpiAuth = FindCpiAuthPda({
+        componentId: framework.exampleComponentPosition.programId,
+      });
+      try {
+        await framework.worldCaller.methods
+          .applySystemAndUpdate(SerializeArgs(), Buffer.from(""))
+          .accountsPartial({
+            boltSystem: framework.systemFly.programId,
+            authority: framework.provider.wallet.publicKey,
+            world: framework.worldPda,
+          })
+          .remainingAccounts([
+            {
+              pubkey: framework.exampleComponentPosition.programId,
+              isSigner: false,
+              isWritable: false,
+            },
+            { pubkey: positionPda, isSigner: false, isWritable: true },
+            {
+              pubkey: framework.worldProgram.programId,
+              isSigner: false,
+              isWritable: false,
+            },
+            { pubkey: cpiAuth, isSigner: false, isWritable: false },
+          ])
+          .rpc();
+        assert.fail("The program calling the world re-used its signer");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          `${cpiAuth.toBase58()}'s signer privilege escalated`,
+        );
+      }
+      expect(await positionZ()).to.equal(1);
+    });
+  });
+}
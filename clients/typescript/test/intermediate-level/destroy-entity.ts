@@ -0,0 +1,126 @@
+import { web3, AddEntity, DestroyEntity, InitializeComponent } from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+
+export function destroyEntity(framework: Framework) {
+  describe("Destroy entity", () => {
+    const receiver = web3.Keypair.generate().publicKey;
+    let entityPda: web3.PublicKey;
+    let positionPda: web3.PublicKey;
+    let velocityPda: web3.PublicKey;
+
+    async function applyDestroy(authority: web3.PublicKey) {
+      return DestroyEntity({
+        authority,
+        entity: entityPda,
+        receiver,
+        world: framework.worldPda,
+        components: [
+          { componentId: framework.exampleComponentPosition.programId },
+          {
+            componentId: framework.exampleComponentVelocity.programId,
+            seed: "component-velocity",
+          },
+        ],
+      });
+    }
+
+    it("Add an entity with a Position and a Velocity held by its owner", async () => {
+      const addEntity = await AddEntity({
+        payer: framework.provider.wallet.publicKey,
+        world: framework.worldPda,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(addEntity.transaction);
+      entityPda = addEntity.entityPda;
+
+      const initializePosition = await InitializeComponent({
+        payer: framework.provider.wallet.publicKey,
+        entity: entityPda,
+        world: framework.worldPda,
+        componentId: framework.exampleComponentPosition.programId,
+        authority: framework.provider.wallet.publicKey,
+      });
+      await framework.provider.sendAndConfirm(initializePosition.transaction);
+      positionPda = initializePosition.componentPda;
+
+      const initializeVelocity = await InitializeComponent({
+        payer: framework.provider.wallet.publicKey,
+        entity: entityPda,
+        world: framework.worldPda,
+        componentId: framework.exampleComponentVelocity.programId,
+        seed: "component-velocity",
+        authority: framework.provider.wallet.publicKey,
+      });
+      await framework.provider.sendAndConfirm(initializeVelocity.transaction);
+      velocityPda = initializeVelocity.componentPda;
+    });
+
+    it("Destroy the entity by another authority should fail", async () => {
+      const other = web3.Keypair.generate();
+      const destroy = await applyDestroy(other.publicKey);
+      try {
+        await framework.provider.sendAndConfirm(destroy.transaction, [other]);
+        assert.fail("The entity was destroyed by another authority");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: InvalidAuthority",
+        );
+      }
+      const entity =
+        await framework.provider.connection.getAccountInfo(entityPda);
+      expect(entity).to.not.be.null;
+    });
+
+    it("Destroy the entity and its components by its owner", async () => {
+      const destroy = await applyDestroy(framework.provider.wallet.publicKey);
+      await framework.provider.sendAndConfirm(destroy.transaction);
+
+      const connection = framework.provider.connection;
+      const accounts = await connection.getMultipleAccountsInfo([
+        entityPda,
+        positionPda,
+        velocityPda,
+      ]);
+      expect(accounts).to.deep.equal([null, null, null]);
+      // The rent of the entity and of its components goes to the receiver
+      expect(await connection.getBalance(receiver)).to.be.greaterThan(0);
+    });
+
+    it("Destroy an entity holding a Position of another player by a moderator", async () => {
+      const addEntity = await AddEntity({
+        payer: framework.provider.wallet.publicKey,
+        world: framework.worldPda,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(addEntity.transaction);
+      entityPda = addEntity.entityPda;
+      const initializePosition = await InitializeComponent({
+        payer: framework.provider.wallet.publicKey,
+        entity: entityPda,
+        world: framework.worldPda,
+        componentId: framework.exampleComponentPosition.programId,
+        authority: web3.Keypair.generate().publicKey,
+      });
+      await framework.provider.sendAndConfirm(initializePosition.transaction);
+
+      // The world signs for its moderator, the wallet, with its moderator authority
+      const destroy = await DestroyEntity({
+        authority: framework.provider.wallet.publicKey,
+        entity: entityPda,
+        receiver,
+        world: framework.worldPda,
+        components: [
+          { componentId: framework.exampleComponentPosition.programId },
+        ],
+      });
+      await framework.provider.sendAndConfirm(destroy.transaction);
+      const accounts =
+        await framework.provider.connection.getMultipleAccountsInfo([
+          entityPda,
+          initializePosition.componentPda,
+        ]);
+      expect(accounts).to.deep.equal([null, null]);
+    });
+  });
+}
@@ -6,7 +6,7 @@ import {
   DestroyComponent,
 } from "../../lib";
 import { Direction, Framework } from "../framework";
-import { expect } from "chai";
+import { assert, expect } from "chai";
 
 export function ecs(framework: Framework) {
   describe("ECS", () => {
@@ -54,6 +54,7 @@ export function ecs(framework: Framework) {
       const initializeComponent = await InitializeComponent({
         payer: framework.provider.wallet.publicKey,
         entity: framework.entity1Pda,
+        world: framework.worldPda,
         componentId: framework.exampleComponentVelocity.programId,
         seed: "component-velocity",
         authority: framework.provider.wallet.publicKey,
@@ -66,6 +67,7 @@ export function ecs(framework: Framework) {
       const initializeComponent = await InitializeComponent({
         payer: framework.provider.wallet.publicKey,
         entity: framework.entity1Pda,
+        world: framework.worldPda,
         componentId: framework.exampleComponentPosition.programId,
       });
       await framework.provider.sendAndConfirm(initializeComponent.transaction);
@@ -76,15 +78,18 @@ export function ecs(framework: Framework) {
       const initializeComponent = await InitializeComponent({
         payer: framework.provider.wallet.publicKey,
         entity: framework.entity2Pda,
+        world: framework.worldPda,
         componentId: framework.exampleComponentPosition.programId,
       });
       await framework.provider.sendAndConfirm(initializeComponent.transaction);
+      framework.componentPositionEntity2Pda = initializeComponent.componentPda; // Saved for later
     });
 
     it("Initialize Position Component on Entity 4", async () => {
       const initializeComponent = await InitializeComponent({
         payer: framework.provider.wallet.publicKey,
         entity: framework.entity4Pda,
+        world: framework.worldPda,
         componentId: framework.exampleComponentPosition.programId,
       });
       await framework.provider.sendAndConfirm(initializeComponent.transaction);
@@ -159,6 +164,170 @@ export function ecs(framework: Framework) {
       expect(position.z.toNumber()).to.equal(0);
     });
 
+    it("Apply Simple Movement System with Position passed read-only should fail", async () => {
+      const applySystem = await ApplySystem({
+        authority: framework.provider.wallet.publicKey,
+        systemId: framework.systemSimpleMovement.programId,
+        world: framework.worldPda,
+        entities: [
+          {
+            entity: framework.entity1Pda,
+            components: [
+              {
+                componentId: framework.exampleComponentPosition.programId,
+                readOnly: true,
+              },
+            ],
+          },
+        ],
+        args: {
+          direction: Direction.Up,
+        },
+      });
+      try {
+        await framework.provider.sendAndConfirm(applySystem.transaction);
+        assert.fail("The written back component was passed read-only");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: InvalidComponentAccess",
+        );
+      }
+    });
+
+    it("Apply Follow System with the read-only target passed writable should fail", async () => {
+      const applySystem = await ApplySystem({
+        authority: framework.provider.wallet.publicKey,
+        systemId: framework.systemFollow.programId,
+        world: framework.worldPda,
+        entities: [
+          {
+            entity: framework.entity2Pda,
+            components: [
+              { componentId: framework.exampleComponentPosition.programId },
+            ],
+          },
+          {
+            entity: framework.entity1Pda,
+            components: [
+              { componentId: framework.exampleComponentPosition.programId },
+            ],
+          },
+        ],
+      });
+      try {
+        await framework.provider.sendAndConfirm(applySystem.transaction);
+        assert.fail("The read-only component was passed writable");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: InvalidComponentAccess",
+        );
+      }
+    });
+
+    it("Apply Follow System on Entity 2 with Entity 1 as read-only target", async () => {
+      const applySystem = await ApplySystem({
+        authority: framework.provider.wallet.publicKey,
+        systemId: framework.systemFollow.programId,
+        world: framework.worldPda,
+        entities: [
+          {
+            entity: framework.entity2Pda,
+            components: [
+              { componentId: framework.exampleComponentPosition.programId },
+            ],
+          },
+          {
+            entity: framework.entity1Pda,
+            components: [
+              {
+                componentId: framework.exampleComponentPosition.programId,
+                readOnly: true,
+              },
+            ],
+          },
+        ],
+      });
+      await framework.provider.sendAndConfirm(applySystem.transaction);
+
+      const position =
+        await framework.exampleComponentPosition.account.position.fetch(
+          framework.componentPositionEntity2Pda,
+        );
+      expect(position.x.toNumber()).to.equal(1);
+      expect(position.y.toNumber()).to.equal(1);
+      expect(position.z.toNumber()).to.equal(0);
+    });
+
+    it("Apply Follow System with a read-only target of another world should fail", async () => {
+      const addEntity = await AddEntity({
+        payer: framework.provider.wallet.publicKey,
+        world: framework.secondWorldPda,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(addEntity.transaction);
+      const initializeComponent = await InitializeComponent({
+        payer: framework.provider.wallet.publicKey,
+        entity: addEntity.entityPda,
+        world: framework.secondWorldPda,
+        componentId: framework.exampleComponentPosition.programId,
+      });
+      await framework.provider.sendAndConfirm(initializeComponent.transaction);
+
+      const applySystem = await ApplySystem({
+        authority: framework.provider.wallet.publicKey,
+        systemId: framework.systemFollow.programId,
+        world: framework.worldPda,
+        entities: [
+          {
+            entity: framework.entity2Pda,
+            components: [
+              { componentId: framework.exampleComponentPosition.programId },
+            ],
+          },
+          {
+            entity: addEntity.entityPda,
+            components: [
+              {
+                componentId: framework.exampleComponentPosition.programId,
+                readOnly: true,
+              },
+            ],
+          },
+        ],
+      });
+      try {
+        await framework.provider.sendAndConfirm(applySystem.transaction);
+        assert.fail("The component of another world was read by the system");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain("Error Code: InvalidWorld");
+      }
+    });
+
+    it("Apply Legacy System, built with the former account layout, on Entity 2", async () => {
+      const applySystem = await ApplySystem({
+        authority: framework.provider.wallet.publicKey,
+        systemId: framework.systemLegacy.programId,
+        world: framework.worldPda,
+        entities: [
+          {
+            entity: framework.entity2Pda,
+            components: [
+              { componentId: framework.exampleComponentPosition.programId },
+            ],
+          },
+        ],
+      });
+      await framework.provider.sendAndConfirm(applySystem.transaction);
+
+      const position =
+        await framework.exampleComponentPosition.account.position.fetch(
+          framework.componentPositionEntity2Pda,
+        );
+      expect(position.x.toNumber()).to.equal(2);
+      expect(position.y.toNumber()).to.equal(1);
+      expect(position.z.toNumber()).to.equal(0);
+    });
+
     it("Apply Fly System on Entity 1", async () => {
       const applySystem = await ApplySystem({
         authority: framework.provider.wallet.publicKey,
@@ -288,6 +457,7 @@ export function ecs(framework: Framework) {
           const initializeComponent = await InitializeComponent({
             payer: framework.provider.wallet.publicKey,
             entity: entitiesPdas[i],
+            world: framework.worldPda,
             componentId: framework.componentSmall.programId,
           });
           await framework.provider.sendAndConfirm(
@@ -376,6 +546,7 @@ export function ecs(framework: Framework) {
       const destroyComponent = await DestroyComponent({
         authority: framework.provider.wallet.publicKey,
         entity: framework.entity1Pda,
+        world: framework.worldPda,
         componentId: framework.exampleComponentVelocity.programId,
         receiver: keypair.publicKey,
         seed: "component-velocity",
@@ -0,0 +1,132 @@
+import {
+  anchor,
+  web3,
+  AddEntity,
+  ApplySystem,
+  InitializeComponent,
+} from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+import { createHash } from "crypto";
+
+// The component events are declared by bolt-lang, not by the component IDLs,
+// so they are decoded by hand: the program and the component keys lead them
+const componentUpdated = createHash("sha256")
+  .update("event:ComponentUpdated")
+  .digest()
+  .subarray(0, 8);
+
+function updatedComponents(logs: string[], program: web3.PublicKey) {
+  return logs
+    .filter((log) => log.startsWith("Program data: "))
+    .map((log) => Buffer.from(log.slice("Program data: ".length), "base64"))
+    .filter((data) => data.subarray(0, 8).equals(componentUpdated))
+    .filter((data) => new web3.PublicKey(data.subarray(8, 40)).equals(program))
+    .map((data) => new web3.PublicKey(data.subarray(40, 72)).toBase58());
+}
+
+export function events(framework: Framework) {
+  describe("Events", () => {
+    const positionPdas: web3.PublicKey[] = [];
+    const entities: web3.PublicKey[] = [];
+
+    it("Add two entities with a Position", async () => {
+      for (let i = 0; i < 2; i++) {
+        const addEntity = await AddEntity({
+          payer: framework.provider.wallet.publicKey,
+          world: framework.worldPda,
+          connection: framework.provider.connection,
+        });
+        await framework.provider.sendAndConfirm(addEntity.transaction);
+        entities.push(addEntity.entityPda);
+
+        const initializeComponent = await InitializeComponent({
+          payer: framework.provider.wallet.publicKey,
+          entity: addEntity.entityPda,
+          world: framework.worldPda,
+          componentId: framework.exampleComponentPosition.programId,
+        });
+        await framework.provider.sendAndConfirm(
+          initializeComponent.transaction,
+        );
+        positionPdas.push(initializeComponent.componentPda);
+      }
+    });
+
+    async function applyFollow(targetReadOnly: boolean) {
+      return ApplySystem({
+        authority: framework.provider.wallet.publicKey,
+        systemId: framework.systemFollow.programId,
+        world: framework.worldPda,
+        entities: [
+          {
+            entity: entities[0],
+            components: [
+              { componentId: framework.exampleComponentPosition.programId },
+            ],
+          },
+          {
+            entity: entities[1],
+            components: [
+              {
+                componentId: framework.exampleComponentPosition.programId,
+                readOnly: targetReadOnly,
+              },
+            ],
+          },
+        ],
+      });
+    }
+
+    function appliedSystems(logs: string[]) {
+      const parser = new anchor.EventParser(
+        framework.worldProgram.programId,
+        framework.worldProgram.coder,
+      );
+      return [...parser.parseLogs(logs)]
+        .filter((event) => event.name === "systemApplied")
+        .map((event) => event.data.system.toBase58());
+    }
+
+    it("Apply Follow System with a writable target should fail and emit nothing", async () => {
+      const applySystem = await applyFollow(false);
+      try {
+        await framework.provider.sendAndConfirm(applySystem.transaction);
+        assert.fail("The target was passed writable");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: InvalidComponentAccess",
+        );
+        expect(appliedSystems(error.logs)).to.be.empty;
+        expect(
+          updatedComponents(
+            error.logs,
+            framework.exampleComponentPosition.programId,
+          ),
+        ).to.be.empty;
+      }
+    });
+
+    it("Apply Follow System emits the system and the updated Position", async () => {
+      const applySystem = await applyFollow(true);
+      const signature = await framework.provider.sendAndConfirm(
+        applySystem.transaction,
+        [],
+        { commitment: "confirmed" },
+      );
+      const transaction = await framework.provider.connection.getTransaction(
+        signature,
+        { commitment: "confirmed", maxSupportedTransactionVersion: 0 },
+      );
+      const logs = transaction.meta.logMessages;
+
+      expect(appliedSystems(logs)).to.deep.equal([
+        framework.systemFollow.programId.toBase58(),
+      ]);
+      // The read-only target isn't updated, so it emits no event
+      expect(
+        updatedComponents(logs, framework.exampleComponentPosition.programId),
+      ).to.deep.equal([positionPdas[0].toBase58()]);
+    });
+  });
+}
@@ -0,0 +1,84 @@
+import { anchor } from "../../lib";
+import { Framework } from "../framework";
+import { expect } from "chai";
+
+// The system input is an instruction only described by the IDL: its accounts
+// are the ones expected by apply, and its argument is the arguments type
+function systemInput(program: anchor.Program<any>) {
+  const idl = program.idl as anchor.Idl;
+  const input = idl.instructions.find(
+    (instruction) => instruction.name === "boltSystemInput",
+  );
+  const argsType = input.args[0].type as any;
+  const args = idl.types?.find(
+    (type) => type.name === argsType.defined?.name,
+  ) as any;
+  return {
+    docs: input.docs,
+    accounts: input.accounts as any[],
+    args: args ? args.type.fields.map((field) => field.name) : argsType,
+  };
+}
+
+export function idl(framework: Framework) {
+  describe("System IDL", () => {
+    it("Describe the components and the Borsh arguments of a system", () => {
+      const input = systemInput(framework.systemBorshMovement);
+      expect(input.docs).to.deep.equal([
+        "The arguments are encoded with Borsh.",
+      ]);
+      expect(input.accounts.map((account) => account.name)).to.deep.equal([
+        "positionProgram",
+        "position",
+      ]);
+      expect(input.accounts[0].address).to.equal(
+        framework.exampleComponentPosition.programId.toBase58(),
+      );
+      expect(input.accounts[1].writable).to.be.true;
+      expect(input.args).to.deep.equal(["dx", "dy", "dz"]);
+    });
+
+    it("Describe the extra accounts and the JSON arguments of a system", () => {
+      const input = systemInput(framework.systemEscrowFunding);
+      expect(input.docs).to.deep.equal([
+        "The arguments are encoded with JSON.",
+      ]);
+      expect(input.accounts.map((account) => account.name)).to.deep.equal([
+        "receiverProgram",
+        "receiver",
+        "extraAccounts",
+      ]);
+      expect(
+        input.accounts[2].accounts.map((account) => account.name),
+      ).to.deep.equal(["sender", "systemProgram"]);
+      expect(input.args).to.deep.equal(["amount"]);
+    });
+
+    it("Describe the JSON arguments of a system without the IDL opt-in as bytes", () => {
+      const input = systemInput(framework.systemSimpleMovement);
+      expect(input.docs).to.deep.equal([
+        "The arguments are encoded with JSON.",
+      ]);
+      expect(input.args).to.equal("bytes");
+    });
+
+    it("Describe no arguments type nor write for a read-only component", () => {
+      const input = systemInput(framework.systemFollow);
+      expect(input.docs).to.deep.equal([
+        "The arguments are passed as raw bytes.",
+      ]);
+      expect(input.args).to.equal("bytes");
+      expect(input.accounts.map((account) => account.name)).to.deep.equal([
+        "positionProgram",
+        "position",
+        "targetProgram",
+        "target",
+      ]);
+      expect(input.accounts[1].writable).to.be.true;
+      expect(input.accounts[3].writable).to.be.undefined;
+      expect(input.accounts[3].docs).to.deep.equal([
+        "The `target` component, read-only",
+      ]);
+    });
+  });
+}
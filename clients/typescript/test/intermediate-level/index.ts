@@ -1,6 +1,28 @@
 import { Framework } from "../framework";
 import { world } from "./world";
+import { roles } from "./roles";
+import { worldState } from "./world-state";
+import { componentPermissions } from "./component-permissions";
 import { ecs } from "./ecs";
+import { resource } from "./resource";
+import { instances } from "./instances";
+import { optional } from "./optional";
+import { set } from "./set";
+import { componentValidation } from "./component-validation";
+import { batch } from "./batch";
+import { buffered } from "./buffered";
+import { commands } from "./commands";
+import { migrate } from "./migrate";
+import { setAuthority } from "./set-authority";
+import { transfer } from "./transfer";
+import { destroyEntity } from "./destroy-entity";
+import { resize } from "./resize";
+import { patch } from "./patch";
+import { borsh } from "./borsh";
+import { idl } from "./idl";
+import { zeroCopy } from "./zero-copy";
+import { events } from "./events";
+import { cpi } from "./cpi";
 import { session } from "./session";
 import { permissioning } from "./permissioning";
 import { acceleration } from "./acceleration";
@@ -8,7 +30,29 @@ import { acceleration } from "./acceleration";
 describe("Intermediate level API", () => {
   const framework: Framework = new Framework();
   world(framework);
+  roles(framework);
+  worldState(framework);
+  componentPermissions(framework);
   ecs(framework);
+  resource(framework);
+  instances(framework);
+  optional(framework);
+  set(framework);
+  componentValidation(framework);
+  batch(framework);
+  buffered(framework);
+  commands(framework);
+  migrate(framework);
+  setAuthority(framework);
+  transfer(framework);
+  destroyEntity(framework);
+  resize(framework);
+  patch(framework);
+  borsh(framework);
+  idl(framework);
+  zeroCopy(framework);
+  events(framework);
+  cpi(framework);
   session(framework);
   acceleration(framework);
   permissioning(framework);
@@ -0,0 +1,84 @@
+import { web3, AddEntity, ApplySystem, InitializeComponent } from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+
+export function instances(framework: Framework) {
+  describe("Component instances", () => {
+    const instanceSeeds = [new Uint8Array([0, 1]), new Uint8Array([0, 2])];
+    const positionPdas: web3.PublicKey[] = [];
+    let entityPda: web3.PublicKey;
+
+    async function initializePosition(instance: Uint8Array) {
+      return InitializeComponent({
+        payer: framework.provider.wallet.publicKey,
+        entity: entityPda,
+        world: framework.worldPda,
+        componentId: framework.exampleComponentPosition.programId,
+        instance,
+      });
+    }
+
+    it("Add an entity with two instances of a Position", async () => {
+      const addEntity = await AddEntity({
+        payer: framework.provider.wallet.publicKey,
+        world: framework.worldPda,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(addEntity.transaction);
+      entityPda = addEntity.entityPda;
+
+      for (const instance of instanceSeeds) {
+        const initializeComponent = await initializePosition(instance);
+        await framework.provider.sendAndConfirm(
+          initializeComponent.transaction,
+        );
+        positionPdas.push(initializeComponent.componentPda);
+      }
+      expect(positionPdas[0].equals(positionPdas[1])).to.be.false;
+    });
+
+    it("Initialize an instance of the Position twice should fail", async () => {
+      const initializeComponent = await initializePosition(instanceSeeds[1]);
+      try {
+        await framework.provider.sendAndConfirm(
+          initializeComponent.transaction,
+        );
+        assert.fail("The instance was initialized twice");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: ComponentAlreadyInitialized",
+        );
+      }
+    });
+
+    it("Apply Fly System on the second instance only", async () => {
+      const applySystem = await ApplySystem({
+        authority: framework.provider.wallet.publicKey,
+        systemId: framework.systemFly.programId,
+        world: framework.worldPda,
+        entities: [
+          {
+            entity: entityPda,
+            components: [
+              {
+                componentId: framework.exampleComponentPosition.programId,
+                instance: instanceSeeds[1],
+              },
+            ],
+          },
+        ],
+      });
+      await framework.provider.sendAndConfirm(applySystem.transaction);
+
+      const positions = await Promise.all(
+        positionPdas.map((positionPda) =>
+          framework.exampleComponentPosition.account.position.fetch(
+            positionPda,
+          ),
+        ),
+      );
+      const z = positions.map((position) => position.z.toNumber());
+      expect(z).to.deep.equal([0, 1]);
+    });
+  });
+}
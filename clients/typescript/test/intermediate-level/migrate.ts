@@ -0,0 +1,127 @@
+import {
+  web3,
+  FindComponentPda,
+  MigrateComponent,
+  SetComponentAuthority,
+  WORLD_PROGRAM_ID,
+} from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+
+// Entities with a Position created before versioning, whose metadata only holds the authority.
+// They are loaded by the test validator from the fixtures. The first entity was created before
+// world binding as well, the second one is migrated and bound to its world.
+const legacyEntity = new web3.PublicKey(
+  "8qBMmiQA1hSxvERmmLU9nobmYyM3aeBAUf2yF3u6L9SG",
+);
+const boundEntity = new web3.PublicKey(
+  "8sDGhka9TLL6aLBTo1oxNJhR3DeXyqUDQ6zGkHUsUt2C",
+);
+const boundWorld = new web3.PublicKey(
+  "EdmGGichKAMUTnWRHgoujH3fmDjTzVemZbu1jtv7K8Hn",
+);
+
+export function migrate(framework: Framework) {
+  describe("Migrate", () => {
+    const legacyPosition = (entity: web3.PublicKey) =>
+      FindComponentPda({
+        componentId: framework.exampleComponentPosition.programId,
+        entity,
+      });
+
+    async function setAuthority(entity: web3.PublicKey) {
+      const setComponentAuthority = await SetComponentAuthority({
+        authority: framework.provider.wallet.publicKey,
+        newAuthority: framework.provider.wallet.publicKey,
+        entity,
+        componentId: framework.exampleComponentPosition.programId,
+      });
+      return setComponentAuthority.transaction;
+    }
+
+    it("Set the authority of a legacy Position before its migration should fail", async () => {
+      try {
+        await framework.provider.sendAndConfirm(
+          await setAuthority(legacyEntity),
+        );
+        assert.fail("The legacy component was written with its former layout");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain("Error Code: NotMigrated");
+      }
+    });
+
+    it("Migrate the legacy Position of an entity without world should fail", async () => {
+      const migrateComponent = await MigrateComponent({
+        payer: framework.provider.wallet.publicKey,
+        entity: legacyEntity,
+        componentId: framework.exampleComponentPosition.programId,
+      });
+      try {
+        await framework.provider.sendAndConfirm(migrateComponent.transaction);
+        assert.fail(
+          "The component was migrated without being bound to a world",
+        );
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain("Error Code: InvalidWorld");
+      }
+    });
+
+    it("Migrate the legacy Position of the bound entity", async () => {
+      const migrateComponent = await MigrateComponent({
+        payer: framework.provider.wallet.publicKey,
+        entity: boundEntity,
+        componentId: framework.exampleComponentPosition.programId,
+      });
+      await framework.provider.sendAndConfirm(migrateComponent.transaction);
+
+      const position =
+        await framework.exampleComponentPosition.account.position.fetch(
+          legacyPosition(boundEntity),
+        );
+      expect(position.x.toNumber()).to.equal(1);
+      expect(position.y.toNumber()).to.equal(2);
+      expect(position.z.toNumber()).to.equal(3);
+      expect(position.boltMetadata.version).to.equal(1);
+      expect(position.boltMetadata.authority.toBase58()).to.equal(
+        WORLD_PROGRAM_ID.toBase58(),
+      );
+      expect(position.boltMetadata.world.toBase58()).to.equal(
+        boundWorld.toBase58(),
+      );
+      expect(position.boltMetadata.entity.toBase58()).to.equal(
+        boundEntity.toBase58(),
+      );
+      const account = await framework.provider.connection.getAccountInfo(
+        legacyPosition(boundEntity),
+      );
+      // The discriminator, the coordinates and the current metadata
+      expect(account.data.length).to.equal(8 + 24 + 97);
+    });
+
+    it("Migrate the migrated Position again should fail", async () => {
+      const migrateComponent = await MigrateComponent({
+        payer: framework.provider.wallet.publicKey,
+        entity: boundEntity,
+        componentId: framework.exampleComponentPosition.programId,
+      });
+      try {
+        await framework.provider.sendAndConfirm(migrateComponent.transaction);
+        assert.fail("The component was migrated twice");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain("Error Code: AlreadyMigrated");
+      }
+    });
+
+    it("Set the authority of the migrated Position", async () => {
+      await framework.provider.sendAndConfirm(await setAuthority(boundEntity));
+
+      const position =
+        await framework.exampleComponentPosition.account.position.fetch(
+          legacyPosition(boundEntity),
+        );
+      expect(position.boltMetadata.authority.toBase58()).to.equal(
+        framework.provider.wallet.publicKey.toBase58(),
+      );
+    });
+  });
+}
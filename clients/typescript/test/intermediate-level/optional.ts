@@ -0,0 +1,75 @@
+import { web3, AddEntity, ApplySystem, InitializeComponent } from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+
+export function optional(framework: Framework) {
+  describe("Optional components", () => {
+    let entityPda: web3.PublicKey;
+    let positionPda: web3.PublicKey;
+
+    async function applyDrift(positionAbsent: boolean) {
+      return ApplySystem({
+        authority: framework.provider.wallet.publicKey,
+        systemId: framework.systemDrift.programId,
+        world: framework.worldPda,
+        entities: [
+          {
+            entity: entityPda,
+            components: [
+              {
+                componentId: framework.exampleComponentPosition.programId,
+                absent: positionAbsent,
+              },
+              {
+                componentId: framework.exampleComponentVelocity.programId,
+                absent: true,
+              },
+            ],
+          },
+        ],
+      });
+    }
+
+    it("Add an entity with a Position and no Velocity", async () => {
+      const addEntity = await AddEntity({
+        payer: framework.provider.wallet.publicKey,
+        world: framework.worldPda,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(addEntity.transaction);
+      entityPda = addEntity.entityPda;
+
+      const initializeComponent = await InitializeComponent({
+        payer: framework.provider.wallet.publicKey,
+        entity: entityPda,
+        world: framework.worldPda,
+        componentId: framework.exampleComponentPosition.programId,
+      });
+      await framework.provider.sendAndConfirm(initializeComponent.transaction);
+      positionPda = initializeComponent.componentPda;
+    });
+
+    it("Apply Drift System with the required Position absent should fail", async () => {
+      const applySystem = await applyDrift(true);
+      try {
+        await framework.provider.sendAndConfirm(applySystem.transaction);
+        assert.fail("The system ran without its required Position");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: InvalidComponentAccess",
+        );
+      }
+    });
+
+    it("Apply Drift System without the optional Velocity", async () => {
+      const applySystem = await applyDrift(false);
+      await framework.provider.sendAndConfirm(applySystem.transaction);
+
+      const position =
+        await framework.exampleComponentPosition.account.position.fetch(
+          positionPda,
+        );
+      expect(position.x.toNumber()).to.equal(1);
+    });
+  });
+}
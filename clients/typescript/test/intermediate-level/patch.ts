@@ -0,0 +1,96 @@
+import { web3, AddEntity, ApplySystem, InitializeComponent } from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+
+export function patch(framework: Framework) {
+  describe("Patch", () => {
+    // Longer than the 20 characters the Velocity allocates for its description
+    const description =
+      "A description which outgrows the space allocated by the Velocity";
+    let entityPda: web3.PublicKey;
+    let velocityPda: web3.PublicKey;
+
+    async function applyDescribe(authority: web3.PublicKey) {
+      return ApplySystem({
+        authority,
+        systemId: framework.systemDescribe.programId,
+        world: framework.worldPda,
+        entities: [
+          {
+            entity: entityPda,
+            components: [
+              {
+                componentId: framework.exampleComponentVelocity.programId,
+                seed: "component-velocity",
+              },
+            ],
+          },
+        ],
+        args: { description },
+        patch: true,
+      });
+    }
+
+    it("Add an entity with a Velocity held by the wallet", async () => {
+      const addEntity = await AddEntity({
+        payer: framework.provider.wallet.publicKey,
+        world: framework.worldPda,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(addEntity.transaction);
+      entityPda = addEntity.entityPda;
+
+      const initializeComponent = await InitializeComponent({
+        payer: framework.provider.wallet.publicKey,
+        entity: entityPda,
+        world: framework.worldPda,
+        componentId: framework.exampleComponentVelocity.programId,
+        seed: "component-velocity",
+        authority: framework.provider.wallet.publicKey,
+      });
+      await framework.provider.sendAndConfirm(initializeComponent.transaction);
+      velocityPda = initializeComponent.componentPda;
+    });
+
+    it("Apply Describe System as a patch by another authority should fail", async () => {
+      const other = web3.Keypair.generate();
+      const applySystem = await applyDescribe(other.publicKey);
+      try {
+        await framework.provider.sendAndConfirm(applySystem.transaction, [
+          other,
+        ]);
+        assert.fail("The Velocity was patched by another authority");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: InvalidAuthority",
+        );
+      }
+      const velocity =
+        await framework.exampleComponentVelocity.account.velocity.fetch(
+          velocityPda,
+        );
+      expect(velocity.description).to.equal("");
+    });
+
+    it("Apply Describe System as a patch grows the Velocity", async () => {
+      const before =
+        await framework.provider.connection.getAccountInfo(velocityPda);
+      const applySystem = await applyDescribe(
+        framework.provider.wallet.publicKey,
+      );
+      await framework.provider.sendAndConfirm(applySystem.transaction);
+
+      const after =
+        await framework.provider.connection.getAccountInfo(velocityPda);
+      expect(after.data.length).to.be.greaterThan(before.data.length);
+      const velocity =
+        await framework.exampleComponentVelocity.account.velocity.fetch(
+          velocityPda,
+        );
+      expect(velocity.description).to.equal(description);
+      expect(velocity.boltMetadata.authority.toBase58()).to.equal(
+        framework.provider.wallet.publicKey.toBase58(),
+      );
+    });
+  });
+}
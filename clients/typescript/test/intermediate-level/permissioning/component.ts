@@ -26,6 +26,7 @@ export function component(framework) {
       const initializeComponent = await InitializeComponent({
         payer: framework.provider.wallet.publicKey,
         entity: entity,
+        world: framework.worldPda,
         componentId: framework.exampleComponentPosition.programId,
         authority: framework.provider.wallet.publicKey,
       });
@@ -0,0 +1,112 @@
+import { web3, AddEntity, ApplySystem, InitializeComponent } from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+
+export function resize(framework: Framework) {
+  describe("Resize", () => {
+    // Longer than the 20 characters the Velocity allocates for its description
+    const description =
+      "A description which outgrows the space allocated by the Velocity";
+    // An authority without lamports, which can't pay for the growth
+    const unfunded = web3.Keypair.generate();
+
+    async function addVelocity(authority: web3.PublicKey) {
+      const addEntity = await AddEntity({
+        payer: framework.provider.wallet.publicKey,
+        world: framework.worldPda,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(addEntity.transaction);
+
+      const initializeComponent = await InitializeComponent({
+        payer: framework.provider.wallet.publicKey,
+        entity: addEntity.entityPda,
+        world: framework.worldPda,
+        componentId: framework.exampleComponentVelocity.programId,
+        seed: "component-velocity",
+        authority,
+      });
+      await framework.provider.sendAndConfirm(initializeComponent.transaction);
+      return {
+        entityPda: addEntity.entityPda,
+        velocityPda: initializeComponent.componentPda,
+      };
+    }
+
+    async function applyDescribe(
+      authority: web3.PublicKey,
+      entityPda: web3.PublicKey,
+      description: string,
+    ) {
+      return ApplySystem({
+        authority,
+        systemId: framework.systemDescribe.programId,
+        world: framework.worldPda,
+        entities: [
+          {
+            entity: entityPda,
+            components: [
+              {
+                componentId: framework.exampleComponentVelocity.programId,
+                seed: "component-velocity",
+              },
+            ],
+          },
+        ],
+        args: { description },
+      });
+    }
+
+    it("Grow a Velocity held by an authority without lamports should fail", async () => {
+      const { entityPda, velocityPda } = await addVelocity(unfunded.publicKey);
+      const applySystem = await applyDescribe(
+        unfunded.publicKey,
+        entityPda,
+        description,
+      );
+      try {
+        await framework.provider.sendAndConfirm(applySystem.transaction, [
+          unfunded,
+        ]);
+        assert.fail("The Velocity grew without its rent");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain("insufficient lamports");
+      }
+      const velocity =
+        await framework.exampleComponentVelocity.account.velocity.fetch(
+          velocityPda,
+        );
+      expect(velocity.description).to.equal("");
+    });
+
+    it("Grow a Velocity on update and keep its size when it shrinks", async () => {
+      const authority = framework.provider.wallet.publicKey;
+      const { entityPda, velocityPda } = await addVelocity(authority);
+      const before =
+        await framework.provider.connection.getAccountInfo(velocityPda);
+
+      let applySystem = await applyDescribe(authority, entityPda, description);
+      await framework.provider.sendAndConfirm(applySystem.transaction);
+      const grown =
+        await framework.provider.connection.getAccountInfo(velocityPda);
+      expect(grown.data.length).to.be.greaterThan(before.data.length);
+      expect(grown.lamports).to.be.greaterThan(before.lamports);
+      let velocity =
+        await framework.exampleComponentVelocity.account.velocity.fetch(
+          velocityPda,
+        );
+      expect(velocity.description).to.equal(description);
+
+      applySystem = await applyDescribe(authority, entityPda, "Short");
+      await framework.provider.sendAndConfirm(applySystem.transaction);
+      const shrunk =
+        await framework.provider.connection.getAccountInfo(velocityPda);
+      expect(shrunk.data.length).to.equal(grown.data.length);
+      velocity =
+        await framework.exampleComponentVelocity.account.velocity.fetch(
+          velocityPda,
+        );
+      expect(velocity.description).to.equal("Short");
+    });
+  });
+}
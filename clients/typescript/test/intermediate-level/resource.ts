@@ -0,0 +1,76 @@
+import {
+  ApplySystem,
+  InitializeComponent,
+  InitializeResource,
+} from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+
+export function resource(framework: Framework) {
+  describe("Resource", () => {
+    it("Initialize Counter Resource of the world", async () => {
+      const initializeResource = await InitializeResource({
+        payer: framework.provider.wallet.publicKey,
+        world: framework.worldPda,
+        componentId: framework.exampleComponentCounter.programId,
+      });
+      await framework.provider.sendAndConfirm(initializeResource.transaction);
+      framework.counterResourcePda = initializeResource.resourcePda; // Saved for later
+
+      const counter =
+        await framework.exampleComponentCounter.account.counter.fetch(
+          framework.counterResourcePda,
+        );
+      expect(counter.count.toNumber()).to.equal(0);
+    });
+
+    it("Apply Counter System on the Counter Resource", async () => {
+      const applySystem = await ApplySystem({
+        authority: framework.provider.wallet.publicKey,
+        systemId: framework.systemCounter.programId,
+        world: framework.worldPda,
+        entities: [],
+        resources: [
+          { componentId: framework.exampleComponentCounter.programId },
+        ],
+      });
+      await framework.provider.sendAndConfirm(applySystem.transaction);
+
+      const counter =
+        await framework.exampleComponentCounter.account.counter.fetch(
+          framework.counterResourcePda,
+        );
+      expect(counter.count.toNumber()).to.equal(1);
+    });
+
+    it("Apply Counter System on a Counter Component of an entity should fail", async () => {
+      const initializeComponent = await InitializeComponent({
+        payer: framework.provider.wallet.publicKey,
+        entity: framework.entity1Pda,
+        world: framework.worldPda,
+        componentId: framework.exampleComponentCounter.programId,
+      });
+      await framework.provider.sendAndConfirm(initializeComponent.transaction);
+
+      const applySystem = await ApplySystem({
+        authority: framework.provider.wallet.publicKey,
+        systemId: framework.systemCounter.programId,
+        world: framework.worldPda,
+        entities: [
+          {
+            entity: framework.entity1Pda,
+            components: [
+              { componentId: framework.exampleComponentCounter.programId },
+            ],
+          },
+        ],
+      });
+      try {
+        await framework.provider.sendAndConfirm(applySystem.transaction);
+        assert.fail("The component of an entity was passed as the resource");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain("Error Code: InvalidResource");
+      }
+    });
+  });
+}
@@ -0,0 +1,116 @@
+import {
+  web3,
+  AddAuthority,
+  ApproveSystem,
+  InitializeNewWorld,
+  RemoveAuthority,
+  SetAuthorityRoles,
+  ROLES,
+} from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+
+export function roles(framework: Framework) {
+  describe("Roles", () => {
+    const admin = framework.provider.wallet.publicKey;
+    const operator = web3.Keypair.generate();
+    let worldPda: web3.PublicKey;
+
+    it("Initialize a world administered by the wallet, with an operator", async () => {
+      const initializeNewWorld = await InitializeNewWorld({
+        payer: admin,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(initializeNewWorld.transaction);
+      worldPda = initializeNewWorld.worldPda;
+
+      const addAuthority = await AddAuthority({
+        authority: admin,
+        newAuthority: operator.publicKey,
+        world: worldPda,
+        roles: ROLES.OPERATOR,
+        connection: framework.provider.connection,
+      });
+      // The operator pays for the growth of the world when it approves systems
+      addAuthority.transaction.add(
+        web3.SystemProgram.transfer({
+          fromPubkey: admin,
+          toPubkey: operator.publicKey,
+          lamports: web3.LAMPORTS_PER_SOL,
+        }),
+      );
+      await framework.provider.sendAndConfirm(addAuthority.transaction);
+    });
+
+    it("Add an authority by an operator should fail", async () => {
+      const addAuthority = await AddAuthority({
+        authority: operator.publicKey,
+        newAuthority: web3.Keypair.generate().publicKey,
+        world: worldPda,
+        connection: framework.provider.connection,
+      });
+      try {
+        await framework.provider.sendAndConfirm(addAuthority.transaction, [
+          operator,
+        ]);
+        assert.fail("The operator managed the authorities of the world");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain("Error Code: MissingRole");
+      }
+    });
+
+    it("Remove the last admin of the world should fail", async () => {
+      const removeAuthority = await RemoveAuthority({
+        authority: admin,
+        authorityToDelete: admin,
+        world: worldPda,
+        connection: framework.provider.connection,
+      });
+      try {
+        await framework.provider.sendAndConfirm(removeAuthority.transaction);
+        assert.fail("The world lost its last admin");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain("Error Code: LastAdmin");
+      }
+    });
+
+    it("Approve a system by the operator", async () => {
+      const approveSystem = await ApproveSystem({
+        authority: operator.publicKey,
+        systemToApprove: framework.systemFly.programId,
+        world: worldPda,
+      });
+      await framework.provider.sendAndConfirm(approveSystem.transaction, [
+        operator,
+      ]);
+      const world = await framework.worldProgram.account.world.fetch(worldPda);
+      expect(world.permissionless).to.be.false;
+    });
+
+    it("Hand the admin role over to the operator", async () => {
+      const setAuthorityRoles = await SetAuthorityRoles({
+        authority: admin,
+        authorityToUpdate: operator.publicKey,
+        world: worldPda,
+        roles: ROLES.ALL,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(setAuthorityRoles.transaction);
+
+      // Once the operator is an admin, the former admin can leave the world
+      const removeAuthority = await RemoveAuthority({
+        authority: operator.publicKey,
+        authorityToDelete: admin,
+        world: worldPda,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(removeAuthority.transaction, [
+        operator,
+      ]);
+      const world = await framework.worldProgram.account.world.fetch(worldPda);
+      expect(world.authorities.map((key) => key.toBase58())).to.deep.equal([
+        operator.publicKey.toBase58(),
+      ]);
+    });
+  });
+}
@@ -44,6 +44,8 @@ export function session(framework) {
       const initializeComponent = await InitializeComponent({
         payer: session.signer.publicKey,
         entity: entity,
+        world: framework.worldPda,
+        owner: framework.provider.wallet.publicKey,
         componentId: framework.exampleComponentPosition.programId,
       });
       await framework.provider.sendAndConfirm(initializeComponent.transaction, [
@@ -102,6 +104,8 @@ export function session(framework) {
       const initializeComponent = await InitializeComponent({
         payer: session.signer.publicKey,
         entity: entityWithAuthority,
+        world: framework.worldPda,
+        owner: framework.provider.wallet.publicKey,
         componentId: framework.exampleComponentPosition.programId,
         authority: framework.provider.wallet.publicKey,
       });
@@ -0,0 +1,81 @@
+import {
+  web3,
+  AddEntity,
+  InitializeComponent,
+  SetComponentAuthority,
+} from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+
+export function setAuthority(framework: Framework) {
+  describe("Set component authority", () => {
+    const newAuthority = web3.Keypair.generate().publicKey;
+    let entityPda: web3.PublicKey;
+    let positionPda: web3.PublicKey;
+
+    async function positionAuthority() {
+      const position =
+        await framework.exampleComponentPosition.account.position.fetch(
+          positionPda,
+        );
+      return position.boltMetadata.authority.toBase58();
+    }
+
+    it("Add an entity with a Position held by the wallet", async () => {
+      const addEntity = await AddEntity({
+        payer: framework.provider.wallet.publicKey,
+        world: framework.worldPda,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(addEntity.transaction);
+      entityPda = addEntity.entityPda;
+
+      const initializeComponent = await InitializeComponent({
+        payer: framework.provider.wallet.publicKey,
+        entity: entityPda,
+        world: framework.worldPda,
+        componentId: framework.exampleComponentPosition.programId,
+        authority: framework.provider.wallet.publicKey,
+      });
+      await framework.provider.sendAndConfirm(initializeComponent.transaction);
+      positionPda = initializeComponent.componentPda;
+    });
+
+    it("Set the authority of the Position by another authority should fail", async () => {
+      const other = web3.Keypair.generate();
+      const setComponentAuthority = await SetComponentAuthority({
+        authority: other.publicKey,
+        newAuthority: other.publicKey,
+        entity: entityPda,
+        componentId: framework.exampleComponentPosition.programId,
+      });
+      try {
+        await framework.provider.sendAndConfirm(
+          setComponentAuthority.transaction,
+          [other],
+        );
+        assert.fail("The Position was taken over by another authority");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: InvalidAuthority",
+        );
+      }
+      expect(await positionAuthority()).to.equal(
+        framework.provider.wallet.publicKey.toBase58(),
+      );
+    });
+
+    it("Hand the Position over to a new authority", async () => {
+      const setComponentAuthority = await SetComponentAuthority({
+        authority: framework.provider.wallet.publicKey,
+        newAuthority,
+        entity: entityPda,
+        componentId: framework.exampleComponentPosition.programId,
+      });
+      await framework.provider.sendAndConfirm(
+        setComponentAuthority.transaction,
+      );
+      expect(await positionAuthority()).to.equal(newAuthority.toBase58());
+    });
+  });
+}
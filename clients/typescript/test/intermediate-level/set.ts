@@ -0,0 +1,78 @@
+import { web3, AddEntity, ApplySystem, InitializeComponent } from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+
+export function set(framework: Framework) {
+  describe("Component sets", () => {
+    const positionPdas: web3.PublicKey[] = [];
+    const entities: web3.PublicKey[] = [];
+
+    async function applyMoveAll(endsSet: boolean) {
+      const componentId = framework.exampleComponentPosition.programId;
+      return ApplySystem({
+        authority: framework.provider.wallet.publicKey,
+        systemId: framework.systemMoveAll.programId,
+        world: framework.worldPda,
+        entities: entities.map((entity, index) => ({
+          entity,
+          components: [
+            { componentId, endsSet: endsSet && index === entities.length - 1 },
+          ],
+        })),
+      });
+    }
+
+    async function positionsX() {
+      const positions = await Promise.all(
+        positionPdas.map((positionPda) =>
+          framework.exampleComponentPosition.account.position.fetch(
+            positionPda,
+          ),
+        ),
+      );
+      return positions.map((position) => position.x.toNumber());
+    }
+
+    it("Add three entities with a Position", async () => {
+      for (let i = 0; i < 3; i++) {
+        const addEntity = await AddEntity({
+          payer: framework.provider.wallet.publicKey,
+          world: framework.worldPda,
+          connection: framework.provider.connection,
+        });
+        await framework.provider.sendAndConfirm(addEntity.transaction);
+        entities.push(addEntity.entityPda);
+
+        const initializeComponent = await InitializeComponent({
+          payer: framework.provider.wallet.publicKey,
+          entity: addEntity.entityPda,
+          world: framework.worldPda,
+          componentId: framework.exampleComponentPosition.programId,
+        });
+        await framework.provider.sendAndConfirm(
+          initializeComponent.transaction,
+        );
+        positionPdas.push(initializeComponent.componentPda);
+      }
+    });
+
+    it("Apply Move All System without the end of the set should fail", async () => {
+      const applySystem = await applyMoveAll(false);
+      try {
+        await framework.provider.sendAndConfirm(applySystem.transaction);
+        assert.fail("The set was read without its end");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: AccountNotEnoughKeys",
+        );
+      }
+      expect(await positionsX()).to.deep.equal([0, 0, 0]);
+    });
+
+    it("Apply Move All System moves the Position of every entity", async () => {
+      const applySystem = await applyMoveAll(true);
+      await framework.provider.sendAndConfirm(applySystem.transaction);
+      expect(await positionsX()).to.deep.equal([1, 1, 1]);
+    });
+  });
+}
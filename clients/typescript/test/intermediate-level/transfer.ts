@@ -0,0 +1,210 @@
+import {
+  web3,
+  AddEntity,
+  FindComponentPda,
+  FindComponentProgramDataPda,
+  FindCpiAuthPda,
+  InitializeComponent,
+  TransferEntity,
+} from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+
+export function transfer(framework: Framework) {
+  describe("Transfer", () => {
+    const newOwner = web3.Keypair.generate().publicKey;
+    let entityPda: web3.PublicKey;
+
+    const components = () => [
+      { componentId: framework.exampleComponentPosition.programId },
+      {
+        componentId: framework.exampleComponentVelocity.programId,
+        seed: "component-velocity",
+      },
+    ];
+
+    it("Add an entity with a Position held by the world and a Velocity held by its owner", async () => {
+      const addEntity = await AddEntity({
+        payer: framework.provider.wallet.publicKey,
+        world: framework.worldPda,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(addEntity.transaction);
+      entityPda = addEntity.entityPda;
+
+      const initializePosition = await InitializeComponent({
+        payer: framework.provider.wallet.publicKey,
+        entity: entityPda,
+        world: framework.worldPda,
+        componentId: framework.exampleComponentPosition.programId,
+      });
+      await framework.provider.sendAndConfirm(initializePosition.transaction);
+      const initializeVelocity = await InitializeComponent({
+        payer: framework.provider.wallet.publicKey,
+        entity: entityPda,
+        world: framework.worldPda,
+        componentId: framework.exampleComponentVelocity.programId,
+        seed: "component-velocity",
+        authority: framework.provider.wallet.publicKey,
+      });
+      await framework.provider.sendAndConfirm(initializeVelocity.transaction);
+    });
+
+    it("Transfer the entity with a component of another entity should fail", async () => {
+      const componentProgram = framework.exampleComponentPosition.programId;
+      const instruction = await framework.worldProgram.methods
+        .transferEntity([{ seed: Buffer.from(""), instance: null }])
+        .accountsPartial({
+          owner: framework.provider.wallet.publicKey,
+          newOwner,
+          entity: entityPda,
+          world: framework.worldPda,
+        })
+        .remainingAccounts([
+          { pubkey: componentProgram, isSigner: false, isWritable: false },
+          {
+            pubkey: FindComponentProgramDataPda({
+              programId: componentProgram,
+            }),
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            // The Position of entity 1, rather than the one of the transferred entity
+            pubkey: FindComponentPda({
+              componentId: componentProgram,
+              entity: framework.entity1Pda,
+            }),
+            isSigner: false,
+            isWritable: true,
+          },
+          {
+            pubkey: FindCpiAuthPda({ componentId: componentProgram }),
+            isSigner: false,
+            isWritable: false,
+          },
+        ])
+        .instruction();
+      try {
+        await framework.provider.sendAndConfirm(
+          new web3.Transaction().add(instruction),
+        );
+        assert.fail("A component of another entity was handed over");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: ComponentEntityMismatch",
+        );
+      }
+    });
+
+    it("Transfer the entity and its components to a new owner", async () => {
+      // The owner is an admin of the world, which hands over the Position it holds
+      const transferEntity = await TransferEntity({
+        owner: framework.provider.wallet.publicKey,
+        newOwner,
+        entity: entityPda,
+        world: framework.worldPda,
+        components: components(),
+      });
+      await framework.provider.sendAndConfirm(transferEntity.transaction);
+
+      const entity =
+        await framework.worldProgram.account.entity.fetch(entityPda);
+      expect(entity.owner.toBase58()).to.equal(newOwner.toBase58());
+      const position =
+        await framework.exampleComponentPosition.account.position.fetch(
+          FindComponentPda({
+            componentId: framework.exampleComponentPosition.programId,
+            entity: entityPda,
+          }),
+        );
+      expect(position.boltMetadata.authority.toBase58()).to.equal(
+        newOwner.toBase58(),
+      );
+      const velocity =
+        await framework.exampleComponentVelocity.account.velocity.fetch(
+          FindComponentPda({
+            componentId: framework.exampleComponentVelocity.programId,
+            entity: entityPda,
+            seed: "component-velocity",
+          }),
+        );
+      expect(velocity.boltMetadata.authority.toBase58()).to.equal(
+        newOwner.toBase58(),
+      );
+    });
+
+    it("Transfer the entity by its former owner should fail", async () => {
+      const transferEntity = await TransferEntity({
+        owner: framework.provider.wallet.publicKey,
+        newOwner: framework.provider.wallet.publicKey,
+        entity: entityPda,
+        world: framework.worldPda,
+        components: components(),
+      });
+      try {
+        await framework.provider.sendAndConfirm(transferEntity.transaction);
+        assert.fail("The entity was transferred by its former owner");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: InvalidAuthority",
+        );
+      }
+    });
+
+    it("Transfer an entity with a Position held by the world by a player should fail", async () => {
+      // The player owns the entity, but is neither an admin nor a moderator of the world
+      const player = web3.Keypair.generate();
+      const fund = new web3.Transaction().add(
+        web3.SystemProgram.transfer({
+          fromPubkey: framework.provider.wallet.publicKey,
+          toPubkey: player.publicKey,
+          lamports: web3.LAMPORTS_PER_SOL,
+        }),
+      );
+      await framework.provider.sendAndConfirm(fund);
+      const addEntity = await AddEntity({
+        payer: player.publicKey,
+        world: framework.worldPda,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(addEntity.transaction, [player]);
+      const initializePosition = await InitializeComponent({
+        payer: player.publicKey,
+        entity: addEntity.entityPda,
+        world: framework.worldPda,
+        componentId: framework.exampleComponentPosition.programId,
+      });
+      await framework.provider.sendAndConfirm(initializePosition.transaction, [
+        player,
+      ]);
+
+      const transferEntity = await TransferEntity({
+        owner: player.publicKey,
+        newOwner,
+        entity: addEntity.entityPda,
+        world: framework.worldPda,
+        components: [
+          { componentId: framework.exampleComponentPosition.programId },
+        ],
+      });
+      try {
+        await framework.provider.sendAndConfirm(transferEntity.transaction, [
+          player,
+        ]);
+        assert.fail("The player took over a Position held by the world");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: InvalidAuthority",
+        );
+      }
+      const position =
+        await framework.exampleComponentPosition.account.position.fetch(
+          initializePosition.componentPda,
+        );
+      expect(position.boltMetadata.authority.toBase58()).to.equal(
+        framework.worldProgram.programId.toBase58(),
+      );
+    });
+  });
+}
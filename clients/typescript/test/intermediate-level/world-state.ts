@@ -0,0 +1,135 @@
+import {
+  web3,
+  AddEntity,
+  ApplySystem,
+  InitializeComponent,
+  InitializeNewWorld,
+  SetPausedSystems,
+  SetWorldState,
+} from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+
+export function worldState(framework: Framework) {
+  describe("World state", () => {
+    const authority = framework.provider.wallet.publicKey;
+    let worldPda: web3.PublicKey;
+    let entityPda: web3.PublicKey;
+    let positionPda: web3.PublicKey;
+
+    async function setState(state: "active" | "paused" | "frozen") {
+      const setWorldState = await SetWorldState({
+        authority,
+        world: worldPda,
+        state,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(setWorldState.transaction);
+    }
+
+    async function applyFly() {
+      return ApplySystem({
+        authority,
+        systemId: framework.systemFly.programId,
+        world: worldPda,
+        entities: [
+          {
+            entity: entityPda,
+            components: [
+              { componentId: framework.exampleComponentPosition.programId },
+            ],
+          },
+        ],
+      });
+    }
+
+    async function positionZ() {
+      const position =
+        await framework.exampleComponentPosition.account.position.fetch(
+          positionPda,
+        );
+      return position.z.toNumber();
+    }
+
+    it("Initialize a world with an entity and a Position", async () => {
+      const initializeNewWorld = await InitializeNewWorld({
+        payer: authority,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(initializeNewWorld.transaction);
+      worldPda = initializeNewWorld.worldPda;
+
+      const addEntity = await AddEntity({
+        payer: authority,
+        world: worldPda,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(addEntity.transaction);
+      entityPda = addEntity.entityPda;
+
+      const initializeComponent = await InitializeComponent({
+        payer: authority,
+        entity: entityPda,
+        world: worldPda,
+        componentId: framework.exampleComponentPosition.programId,
+      });
+      await framework.provider.sendAndConfirm(initializeComponent.transaction);
+      positionPda = initializeComponent.componentPda;
+    });
+
+    it("Add an entity to a paused world should fail", async () => {
+      await setState("paused");
+      const addEntity = await AddEntity({
+        payer: authority,
+        world: worldPda,
+        connection: framework.provider.connection,
+      });
+      try {
+        await framework.provider.sendAndConfirm(addEntity.transaction);
+        assert.fail("An entity was added to a paused world");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain("Error Code: WorldPaused");
+      }
+    });
+
+    it("Apply Fly System, kept running, in the paused world", async () => {
+      const setPausedSystems = await SetPausedSystems({
+        authority,
+        world: worldPda,
+        systems: [framework.systemFly.programId],
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(setPausedSystems.transaction);
+
+      const applySystem = await applyFly();
+      await framework.provider.sendAndConfirm(applySystem.transaction);
+      expect(await positionZ()).to.equal(1);
+    });
+
+    it("Apply Fly System in the frozen world should fail", async () => {
+      await setState("frozen");
+      const applySystem = await applyFly();
+      try {
+        await framework.provider.sendAndConfirm(applySystem.transaction);
+        assert.fail("A system ran in a frozen world");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain("Error Code: WorldFrozen");
+      }
+      expect(await positionZ()).to.equal(1);
+    });
+
+    it("Add an entity once the world is active again", async () => {
+      await setState("active");
+      const addEntity = await AddEntity({
+        payer: authority,
+        world: worldPda,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(addEntity.transaction);
+      const entity = await framework.worldProgram.account.entity.fetch(
+        addEntity.entityPda,
+      );
+      expect(entity.world.toBase58()).to.equal(worldPda.toBase58());
+    });
+  });
+}
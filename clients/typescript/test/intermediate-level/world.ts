@@ -29,6 +29,7 @@ export function world(framework) {
         connection: framework.provider.connection,
       });
       await framework.provider.sendAndConfirm(initializeNewWorld.transaction);
+      framework.secondWorldPda = initializeNewWorld.worldPda; // Saved for later
     });
   });
 }
@@ -0,0 +1,81 @@
+import { web3, AddEntity, ApplySystem, InitializeComponent } from "../../lib";
+import { Framework } from "../framework";
+import { assert, expect } from "chai";
+
+export function zeroCopy(framework: Framework) {
+  describe("Zero-copy components", () => {
+    let entityPda: web3.PublicKey;
+    let gridPda: web3.PublicKey;
+
+    async function applyPaint(readOnly: boolean, patch = true) {
+      return ApplySystem({
+        authority: framework.provider.wallet.publicKey,
+        systemId: framework.systemPaint.programId,
+        world: framework.worldPda,
+        entities: [
+          {
+            entity: entityPda,
+            components: [
+              { componentId: framework.componentGrid.programId, readOnly },
+            ],
+          },
+        ],
+        // The Grid is larger than the return data, only its changes are returned
+        args: { cell: 2000, color: 7 },
+        patch,
+      });
+    }
+
+    it("Add an entity with a Grid", async () => {
+      const addEntity = await AddEntity({
+        payer: framework.provider.wallet.publicKey,
+        world: framework.worldPda,
+        connection: framework.provider.connection,
+      });
+      await framework.provider.sendAndConfirm(addEntity.transaction);
+      entityPda = addEntity.entityPda;
+
+      const initializeComponent = await InitializeComponent({
+        payer: framework.provider.wallet.publicKey,
+        entity: entityPda,
+        world: framework.worldPda,
+        componentId: framework.componentGrid.programId,
+      });
+      await framework.provider.sendAndConfirm(initializeComponent.transaction);
+      gridPda = initializeComponent.componentPda;
+    });
+
+    it("Apply Paint System with a read-only Grid should fail", async () => {
+      const applySystem = await applyPaint(true);
+      try {
+        await framework.provider.sendAndConfirm(applySystem.transaction);
+        assert.fail("The system wrote a read-only Grid");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain(
+          "Error Code: InvalidComponentAccess",
+        );
+      }
+    });
+
+    it("Apply Paint System without patches should fail", async () => {
+      const applySystem = await applyPaint(false, false);
+      try {
+        await framework.provider.sendAndConfirm(applySystem.transaction);
+        assert.fail("The whole Grid was returned through the return data");
+      } catch (error) {
+        expect(error.logs.join("\n")).to.contain("Error Code: ZeroCopyOutput");
+      }
+      const grid = await framework.componentGrid.account.grid.fetch(gridPda);
+      expect(grid.cells[2000]).to.equal(0);
+    });
+
+    it("Apply Paint System as a patch paints a cell of the Grid", async () => {
+      const applySystem = await applyPaint(false);
+      await framework.provider.sendAndConfirm(applySystem.transaction);
+
+      const grid = await framework.componentGrid.account.grid.fetch(gridPda);
+      expect(grid.cells[2000]).to.equal(7);
+      expect(grid.cells.filter((cell) => cell !== 0).length).to.equal(1);
+    });
+  });
+}
@@ -4,6 +4,7 @@ import {
   web3,
   FindComponentPda,
   FindComponentProgramDataPda,
+  FindCpiAuthPda,
   FindEntityPda,
   SerializeArgs,
 } from "../../lib";
@@ -97,13 +98,15 @@ export function ecs(framework) {
         seed: "component-velocity",
       });
       const instruction = await framework.worldProgram.methods
-        .initializeComponent()
+        .initializeComponent(null)
         .accounts({
           payer: framework.provider.wallet.publicKey,
+          owner: framework.provider.wallet.publicKey,
           entity: framework.entity1Pda,
           data: framework.componentVelocityEntity1Pda,
           componentProgram: componentId,
           authority: framework.provider.wallet.publicKey,
+          world: framework.worldPda,
         })
         .instruction();
       const transaction = new anchor.web3.Transaction().add(instruction);
@@ -117,13 +120,15 @@ export function ecs(framework) {
         entity: framework.entity1Pda,
       });
       const instruction = await framework.worldProgram.methods
-        .initializeComponent()
+        .initializeComponent(null)
         .accounts({
           payer: framework.provider.wallet.publicKey,
+          owner: framework.provider.wallet.publicKey,
           entity: framework.entity1Pda,
           data: framework.componentPositionEntity1Pda,
           componentProgram: componentId,
           authority: framework.worldProgram.programId,
+          world: framework.worldPda,
         })
         .instruction();
       const transaction = new anchor.web3.Transaction().add(instruction);
@@ -137,13 +142,15 @@ export function ecs(framework) {
         entity: framework.entity2Pda,
       });
       const instruction = await framework.worldProgram.methods
-        .initializeComponent()
+        .initializeComponent(null)
         .accounts({
           payer: framework.provider.wallet.publicKey,
+          owner: framework.provider.wallet.publicKey,
           entity: framework.entity2Pda,
           data: componentPda,
           componentProgram: componentId,
           authority: framework.worldProgram.programId,
+          world: framework.worldPda,
         })
         .instruction();
       const transaction = new anchor.web3.Transaction().add(instruction);
@@ -157,13 +164,15 @@ export function ecs(framework) {
         entity: framework.entity4Pda,
       });
       const instruction = await framework.worldProgram.methods
-        .initializeComponent()
+        .initializeComponent(null)
         .accounts({
           payer: framework.provider.wallet.publicKey,
+          owner: framework.provider.wallet.publicKey,
           entity: framework.entity4Pda,
           data: framework.componentPositionEntity4Pda,
           componentProgram: componentId,
           authority: framework.worldProgram.programId,
+          world: framework.worldPda,
         })
         .instruction();
       const transaction = new anchor.web3.Transaction().add(instruction);
@@ -199,6 +208,18 @@ export function ecs(framework) {
             isSigner: false,
             isWritable: true,
           },
+          {
+            pubkey: framework.worldProgram.programId,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: FindCpiAuthPda({
+              componentId: framework.exampleComponentPosition.programId,
+            }),
+            isSigner: false,
+            isWritable: false,
+          },
         ])
         .instruction();
 
@@ -233,6 +254,18 @@ export function ecs(framework) {
             isSigner: false,
             isWritable: true,
           },
+          {
+            pubkey: framework.worldProgram.programId,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: FindCpiAuthPda({
+              componentId: framework.exampleComponentPosition.programId,
+            }),
+            isSigner: false,
+            isWritable: false,
+          },
         ])
         .instruction();
       const transaction = new anchor.web3.Transaction().add(instruction);
@@ -266,6 +299,18 @@ export function ecs(framework) {
             isSigner: false,
             isWritable: true,
           },
+          {
+            pubkey: framework.worldProgram.programId,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: FindCpiAuthPda({
+              componentId: framework.exampleComponentPosition.programId,
+            }),
+            isSigner: false,
+            isWritable: false,
+          },
         ])
         .instruction();
       const transaction = new anchor.web3.Transaction().add(instruction);
@@ -309,6 +354,25 @@ export function ecs(framework) {
             isSigner: false,
             isWritable: true,
           },
+          {
+            pubkey: framework.worldProgram.programId,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: FindCpiAuthPda({
+              componentId: framework.exampleComponentVelocity.programId,
+            }),
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: FindCpiAuthPda({
+              componentId: framework.exampleComponentPosition.programId,
+            }),
+            isSigner: false,
+            isWritable: false,
+          },
         ])
         .instruction();
       const transaction = new anchor.web3.Transaction().add(instruction);
@@ -373,6 +437,20 @@ export function ecs(framework) {
             isWritable: false,
             isSigner: false,
           },
+          {
+            pubkey: FindCpiAuthPda({
+              componentId: framework.exampleComponentVelocity.programId,
+            }),
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: FindCpiAuthPda({
+              componentId: framework.exampleComponentPosition.programId,
+            }),
+            isSigner: false,
+            isWritable: false,
+          },
         ])
         .instruction();
       const transaction = new anchor.web3.Transaction().add(instruction);
@@ -406,6 +484,18 @@ export function ecs(framework) {
             isSigner: false,
             isWritable: true,
           },
+          {
+            pubkey: framework.worldProgram.programId,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: FindCpiAuthPda({
+              componentId: framework.exampleComponentPosition.programId,
+            }),
+            isSigner: false,
+            isWritable: false,
+          },
         ])
         .instruction();
       const transaction = new anchor.web3.Transaction().add(instruction);
@@ -432,7 +522,7 @@ export function ecs(framework) {
       });
 
       const instruction = await framework.worldProgram.methods
-        .destroyComponent()
+        .destroyComponent(null)
         .accounts({
           authority: framework.provider.wallet.publicKey,
           componentProgram: framework.exampleComponentVelocity.programId,
@@ -440,6 +530,7 @@ export function ecs(framework) {
           component: framework.componentVelocityEntity1Pda,
           componentProgramData: componentProgramData,
           receiver: keypair.publicKey,
+          world: framework.worldPda,
         })
         .instruction();
       const transaction = new anchor.web3.Transaction().add(instruction);
@@ -3,6 +3,7 @@ import {
   anchor,
   FindEntityPda,
   FindComponentPda,
+  FindCpiAuthPda,
   SerializeArgs,
 } from "../../../lib";
 import { assert, expect } from "chai";
@@ -39,13 +40,15 @@ export function component(framework) {
         entity: entity,
       });
       const instruction = await framework.worldProgram.methods
-        .initializeComponent()
+        .initializeComponent(null)
         .accounts({
           payer: framework.provider.wallet.publicKey,
+          owner: framework.provider.wallet.publicKey,
           entity: entity,
           data: component,
           componentProgram: componentId,
           authority: framework.provider.wallet.publicKey,
+          world: framework.worldPda,
         })
         .instruction();
       const transaction = new anchor.web3.Transaction().add(instruction);
@@ -78,6 +81,18 @@ export function component(framework) {
             isSigner: false,
             isWritable: true,
           },
+          {
+            pubkey: framework.worldProgram.programId,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: FindCpiAuthPda({
+              componentId: framework.exampleComponentPosition.programId,
+            }),
+            isSigner: false,
+            isWritable: false,
+          },
         ])
         .instruction();
       const transaction = new anchor.web3.Transaction().add(instruction);
@@ -127,6 +142,18 @@ export function component(framework) {
             isSigner: false,
             isWritable: true,
           },
+          {
+            pubkey: framework.worldProgram.programId,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: FindCpiAuthPda({
+              componentId: framework.exampleComponentPosition.programId,
+            }),
+            isSigner: false,
+            isWritable: false,
+          },
         ])
         .instruction();
       const transaction = new anchor.web3.Transaction().add(instruction);
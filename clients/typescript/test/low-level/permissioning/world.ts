@@ -1,11 +1,11 @@
 import { expect } from "chai";
-import { anchor, SerializeArgs } from "../../../lib";
+import { anchor, FindCpiAuthPda, ROLES, SerializeArgs } from "../../../lib";
 
 export function world(framework) {
   describe("World authority", () => {
     it("Add authority", async () => {
       const instruction = await framework.worldProgram.methods
-        .addAuthority(framework.worldId)
+        .addAuthority(framework.worldId, ROLES.ALL)
         .accounts({
           authority: framework.provider.wallet.publicKey,
           newAuthority: framework.provider.wallet.publicKey,
@@ -29,7 +29,7 @@ export function world(framework) {
 
     it("Add a second authority", async () => {
       const instruction = await framework.worldProgram.methods
-        .addAuthority(framework.worldId)
+        .addAuthority(framework.worldId, ROLES.ALL)
         .accounts({
           authority: framework.provider.wallet.publicKey,
           newAuthority: framework.secondAuthority,
@@ -133,6 +133,18 @@ export function world(framework) {
             isSigner: false,
             isWritable: true,
           },
+          {
+            pubkey: framework.worldProgram.programId,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: FindCpiAuthPda({
+              componentId: framework.exampleComponentPosition.programId,
+            }),
+            isSigner: false,
+            isWritable: false,
+          },
         ])
         .instruction();
       const transaction = new anchor.web3.Transaction().add(instruction);
@@ -180,6 +192,18 @@ export function world(framework) {
             isSigner: false,
             isWritable: true,
           },
+          {
+            pubkey: framework.worldProgram.programId,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: FindCpiAuthPda({
+              componentId: framework.exampleComponentPosition.programId,
+            }),
+            isSigner: false,
+            isWritable: false,
+          },
         ])
         .instruction();
       const transaction = new anchor.web3.Transaction().add(instruction);
@@ -196,16 +220,21 @@ export function world(framework) {
     });
 
     it("Check invalid component init without CPI", async () => {
+      // A signer of its own in place of the signer of the world
+      const cpiAuth = anchor.web3.Keypair.generate();
       let invalid = false;
       try {
         await framework.exampleComponentPosition.methods
-          .initialize()
+          .initialize(null)
           .accounts({
             payer: framework.provider.wallet.publicKey,
             data: framework.componentPositionEntity1Pda,
             entity: framework.entity1Pda,
             authority: framework.provider.wallet.publicKey,
+            cpiAuth: cpiAuth.publicKey,
+            world: framework.worldPda,
           })
+          .signers([cpiAuth])
           .rpc();
       } catch (error) {
         expect(error.message).to.contain("Error Code: InvalidCaller");
@@ -215,6 +244,7 @@ export function world(framework) {
     });
 
     it("Check invalid component update without CPI", async () => {
+      const cpiAuth = anchor.web3.Keypair.generate();
       let invalid = false;
       try {
         await framework.exampleComponentPosition.methods
@@ -222,7 +252,10 @@ export function world(framework) {
           .accounts({
             boltComponent: framework.componentPositionEntity4Pda,
             authority: framework.provider.wallet.publicKey,
+            cpiAuth: cpiAuth.publicKey,
+            world: framework.worldPda,
           })
+          .signers([cpiAuth])
           .rpc();
       } catch (error) {
         expect(error.message).to.contain("Error Code: InvalidCaller");
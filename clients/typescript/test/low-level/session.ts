@@ -2,6 +2,7 @@ import { expect } from "chai";
 import {
   anchor,
   FindComponentPda,
+  FindCpiAuthPda,
   FindEntityPda,
   SerializeArgs,
   SessionProgram,
@@ -64,13 +65,15 @@ export function session(framework) {
         entity,
       });
       const instruction = await framework.worldProgram.methods
-        .initializeComponent()
+        .initializeComponent(null)
         .accounts({
           payer: sessionSigner.publicKey,
+          owner: sessionSigner.publicKey,
           entity: entity,
           data: component,
           componentProgram: componentId,
           authority: framework.worldProgram.programId,
+          world: framework.worldPda,
         })
         .instruction();
       const transaction = new anchor.web3.Transaction().add(instruction);
@@ -102,6 +105,18 @@ export function session(framework) {
             isSigner: false,
             isWritable: true,
           },
+          {
+            pubkey: framework.worldProgram.programId,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: FindCpiAuthPda({
+              componentId: framework.exampleComponentPosition.programId,
+            }),
+            isSigner: false,
+            isWritable: false,
+          },
         ])
         .instruction();
       const transaction = new anchor.web3.Transaction().add(instruction);
@@ -150,13 +165,15 @@ export function session(framework) {
         entity: entityWithAuthority,
       });
       const instruction = await framework.worldProgram.methods
-        .initializeComponent()
+        .initializeComponent(null)
         .accounts({
           payer: sessionSigner.publicKey,
+          owner: sessionSigner.publicKey,
           entity: entityWithAuthority,
           data: componentWithAuthority,
           componentProgram: componentId,
           authority: framework.provider.wallet.publicKey,
+          world: framework.worldPda,
         })
         .instruction();
       const transaction = new anchor.web3.Transaction().add(instruction);
@@ -188,6 +205,18 @@ export function session(framework) {
             isSigner: false,
             isWritable: true,
           },
+          {
+            pubkey: framework.worldProgram.programId,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: FindCpiAuthPda({
+              componentId: framework.exampleComponentPosition.programId,
+            }),
+            isSigner: false,
+            isWritable: false,
+          },
         ])
         .instruction();
       const transaction = new anchor.web3.Transaction().add(instruction);
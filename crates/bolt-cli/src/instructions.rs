@@ -97,6 +97,7 @@ pub async fn authorize(
     cfg_override: &ConfigOverride,
     world: String,
     new_authority: String,
+    roles: u8,
 ) -> Result<()> {
     let world_pubkey = parse_pubkey(&world, "Invalid world public key")?;
     let new_authority_pubkey = parse_pubkey(&new_authority, "Invalid new authority public key")?;
@@ -114,7 +115,7 @@ pub async fn authorize(
             system_program: system_program::ID,
             world: world_pubkey,
         })
-        .args(instruction::AddAuthority { world_id })
+        .args(instruction::AddAuthority { world_id, roles })
         .signer(payer)
         .send()
         .await?;
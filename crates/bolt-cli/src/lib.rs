@@ -84,6 +84,11 @@ pub struct WorldCommand {}
 pub struct AuthorizeCommand {
     pub world: String,
     pub new_authority: String,
+    #[clap(
+        long,
+        help = "Roles bitmask of the new authority, all the roles by default"
+    )]
+    pub roles: Option<u8>,
 }
 
 #[derive(Debug, Parser)]
@@ -190,7 +195,13 @@ pub async fn entry(opts: Opts) -> Result<()> {
         BoltCommand::Registry(_command) => create_registry(&opts.cfg_override).await,
         BoltCommand::World(_command) => create_world(&opts.cfg_override).await,
         BoltCommand::Authorize(command) => {
-            authorize(&opts.cfg_override, command.world, command.new_authority).await
+            authorize(
+                &opts.cfg_override,
+                command.world,
+                command.new_authority,
+                command.roles.unwrap_or(world::roles::ALL),
+            )
+            .await
         }
         BoltCommand::Deauthorize(command) => {
             deauthorize(
@@ -1,12 +1,42 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, parse_quote, Attribute, DeriveInput};
+use syn::{parse_macro_input, parse_quote, Attribute, DeriveInput, Ident};
 
+/// This macro attribute is used to define the arguments of a BOLT system.
+///
+/// The arguments are decoded from JSON by default. `#[arguments(borsh)]` decodes them with
+/// Borsh instead, which is cheaper, and describes their type in the IDL of the system. The JSON
+/// arguments are described as bytes, unless `#[arguments(idl)]` also derives the Borsh traits with
+/// the `idl-build` feature to describe their type, which requires every field to implement them.
+/// Only the public arguments types are described, the private ones are described as bytes.
+///
+/// # Example
+/// ```ignore
+/// #[arguments(borsh)]
+/// pub struct Args {
+///     pub amount: u64,
+/// }
+/// ```
 #[proc_macro_attribute]
-pub fn arguments(_attr: TokenStream, item: TokenStream) -> TokenStream {
+pub fn arguments(attr: TokenStream, item: TokenStream) -> TokenStream {
     let mut input = parse_macro_input!(item as DeriveInput);
-    let new_attr: Attribute = parse_quote! { #[derive(bolt_lang::serde::Deserialize)] };
-    input.attrs.push(new_attr);
+    let encoding = if attr.is_empty() {
+        None
+    } else {
+        Some(parse_macro_input!(attr as Ident))
+    };
+    let new_attrs: Vec<Attribute> = match encoding {
+        Some(encoding) if encoding == "borsh" => {
+            vec![parse_quote! { #[derive(AnchorSerialize, AnchorDeserialize)] }]
+        }
+        Some(encoding) if encoding == "idl" => vec![
+            parse_quote! { #[derive(bolt_lang::serde::Deserialize)] },
+            parse_quote! { #[cfg_attr(feature = "idl-build", derive(AnchorSerialize, AnchorDeserialize))] },
+        ],
+        Some(encoding) => panic!("Unknown arguments encoding: {}", encoding),
+        None => vec![parse_quote! { #[derive(bolt_lang::serde::Deserialize)] }],
+    };
+    input.attrs.extend(new_attrs);
     let expanded = quote! {
         #input
     };
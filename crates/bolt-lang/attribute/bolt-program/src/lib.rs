@@ -32,7 +32,16 @@ pub fn bolt_program(args: TokenStream, input: TokenStream) -> TokenStream {
     let args = parse_macro_input!(args as syn::AttributeArgs);
     let component_type =
         extract_type_name(&args).expect("Expected a component type in macro arguments");
-    let modified = modify_component_module(ast, &component_type);
+    let migrate_from = extract_migrate_from(&args);
+    let zero_copy = has_flag(&args, "zero_copy");
+    let emit_data = has_flag(&args, "emit_data");
+    let modified = modify_component_module(
+        ast,
+        &component_type,
+        migrate_from.as_ref(),
+        zero_copy,
+        emit_data,
+    );
     let additional_macro: Attribute = parse_quote! { #[program] };
     TokenStream::from(quote! {
         #additional_macro
@@ -41,28 +50,53 @@ pub fn bolt_program(args: TokenStream, input: TokenStream) -> TokenStream {
 }
 
 /// Modifies the component module and adds the necessary functions and structs.
-fn modify_component_module(mut module: ItemMod, component_type: &Type) -> ItemMod {
-    let (initialize_fn, initialize_struct) = generate_initialize(component_type);
-    let (destroy_fn, destroy_struct) = generate_destroy(component_type);
+fn modify_component_module(
+    mut module: ItemMod,
+    component_type: &Type,
+    migrate_from: Option<&Type>,
+    zero_copy: bool,
+    emit_data: bool,
+) -> ItemMod {
+    let (initialize_fn, initialize_struct) = generate_initialize(component_type, zero_copy);
+    let (initialize_resource_fn, initialize_resource_struct) =
+        generate_initialize_resource(component_type, zero_copy);
+    let (destroy_fn, destroy_struct) = generate_destroy(component_type, zero_copy);
     //let (apply_fn, apply_struct, apply_impl, update_fn, update_struct) = generate_instructions(component_type);
+    let component_updated = generate_component_updated(zero_copy, emit_data);
     let (update_fn, update_with_session_fn, update_struct, update_with_session_struct) =
-        generate_update(component_type);
+        generate_update(component_type, zero_copy, &component_updated);
+    let (patch_fn, patch_with_session_fn) =
+        generate_patch(component_type, zero_copy, &component_updated);
+    let (set_authority_fn, set_authority_struct) =
+        generate_set_authority(component_type, zero_copy);
+
+    let mut instructions = vec![
+        initialize_fn,
+        initialize_struct,
+        initialize_resource_fn,
+        initialize_resource_struct,
+        update_fn,
+        update_struct,
+        update_with_session_fn,
+        update_with_session_struct,
+        patch_fn,
+        patch_with_session_fn,
+        set_authority_fn,
+        set_authority_struct,
+    ];
+    // Zero-copy components have a fixed layout, which can't be migrated
+    if !zero_copy {
+        let (migrate_fn, migrate_struct) = generate_migrate(component_type, migrate_from);
+        instructions.extend([migrate_fn, migrate_struct]);
+    }
+    instructions.extend([destroy_fn, destroy_struct]);
 
     module.content = module.content.map(|(brace, mut items)| {
         items.extend(
-            vec![
-                initialize_fn,
-                initialize_struct,
-                update_fn,
-                update_struct,
-                update_with_session_fn,
-                update_with_session_struct,
-                destroy_fn,
-                destroy_struct,
-            ]
-            .into_iter()
-            .map(|item| syn::parse2(item).unwrap())
-            .collect::<Vec<_>>(),
+            instructions
+                .into_iter()
+                .map(|item| syn::parse2(item).unwrap())
+                .collect::<Vec<_>>(),
         );
 
         let modified_items = items
@@ -85,15 +119,44 @@ fn modify_component_module(mut module: ItemMod, component_type: &Type) -> ItemMo
 
 /// Extracts the type name from attribute arguments.
 fn extract_type_name(args: &AttributeArgs) -> Option<Type> {
-    args.iter().find_map(|arg| {
-        if let NestedMeta::Meta(syn::Meta::Path(path)) = arg {
+    args.iter().find_map(|arg| match arg {
+        NestedMeta::Meta(syn::Meta::Path(path))
+            if !FLAGS.iter().any(|flag| path.is_ident(flag)) =>
+        {
             Some(Type::Path(syn::TypePath {
                 qself: None,
                 path: path.clone(),
             }))
-        } else {
-            None
         }
+        _ => None,
+    })
+}
+
+/// Flags of the component, such as `zero_copy` or `emit_data`.
+const FLAGS: [&str; 2] = ["zero_copy", "emit_data"];
+
+/// Checks if the flag is set in the arguments, such as `zero_copy` for the components stored as
+/// zero-copy accounts.
+fn has_flag(args: &AttributeArgs, flag: &str) -> bool {
+    args.iter()
+        .any(|arg| matches!(arg, NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident(flag)))
+}
+
+/// Extracts the previous schema of the component, from the `migrate_from = "OldType"` argument.
+fn extract_migrate_from(args: &AttributeArgs) -> Option<Type> {
+    args.iter().find_map(|arg| match arg {
+        NestedMeta::Meta(syn::Meta::NameValue(name_value))
+            if name_value.path.is_ident("migrate_from") =>
+        {
+            match &name_value.lit {
+                syn::Lit::Str(lit) => Some(
+                    lit.parse()
+                        .expect("Expected a type in migrate_from argument"),
+                ),
+                _ => panic!("Expected a string in migrate_from argument"),
+            }
+        }
+        _ => None,
     })
 }
 
@@ -119,59 +182,69 @@ fn create_check_attribute() -> Attribute {
 }
 
 /// Generates the destroy function and struct.
-fn generate_destroy(component_type: &Type) -> (TokenStream2, TokenStream2) {
+fn generate_destroy(component_type: &Type, zero_copy: bool) -> (TokenStream2, TokenStream2) {
+    let (account_type, metadata) = if zero_copy {
+        (
+            quote! { AccountLoader },
+            quote! { ctx.accounts.component.load()?.bolt_metadata },
+        )
+    } else {
+        (
+            quote! { Account },
+            quote! { ctx.accounts.component.bolt_metadata },
+        )
+    };
     (
         quote! {
             #[automatically_derived]
-            pub fn destroy(ctx: Context<Destroy>) -> Result<()> {
-                let program_data_address =
-                    Pubkey::find_program_address(&[crate::id().as_ref()], &bolt_lang::prelude::solana_program::bpf_loader_upgradeable::id()).0;
-
-                if !program_data_address.eq(ctx.accounts.component_program_data.key) {
-                    return Err(BoltError::InvalidAuthority.into());
-                }
-
-                let program_account_data = ctx.accounts.component_program_data.try_borrow_data()?;
-                let upgrade_authority = if let bolt_lang::prelude::solana_program::bpf_loader_upgradeable::UpgradeableLoaderState::ProgramData {
-                    upgrade_authority_address,
-                    ..
-                } =
-                    bolt_lang::prelude::bincode::deserialize(&program_account_data).map_err(|_| BoltError::InvalidAuthority)?
-                {
-                    Ok(upgrade_authority_address)
-                } else {
-                    Err(anchor_lang::error::Error::from(BoltError::InvalidAuthority))
-                }?.ok_or_else(|| BoltError::InvalidAuthority)?;
+            #[allow(unused_variables)]
+            pub fn destroy(ctx: Context<Destroy>, instance: Option<Vec<u8>>) -> Result<()> {
+                let upgrade_authority = bolt_lang::program_upgrade_authority(&crate::id(), &ctx.accounts.component_program_data)?;
 
-                if ctx.accounts.authority.key != &ctx.accounts.component.bolt_metadata.authority && ctx.accounts.authority.key != &upgrade_authority {
+                let authority = ctx.accounts.authority.key;
+                let metadata = #metadata;
+                // The world signs with the authorities derived from the world of the component for
+                // the destroys it authorizes: the one of its moderators for any component of the
+                // world, the one of its systems for the components held by the world program
+                let by_world = || {
+                    metadata.world != Pubkey::default()
+                        && (authority == &bolt_lang::world::world_authority::moderator(&metadata.world).0
+                            || (metadata.authority == bolt_lang::world::ID
+                                && authority == &bolt_lang::world::world_authority::system(&metadata.world).0))
+                };
+                let signed = ctx.accounts.authority.is_signer
+                    && (authority == &metadata.authority
+                        || authority == &upgrade_authority
+                        || by_world());
+                if !signed {
                     return Err(BoltError::InvalidAuthority.into());
                 }
 
-                let instruction = anchor_lang::solana_program::sysvar::instructions::get_instruction_relative(
-                    0, &ctx.accounts.instruction_sysvar_account.to_account_info()
-                ).map_err(|_| BoltError::InvalidCaller)?;
-                if instruction.program_id != World::id() {
-                    return Err(BoltError::InvalidCaller.into());
-                }
+                emit!(bolt_lang::ComponentDestroyed {
+                    program: crate::id(),
+                    component: ctx.accounts.component.key(),
+                    entity: ctx.accounts.entity.key(),
+                });
                 Ok(())
             }
         },
         quote! {
             #[automatically_derived]
             #[derive(Accounts)]
+            #[instruction(instance: Option<Vec<u8>>)]
             pub struct Destroy<'info> {
                 #[account()]
-                pub authority: Signer<'info>,
+                pub authority: AccountInfo<'info>,
                 #[account(mut)]
                 pub receiver: AccountInfo<'info>,
                 #[account()]
                 pub entity: Account<'info, Entity>,
-                #[account(mut, close = receiver, seeds = [<#component_type>::seed(), entity.key().as_ref()], bump)]
-                pub component: Account<'info, #component_type>,
+                #[account(mut, close = receiver, seeds = [<#component_type>::seed(), entity.key().as_ref(), match instance { Some(ref instance) => instance, None => &[] }], bump)]
+                pub component: #account_type<'info, #component_type>,
                 #[account()]
                 pub component_program_data: AccountInfo<'info>,
-                #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
-                pub instruction_sysvar_account: AccountInfo<'info>,
+                #[account(address = bolt_lang::world::cpi_auth::pda(&crate::id()).0 @ BoltError::InvalidCaller)]
+                pub cpi_auth: Signer<'info>,
                 pub system_program: Program<'info, System>,
             }
         },
@@ -179,85 +252,208 @@ fn generate_destroy(component_type: &Type) -> (TokenStream2, TokenStream2) {
 }
 
 /// Generates the initialize function and struct.
-fn generate_initialize(component_type: &Type) -> (TokenStream2, TokenStream2) {
+fn generate_initialize(component_type: &Type, zero_copy: bool) -> (TokenStream2, TokenStream2) {
+    let (account_type, initialize_data) = generate_initialize_data(
+        component_type,
+        zero_copy,
+        quote! { ctx.accounts.entity.world },
+        quote! { ctx.accounts.entity.key() },
+    );
     (
         quote! {
             #[automatically_derived]
-            pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-                let instruction = anchor_lang::solana_program::sysvar::instructions::get_instruction_relative(
-                    0, &ctx.accounts.instruction_sysvar_account.to_account_info()
-                ).map_err(|_| BoltError::InvalidCaller)?;
-                if instruction.program_id != World::id() {
-                    return Err(BoltError::InvalidCaller.into());
-                }
-                ctx.accounts.data.set_inner(<#component_type>::default());
-                ctx.accounts.data.bolt_metadata.authority = *ctx.accounts.authority.key;
+            #[allow(unused_variables)]
+            pub fn initialize(ctx: Context<Initialize>, instance: Option<Vec<u8>>) -> Result<()> {
+                #initialize_data
+                emit!(bolt_lang::ComponentInitialized {
+                    program: crate::id(),
+                    component: ctx.accounts.data.key(),
+                    entity: Some(ctx.accounts.entity.key()),
+                    world: ctx.accounts.world.key(),
+                });
                 Ok(())
             }
         },
         quote! {
             #[automatically_derived]
             #[derive(Accounts)]
+            #[instruction(instance: Option<Vec<u8>>)]
             pub struct Initialize<'info>  {
                 #[account(mut)]
                 pub payer: Signer<'info>,
-                #[account(init_if_needed, payer = payer, space = <#component_type>::size(), seeds = [<#component_type>::seed(), entity.key().as_ref()], bump)]
-                pub data: Account<'info, #component_type>,
+                #[account(init_if_needed, payer = payer, space = <#component_type>::size(), seeds = [<#component_type>::seed(), entity.key().as_ref(), match instance { Some(ref instance) => instance, None => &[] }], bump)]
+                pub data: #account_type<'info, #component_type>,
                 #[account()]
                 pub entity: Account<'info, Entity>,
                 #[account()]
                 pub authority: AccountInfo<'info>,
-                #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
-                pub instruction_sysvar_account: UncheckedAccount<'info>,
+                #[account(address = bolt_lang::world::cpi_auth::pda(&crate::id()).0 @ BoltError::InvalidCaller)]
+                pub cpi_auth: Signer<'info>,
                 pub system_program: Program<'info, System>,
+                #[account(constraint = entity.world == world.key() @ BoltError::InvalidWorld)]
+                pub world: AccountInfo<'info>,
             }
         },
     )
 }
 
+/// Generates the initialize resource function and struct. A resource is the single component of
+/// its type in the world, derived from the world rather than from an entity.
+fn generate_initialize_resource(
+    component_type: &Type,
+    zero_copy: bool,
+) -> (TokenStream2, TokenStream2) {
+    let (account_type, initialize_data) = generate_initialize_data(
+        component_type,
+        zero_copy,
+        quote! { ctx.accounts.world.key() },
+        quote! { Pubkey::default() },
+    );
+    (
+        quote! {
+            #[automatically_derived]
+            pub fn initialize_resource(ctx: Context<InitializeResource>) -> Result<()> {
+                #initialize_data
+                emit!(bolt_lang::ComponentInitialized {
+                    program: crate::id(),
+                    component: ctx.accounts.data.key(),
+                    entity: None,
+                    world: ctx.accounts.world.key(),
+                });
+                Ok(())
+            }
+        },
+        quote! {
+            #[automatically_derived]
+            #[derive(Accounts)]
+            pub struct InitializeResource<'info>  {
+                #[account(mut)]
+                pub payer: Signer<'info>,
+                #[account(init_if_needed, payer = payer, space = <#component_type>::size(), seeds = [<#component_type>::seed(), world.key().as_ref()], bump)]
+                pub data: #account_type<'info, #component_type>,
+                #[account()]
+                pub authority: AccountInfo<'info>,
+                #[account(address = bolt_lang::world::cpi_auth::pda(&crate::id()).0 @ BoltError::InvalidCaller)]
+                pub cpi_auth: Signer<'info>,
+                pub system_program: Program<'info, System>,
+                #[account(owner = World::id())]
+                pub world: AccountInfo<'info>,
+            }
+        },
+    )
+}
+
+/// Generates the statements which initialize the component data, bound to the `world` and `entity`
+/// keys.
+fn generate_initialize_data(
+    component_type: &Type,
+    zero_copy: bool,
+    world: TokenStream2,
+    entity: TokenStream2,
+) -> (TokenStream2, TokenStream2) {
+    if zero_copy {
+        (
+            quote! { AccountLoader },
+            quote! {
+                let mut data = match ctx.accounts.data.load_init() {
+                    Ok(data) => data,
+                    Err(_) => ctx.accounts.data.load_mut()?,
+                };
+                *data = bolt_lang::bytemuck::Zeroable::zeroed();
+                data.bolt_metadata.authority = *ctx.accounts.authority.key;
+                data.bolt_metadata.version = <#component_type>::version();
+                data.bolt_metadata.world = #world;
+                data.bolt_metadata.entity = #entity;
+            },
+        )
+    } else {
+        (
+            quote! { Account },
+            quote! {
+                ctx.accounts.data.set_inner(<#component_type>::default());
+                ctx.accounts.data.bolt_metadata.authority = *ctx.accounts.authority.key;
+                ctx.accounts.data.bolt_metadata.version = <#component_type>::version();
+                ctx.accounts.data.bolt_metadata.world = #world;
+                ctx.accounts.data.bolt_metadata.entity = #entity;
+            },
+        )
+    }
+}
+
 /// Generates the instructions and related structs to inject in the component.
 fn generate_update(
     component_type: &Type,
+    zero_copy: bool,
+    component_updated: &TokenStream2,
 ) -> (TokenStream2, TokenStream2, TokenStream2, TokenStream2) {
+    let (account_type, metadata, write_data) = if zero_copy {
+        (
+            quote! { AccountLoader },
+            quote! { ctx.accounts.bolt_component.load()?.bolt_metadata },
+            quote! {
+                // Zero-copy components are written in place, and have a fixed size
+                let mut component = ctx.accounts.bolt_component.load_mut()?;
+                let bytes = bolt_lang::bytemuck::bytes_of_mut(&mut *component);
+                require_eq!(data.len(), bytes.len(), ErrorCode::AccountDidNotDeserialize);
+                bytes.copy_from_slice(&data);
+                component.bolt_metadata = metadata;
+                drop(component);
+            },
+        )
+    } else {
+        (
+            quote! { Account },
+            quote! { ctx.accounts.bolt_component.bolt_metadata },
+            quote! {
+                // The payer and the system program are passed as remaining accounts to resize the component
+                let mut component = <#component_type>::try_from_slice(&data)?;
+                component.bolt_metadata = metadata;
+                bolt_lang::resize_component(&ctx.accounts.bolt_component.to_account_info(), 8 + component.try_to_vec()?.len(), <#component_type>::size(), ctx.remaining_accounts)?;
+                ctx.accounts.bolt_component.set_inner(component);
+            },
+        )
+    };
     (
         quote! {
             #[automatically_derived]
-            pub fn update(ctx: Context<Update>, data: Vec<u8>) -> Result<()> {
-                require!(ctx.accounts.bolt_component.bolt_metadata.authority == World::id() || (ctx.accounts.bolt_component.bolt_metadata.authority == *ctx.accounts.authority.key && ctx.accounts.authority.is_signer), BoltError::InvalidAuthority);
+            pub fn update<'info>(ctx: Context<'_, '_, '_, 'info, Update<'info>>, data: Vec<u8>) -> Result<()> {
+                // The metadata is kept by the updates, whatever the system returns
+                let metadata = #metadata;
+                require!(metadata.authority == World::id() || (metadata.authority == *ctx.accounts.authority.key && ctx.accounts.authority.is_signer), BoltError::InvalidAuthority);
 
-                // Check if the instruction is called from the world program
-                let instruction = anchor_lang::solana_program::sysvar::instructions::get_instruction_relative(
-                    0, &ctx.accounts.instruction_sysvar_account.to_account_info()
-                ).map_err(|_| BoltError::InvalidCaller)?;
-                require_eq!(instruction.program_id, World::id(), BoltError::InvalidCaller);
+                // The components created before world binding are not bound to a world
+                let world = metadata.world;
+                require!(world == Pubkey::default() || world == ctx.accounts.world.key(), BoltError::InvalidWorld);
 
-                ctx.accounts.bolt_component.set_inner(<#component_type>::try_from_slice(&data)?);
+                #write_data
+                #component_updated
                 Ok(())
             }
         },
         quote! {
             #[automatically_derived]
-            pub fn update_with_session(ctx: Context<UpdateWithSession>, data: Vec<u8>) -> Result<()> {
-                if ctx.accounts.bolt_component.bolt_metadata.authority == World::id() {
+            pub fn update_with_session<'info>(ctx: Context<'_, '_, '_, 'info, UpdateWithSession<'info>>, data: Vec<u8>) -> Result<()> {
+                // The metadata is kept by the updates, whatever the system returns
+                let metadata = #metadata;
+                if metadata.authority == World::id() {
                     require!(Clock::get()?.unix_timestamp < ctx.accounts.session_token.valid_until, bolt_lang::session_keys::SessionError::InvalidToken);
                 } else {
                     let validity_ctx = bolt_lang::session_keys::ValidityChecker {
                         session_token: ctx.accounts.session_token.clone(),
                         session_signer: ctx.accounts.authority.clone(),
-                        authority: ctx.accounts.bolt_component.bolt_metadata.authority.clone(),
+                        authority: metadata.authority.clone(),
                         target_program: World::id(),
                     };
                     require!(ctx.accounts.session_token.validate(validity_ctx)?, bolt_lang::session_keys::SessionError::InvalidToken);
-                    require_eq!(ctx.accounts.bolt_component.bolt_metadata.authority, ctx.accounts.session_token.authority, bolt_lang::session_keys::SessionError::InvalidToken);
+                    require_eq!(metadata.authority, ctx.accounts.session_token.authority, bolt_lang::session_keys::SessionError::InvalidToken);
                 }
 
-                // Check if the instruction is called from the world program
-                let instruction = anchor_lang::solana_program::sysvar::instructions::get_instruction_relative(
-                    0, &ctx.accounts.instruction_sysvar_account.to_account_info()
-                ).map_err(|_| BoltError::InvalidCaller)?;
-                require_eq!(instruction.program_id, World::id(), BoltError::InvalidCaller);
+                // The components created before world binding are not bound to a world
+                let world = metadata.world;
+                require!(world == Pubkey::default() || world == ctx.accounts.world.key(), BoltError::InvalidWorld);
 
-                ctx.accounts.bolt_component.set_inner(<#component_type>::try_from_slice(&data)?);
+                #write_data
+                #component_updated
                 Ok(())
             }
         },
@@ -266,11 +462,14 @@ fn generate_update(
             #[derive(Accounts)]
             pub struct Update<'info> {
                 #[account(mut)]
-                pub bolt_component: Account<'info, #component_type>,
+                pub bolt_component: #account_type<'info, #component_type>,
                 #[account()]
                 pub authority: Signer<'info>,
-                #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
-                pub instruction_sysvar_account: UncheckedAccount<'info>
+                /// The world program signs the update, wherever it runs in the transaction
+                #[account(address = bolt_lang::world::cpi_auth::pda(&crate::id()).0 @ BoltError::InvalidCaller)]
+                pub cpi_auth: Signer<'info>,
+                #[account()]
+                pub world: AccountInfo<'info>,
             }
         },
         quote! {
@@ -278,13 +477,252 @@ fn generate_update(
             #[derive(Accounts)]
             pub struct UpdateWithSession<'info> {
                 #[account(mut)]
-                pub bolt_component: Account<'info, #component_type>,
+                pub bolt_component: #account_type<'info, #component_type>,
                 #[account()]
                 pub authority: Signer<'info>,
-                #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
-                pub instruction_sysvar_account: UncheckedAccount<'info>,
+                /// The world program signs the update, wherever it runs in the transaction
+                #[account(address = bolt_lang::world::cpi_auth::pda(&crate::id()).0 @ BoltError::InvalidCaller)]
+                pub cpi_auth: Signer<'info>,
                 #[account(constraint = session_token.to_account_info().owner == &bolt_lang::session_keys::ID)]
                 pub session_token: Account<'info, bolt_lang::session_keys::SessionToken>,
+                #[account()]
+                pub world: AccountInfo<'info>,
+            }
+        },
+    )
+}
+
+/// Generates the event of an updated component, which holds the updated component with `emit_data`.
+fn generate_component_updated(zero_copy: bool, emit_data: bool) -> TokenStream2 {
+    let data = match (emit_data, zero_copy) {
+        (false, _) => quote! { None },
+        (true, true) => quote! {
+            Some(bolt_lang::bytemuck::bytes_of(&*ctx.accounts.bolt_component.load()?).to_vec())
+        },
+        (true, false) => quote! { Some(ctx.accounts.bolt_component.try_to_vec()?) },
+    };
+    quote! {
+        emit!(bolt_lang::ComponentUpdated {
+            program: crate::id(),
+            component: ctx.accounts.bolt_component.key(),
+            entity: Some(metadata.entity).filter(|entity| *entity != Pubkey::default()),
+            world: ctx.accounts.world.key(),
+            data: #data,
+        });
+    }
+}
+
+/// Generates the patch instructions, applying the changed byte ranges on top of the component data.
+/// They reuse the accounts of the update instructions.
+fn generate_patch(
+    component_type: &Type,
+    zero_copy: bool,
+    component_updated: &TokenStream2,
+) -> (TokenStream2, TokenStream2) {
+    let (metadata, write_patch) = if zero_copy {
+        (
+            quote! { ctx.accounts.bolt_component.load()?.bolt_metadata },
+            quote! {
+                let mut component = ctx.accounts.bolt_component.load_mut()?;
+                bolt_lang::patch_bytes(bolt_lang::bytemuck::bytes_of_mut(&mut *component), &ranges)?;
+                component.bolt_metadata = metadata;
+                drop(component);
+            },
+        )
+    } else {
+        (
+            quote! { ctx.accounts.bolt_component.bolt_metadata },
+            quote! {
+                let component = bolt_lang::apply_patch::<#component_type>(&ctx.accounts.bolt_component.to_account_info(), &ranges)?;
+                ctx.accounts.bolt_component.set_inner(component);
+                ctx.accounts.bolt_component.bolt_metadata = metadata;
+                // The payer and the system program are passed as remaining accounts to resize the component
+                let len = 8 + ctx.accounts.bolt_component.try_to_vec()?.len();
+                bolt_lang::resize_component(&ctx.accounts.bolt_component.to_account_info(), len, <#component_type>::size(), ctx.remaining_accounts)?;
+            },
+        )
+    };
+    (
+        quote! {
+            #[automatically_derived]
+            pub fn patch<'info>(ctx: Context<'_, '_, '_, 'info, Update<'info>>, ranges: Vec<bolt_lang::PatchRange>) -> Result<()> {
+                // The metadata is kept by the updates, whatever the system returns
+                let metadata = #metadata;
+                require!(metadata.authority == World::id() || (metadata.authority == *ctx.accounts.authority.key && ctx.accounts.authority.is_signer), BoltError::InvalidAuthority);
+
+                // The components created before world binding are not bound to a world
+                let world = metadata.world;
+                require!(world == Pubkey::default() || world == ctx.accounts.world.key(), BoltError::InvalidWorld);
+
+                #write_patch
+                #component_updated
+                Ok(())
+            }
+        },
+        quote! {
+            #[automatically_derived]
+            pub fn patch_with_session<'info>(ctx: Context<'_, '_, '_, 'info, UpdateWithSession<'info>>, ranges: Vec<bolt_lang::PatchRange>) -> Result<()> {
+                // The metadata is kept by the updates, whatever the system returns
+                let metadata = #metadata;
+                if metadata.authority == World::id() {
+                    require!(Clock::get()?.unix_timestamp < ctx.accounts.session_token.valid_until, bolt_lang::session_keys::SessionError::InvalidToken);
+                } else {
+                    let validity_ctx = bolt_lang::session_keys::ValidityChecker {
+                        session_token: ctx.accounts.session_token.clone(),
+                        session_signer: ctx.accounts.authority.clone(),
+                        authority: metadata.authority.clone(),
+                        target_program: World::id(),
+                    };
+                    require!(ctx.accounts.session_token.validate(validity_ctx)?, bolt_lang::session_keys::SessionError::InvalidToken);
+                    require_eq!(metadata.authority, ctx.accounts.session_token.authority, bolt_lang::session_keys::SessionError::InvalidToken);
+                }
+
+                // The components created before world binding are not bound to a world
+                let world = metadata.world;
+                require!(world == Pubkey::default() || world == ctx.accounts.world.key(), BoltError::InvalidWorld);
+
+                #write_patch
+                #component_updated
+                Ok(())
+            }
+        },
+    )
+}
+
+/// Generates the set_authority function and struct, which lets the current authority or the
+/// program upgrade authority hand the component over to a new authority, and the moderator
+/// authority of the world hand over the components held by the world program.
+fn generate_set_authority(component_type: &Type, zero_copy: bool) -> (TokenStream2, TokenStream2) {
+    let (account_type, metadata, check_layout) = if zero_copy {
+        (
+            quote! { AccountLoader },
+            quote! { ctx.accounts.component.load_mut()?.bolt_metadata },
+            quote! {},
+        )
+    } else {
+        (
+            quote! { Account },
+            quote! { ctx.accounts.component.bolt_metadata },
+            quote! {
+                // The components stored with a shorter metadata don't fit their current layout,
+                // and are resized by their migration
+                let len = 8 + ctx.accounts.component.try_to_vec()?.len();
+                require!(ctx.accounts.component.to_account_info().data_len() >= len, BoltError::NotMigrated);
+            },
+        )
+    };
+    (
+        quote! {
+            #[automatically_derived]
+            pub fn set_authority(ctx: Context<SetAuthority>, new_authority: Pubkey) -> Result<()> {
+                #check_layout
+                let metadata = &mut #metadata;
+                // The world signs with its moderator authority, passed after the accounts, for the
+                // entity transfers of its admins and moderators, which hand over the components
+                // held by the world program
+                let by_world = || {
+                    metadata.authority == World::id()
+                        && metadata.world != Pubkey::default()
+                        && ctx.remaining_accounts.first().is_some_and(|account| {
+                            account.is_signer
+                                && account.key == &bolt_lang::world::world_authority::moderator(&metadata.world).0
+                        })
+                };
+                if metadata.authority != *ctx.accounts.authority.key && !by_world() {
+                    let upgrade_authority = bolt_lang::program_upgrade_authority(&crate::id(), &ctx.accounts.component_program_data)?;
+                    require_keys_eq!(*ctx.accounts.authority.key, upgrade_authority, BoltError::InvalidAuthority);
+                }
+                metadata.authority = new_authority;
+                Ok(())
+            }
+        },
+        quote! {
+            #[automatically_derived]
+            #[derive(Accounts)]
+            pub struct SetAuthority<'info> {
+                #[account(mut)]
+                pub component: #account_type<'info, #component_type>,
+                #[account()]
+                pub authority: Signer<'info>,
+                #[account()]
+                pub component_program_data: AccountInfo<'info>,
+                #[account(address = bolt_lang::world::cpi_auth::pda(&crate::id()).0 @ BoltError::InvalidCaller)]
+                pub cpi_auth: Signer<'info>,
+            }
+        },
+    )
+}
+
+/// Generates the migrate function and struct, which moves a component stored with a previous
+/// schema version to the current one.
+fn generate_migrate(
+    component_type: &Type,
+    migrate_from: Option<&Type>,
+) -> (TokenStream2, TokenStream2) {
+    let read_component = match migrate_from {
+        Some(old_type) => quote! {
+            let (old, metadata) = bolt_lang::read_migration_source::<#component_type, #old_type>(&component)?;
+            let mut migrated = <#component_type>::from(old);
+        },
+        None => quote! {
+            let mut migrated = <#component_type>::try_deserialize(&mut &component.try_borrow_data()?[..])?;
+            let metadata = migrated.bolt_metadata;
+        },
+    };
+    (
+        quote! {
+            #[automatically_derived]
+            #[allow(unused_variables)]
+            pub fn migrate(ctx: Context<Migrate>, instance: Option<Vec<u8>>) -> Result<()> {
+                let component = ctx.accounts.component.to_account_info();
+                if let Ok(current) = <#component_type>::try_deserialize(&mut &component.try_borrow_data()?[..]) {
+                    require_neq!(current.bolt_metadata.version, <#component_type>::version(), BoltError::AlreadyMigrated);
+                }
+
+                #read_component
+
+                if metadata.authority != World::id() && metadata.authority != *ctx.accounts.authority.key {
+                    let upgrade_authority = bolt_lang::program_upgrade_authority(&crate::id(), &ctx.accounts.component_program_data)?;
+                    require_keys_eq!(*ctx.accounts.authority.key, upgrade_authority, BoltError::InvalidAuthority);
+                }
+
+                // The components created before world binding are bound to the world of their entity,
+                // which is migrated first so that the component is stamped with its world
+                require!(ctx.accounts.entity.world != Pubkey::default(), BoltError::InvalidWorld);
+                migrated.bolt_metadata = BoltMetadata {
+                    authority: metadata.authority,
+                    version: <#component_type>::version(),
+                    world: ctx.accounts.entity.world,
+                    entity: ctx.accounts.entity.key(),
+                };
+                bolt_lang::write_component(
+                    &component,
+                    &ctx.accounts.payer.to_account_info(),
+                    &ctx.accounts.system_program.to_account_info(),
+                    &migrated,
+                    <#component_type>::size(),
+                )
+            }
+        },
+        quote! {
+            #[automatically_derived]
+            #[derive(Accounts)]
+            #[instruction(instance: Option<Vec<u8>>)]
+            pub struct Migrate<'info> {
+                #[account(mut)]
+                pub payer: Signer<'info>,
+                /// CHECK: The component is deserialized by the migration
+                #[account(mut, owner = crate::id(), seeds = [<#component_type>::seed(), entity.key().as_ref(), match instance { Some(ref instance) => instance, None => &[] }], bump)]
+                pub component: UncheckedAccount<'info>,
+                #[account()]
+                pub entity: Account<'info, Entity>,
+                #[account()]
+                pub authority: Signer<'info>,
+                #[account()]
+                pub component_program_data: AccountInfo<'info>,
+                #[account(address = bolt_lang::world::cpi_auth::pda(&crate::id()).0 @ BoltError::InvalidCaller)]
+                pub cpi_auth: Signer<'info>,
+                pub system_program: Program<'info, System>,
             }
         },
     )
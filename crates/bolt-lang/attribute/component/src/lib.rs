@@ -2,11 +2,11 @@ use proc_macro::TokenStream;
 
 use quote::quote;
 use syn::{
-    parse_macro_input, parse_quote, Attribute, DeriveInput, Lit, Meta, MetaList, MetaNameValue,
-    NestedMeta,
+    parse_macro_input, parse_quote, Attribute, AttributeArgs, DeriveInput, Lit, Meta, MetaList,
+    MetaNameValue, NestedMeta,
 };
 
-use bolt_utils::add_bolt_metadata;
+use bolt_utils::{add_bolt_metadata, add_zero_copy_bolt_metadata};
 use heck::ToSnakeCase;
 
 /// This Component attribute is used to automatically generate the seed and size functions
@@ -14,6 +14,7 @@ use heck::ToSnakeCase;
 /// The component_id can be used to define the seed used to generate the PDA which stores the component data.
 /// The macro also adds the InitSpace and Default derives to the struct.
 ///
+/// ```ignore
 /// #[component]
 /// #[derive(Default, Copy)]
 /// pub struct Position {
@@ -22,53 +23,144 @@ use heck::ToSnakeCase;
 ///     pub z: i64,
 /// }
 /// ```
+///
+/// The schema version of the component defaults to 1. When the fields change, the version is
+/// bumped and the previous fields are kept in a separate struct, converted with a `From`
+/// implementation by the generated `migrate` instruction:
+///
+/// ```ignore
+/// #[component(version = 2, migrate_from = "PositionV1")]
+/// #[derive(Default)]
+/// pub struct Position {
+///     pub x: i64,
+///     pub y: i64,
+///     pub z: i64,
+/// }
+///
+/// #[derive(AnchorSerialize, AnchorDeserialize)]
+/// pub struct PositionV1 {
+///     pub x: i64,
+///     pub y: i64,
+/// }
+///
+/// impl From<PositionV1> for Position {
+///     fn from(old: PositionV1) -> Self {
+///         Position::new(PositionInit { x: old.x, y: old.y, z: 0 })
+///     }
+/// }
+/// ```
+///
+/// Large components can be stored as zero-copy accounts, which are accessed in place instead of
+/// being deserialized. Their fields must be plain old data, and the systems should be applied with
+/// `apply_patch`, so that only the changed bytes are returned to the world program. As with Anchor
+/// zero-copy accounts, the component crate depends on `bytemuck`:
+///
+/// ```ignore
+/// #[component(zero_copy)]
+/// pub struct Grid {
+///     pub cells: [u8; 10000],
+/// }
+/// ```
+///
+/// The component programs emit the `ComponentInitialized`, `ComponentUpdated` and
+/// `ComponentDestroyed` events. With `emit_data`, the `ComponentUpdated` events also hold the
+/// updated component, at the cost of the compute units and the log space to emit it:
+///
+/// ```ignore
+/// #[component(emit_data)]
+/// #[derive(Default)]
+/// pub struct Score {
+///     pub points: u64,
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn component(attr: TokenStream, item: TokenStream) -> TokenStream {
     let mut input = parse_macro_input!(item as DeriveInput);
-    let mut component_id_value = None;
-    let mut delegate_set = false;
+    let args = parse_macro_input!(attr as AttributeArgs);
+    let metas = args
+        .into_iter()
+        .filter_map(|arg| match arg {
+            NestedMeta::Meta(meta) => Some(meta),
+            NestedMeta::Lit(_) => None,
+        })
+        .collect::<Vec<_>>();
 
-    if !attr.is_empty() {
-        let attr_meta = parse_macro_input!(attr as Meta);
-        delegate_set = is_delegate_set(&attr_meta);
-        component_id_value = match attr_meta {
+    let delegate_set = metas.iter().any(is_delegate_set);
+    let zero_copy = metas
+        .iter()
+        .any(|meta| matches!(meta, Meta::Path(path) if path.is_ident("zero_copy")));
+    let emit_data = metas
+        .iter()
+        .any(|meta| matches!(meta, Meta::Path(path) if path.is_ident("emit_data")));
+    let component_id_value = metas
+        .iter()
+        .find_map(|meta| match meta {
             Meta::Path(_) => None,
-            Meta::NameValue(meta_name_value) => extract_component_id(&meta_name_value),
-            Meta::List(meta_list) => {
-                if !delegate_set {
-                    delegate_set = is_delegate_set(&Meta::List(meta_list.clone()));
-                }
-                find_component_id_in_list(meta_list)
-            }
-        };
-    }
+            Meta::NameValue(meta_name_value) => extract_component_id(meta_name_value),
+            Meta::List(meta_list) => find_component_id_in_list(meta_list.clone()),
+        })
+        .unwrap_or_else(|| "".to_string());
+    let version = metas
+        .iter()
+        .find_map(extract_version)
+        .unwrap_or_else(|| quote! { 1 });
+    let migrate_from = metas.iter().find_map(|meta| match meta {
+        Meta::NameValue(meta_name_value) if meta_name_value.path.is_ident("migrate_from") => {
+            Some(meta_name_value.clone())
+        }
+        _ => None,
+    });
 
-    let component_id_value = component_id_value.unwrap_or_else(|| "".to_string());
+    if zero_copy && migrate_from.is_some() {
+        panic!("Zero-copy components can't be migrated");
+    }
 
-    let additional_macro: Attribute = parse_quote! { #[account] };
-    let additional_derives: Attribute = parse_quote! { #[derive(InitSpace)] };
-    input.attrs.push(additional_derives);
+    let additional_macro: Attribute = if zero_copy {
+        parse_quote! { #[account(zero_copy)] }
+    } else {
+        let additional_derives: Attribute = parse_quote! { #[derive(InitSpace)] };
+        input.attrs.push(additional_derives);
+        parse_quote! { #[account] }
+    };
 
-    let new_fn = define_new_fn(&input);
+    let new_fn = define_new_fn(&input, zero_copy);
 
-    add_bolt_metadata(&mut input);
+    if zero_copy {
+        add_zero_copy_bolt_metadata(&mut input);
+    } else {
+        add_bolt_metadata(&mut input);
+    }
 
     let name = &input.ident;
 
     let snake_case_name = name.to_string().to_snake_case();
     let component_name = syn::Ident::new(&snake_case_name, input.ident.span());
 
+    let mut bolt_program_args = match (migrate_from, zero_copy) {
+        (Some(migrate_from), _) => quote! { #name, #migrate_from },
+        (None, true) => quote! { #name, zero_copy },
+        (None, false) => quote! { #name },
+    };
+    if emit_data {
+        bolt_program_args.extend(quote! { , emit_data });
+    }
+    let size = if zero_copy {
+        quote! { 8 + std::mem::size_of::<#name>() }
+    } else {
+        quote! { 8 + <#name>::INIT_SPACE }
+    };
+
     let bolt_program = if delegate_set {
         quote! {
             #[delegate(#name)]
-            #[bolt_program(#name)]
+            #[bolt_program(#bolt_program_args)]
             pub mod #component_name {
                 use super::*;
             }
         }
     } else {
         quote! {
-            #[bolt_program(#name)]
+            #[bolt_program(#bolt_program_args)]
             pub mod #component_name {
                 use super::*;
             }
@@ -90,7 +182,11 @@ pub fn component(attr: TokenStream, item: TokenStream) -> TokenStream {
             }
 
             fn size() -> usize {
-                8 + <#name>::INIT_SPACE
+                #size
+            }
+
+            fn version() -> u8 {
+                #version
             }
         }
 
@@ -99,7 +195,7 @@ pub fn component(attr: TokenStream, item: TokenStream) -> TokenStream {
 }
 
 /// Create a fn `new` to initialize the struct without bolt_metadata field
-fn define_new_fn(input: &DeriveInput) -> proc_macro2::TokenStream {
+fn define_new_fn(input: &DeriveInput, zero_copy: bool) -> proc_macro2::TokenStream {
     let struct_name = &input.ident;
     let init_struct_name = syn::Ident::new(&format!("{}Init", struct_name), struct_name.span());
 
@@ -118,6 +214,12 @@ fn define_new_fn(input: &DeriveInput) -> proc_macro2::TokenStream {
                 quote! { #name: init_struct.#name }
             });
 
+            let metadata_type = if zero_copy {
+                quote! { ZeroCopyBoltMetadata }
+            } else {
+                quote! { BoltMetadata }
+            };
+
             // Generate the new function and the init struct
             let gen = quote! {
                 // Define a new struct to hold initialization parameters
@@ -129,7 +231,7 @@ fn define_new_fn(input: &DeriveInput) -> proc_macro2::TokenStream {
                     pub fn new(init_struct: #init_struct_name) -> Self {
                         Self {
                             #(#struct_init_fields,)*
-                            bolt_metadata: BoltMetadata::default(),
+                            bolt_metadata: #metadata_type::default(),
                         }
                     }
                 }
@@ -163,6 +265,18 @@ fn extract_component_id(meta_name_value: &MetaNameValue) -> Option<String> {
     None
 }
 
+fn extract_version(meta: &Meta) -> Option<proc_macro2::TokenStream> {
+    match meta {
+        Meta::NameValue(meta_name_value) if meta_name_value.path.is_ident("version") => {
+            match &meta_name_value.lit {
+                Lit::Int(lit) => Some(quote! { #lit }),
+                _ => panic!("Expected an integer in version argument"),
+            }
+        }
+        _ => None,
+    }
+}
+
 fn find_component_id_in_list(meta_list: MetaList) -> Option<String> {
     meta_list.nested.into_iter().find_map(|nested_meta| {
         if let NestedMeta::Meta(Meta::NameValue(meta_name_value)) = nested_meta {
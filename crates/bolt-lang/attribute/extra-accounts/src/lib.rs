@@ -71,13 +71,16 @@ pub fn extra_accounts(_attr: TokenStream, item: TokenStream) -> TokenStream {
         let index = syn::Index::from(index); // Create a compile-time index representation
         quote! {
             fn #field_name(&self) -> Result<&'c AccountInfo<'info>> {
-                self.remaining_accounts.get(Self::NUMBER_OF_COMPONENTS + #index).ok_or_else(|| ErrorCode::ConstraintAccountIsNone.into())
+                self.remaining_accounts.get(self.number_of_components() + #index).ok_or_else(|| ErrorCode::ConstraintAccountIsNone.into())
             }
         }
     });
 
     let output_trait_implementation = quote! {
-        impl<'a, 'b, 'c, 'info, T: bolt_lang::Bumps> ContextExtensions<'a, 'b, 'c, 'info, T> for Context<'a, 'b, 'c, 'info, T> {
+        impl<'a, 'b, 'c, 'info, T: bolt_lang::Bumps> ContextExtensions<'a, 'b, 'c, 'info, T> for Context<'a, 'b, 'c, 'info, T>
+        where
+            Self: NumberOfComponents<'a, 'b, 'c, 'info, T>,
+        {
             #(#helper_functions_impl)*
         }
     };
@@ -1,7 +1,10 @@
 use proc_macro::TokenStream;
 
 use quote::quote;
-use syn::{parse_macro_input, Fields, ItemStruct, Lit, Meta, NestedMeta};
+use syn::{
+    parse_macro_input, Field, Fields, GenericArgument, ItemStruct, Lit, Meta, NestedMeta,
+    PathArguments, Type,
+};
 
 /// This macro attribute is used to define a BOLT system input.
 ///
@@ -13,9 +16,34 @@ use syn::{parse_macro_input, Fields, ItemStruct, Lit, Meta, NestedMeta};
 ///#[system_input]
 ///pub struct Components {
 ///    pub position: Position,
+///    #[read_only]
+///    pub velocity: Velocity,
+///    pub shield: Option<Shield>,
+///    pub projectiles: Vec<Position>,
 ///}
 ///
 /// ```
+///
+/// Components marked as `#[read_only]` are not written back, and are passed as non-writable
+/// accounts, while the other components are passed as writable accounts. The world program id
+/// passed in place of a component account is the delimiter of the input: `Option` components are
+/// `None` when it is passed, and are not written back in that case, and `Vec` components take the
+/// consecutive component accounts up to it, and are returned in order. Every component, read-only
+/// ones included, must be bound to the world the system is applied to.
+///
+/// Components marked as `#[zero_copy]` are accessed through an `AccountLoader`, or a
+/// `ZeroCopyComponent` holding a copy of the component when they are written back, and are
+/// returned as their raw bytes. The systems writing them back are applied with `apply_patch`, which
+/// only returns the changed bytes to the world program, or with `apply_buffered`: the other apply
+/// instructions return the whole components through the return data, and fail.
+///
+/// Components marked as `#[resource]` are the resources of the world, derived from the world
+/// rather than from an entity. They are passed after the components of the entities, whatever
+/// their position in the input.
+///
+/// Along with the components, a system can return commands which the world executes after the
+/// updates, with `Ok(ctx.accounts.with_commands(commands)?)`. Such a system is applied with
+/// `apply_commands`, the other apply instructions fail if the system issues commands.
 #[proc_macro_attribute]
 pub fn system_input(_attr: TokenStream, item: TokenStream) -> TokenStream {
     // Parse the input TokenStream (the struct) into a Rust data structure
@@ -42,7 +70,7 @@ pub fn system_input(_attr: TokenStream, item: TokenStream) -> TokenStream {
                                 if let Ok(parsed_component_type) =
                                     syn::parse_str::<syn::Type>(&component_type)
                                 {
-                                    let field_type = &field.ty;
+                                    let field_type = unwrap_component_type(&field.ty);
                                     let component_import = quote! {
                                         use #parsed_component_type as #field_type;
                                     };
@@ -61,13 +89,36 @@ pub fn system_input(_attr: TokenStream, item: TokenStream) -> TokenStream {
         })
         .collect();
 
+    // Resources follow the components of the entities in the accounts and in the outputs
+    let fields = fields
+        .iter()
+        .filter(|f| !has_attribute(f, "resource"))
+        .chain(fields.iter().filter(|f| has_attribute(f, "resource")))
+        .collect::<Vec<_>>();
+
     // Transform fields for the struct definition
     let transformed_fields = fields.iter().map(|f| {
         let field_name = &f.ident;
-        let field_type = &f.ty;
-        quote! {
-            #[account()]
-            pub #field_name: Account<'info, #field_type>,
+        let field_type = unwrap_component_type(&f.ty);
+        match ComponentKind::of(f) {
+            ComponentKind::Required | ComponentKind::Resource => quote! {
+                #[account()]
+                pub #field_name: Account<'info, #field_type>,
+            },
+            ComponentKind::ZeroCopy if has_attribute(f, "read_only") => quote! {
+                #[account()]
+                pub #field_name: AccountLoader<'info, #field_type>,
+            },
+            ComponentKind::ZeroCopy => quote! {
+                pub #field_name: ZeroCopyComponent<'info, #field_type>,
+            },
+            ComponentKind::Optional => quote! {
+                #[account()]
+                pub #field_name: Option<Account<'info, #field_type>>,
+            },
+            ComponentKind::Set => quote! {
+                pub #field_name: ComponentSet<'info, #field_type>,
+            },
         }
     });
 
@@ -82,46 +133,231 @@ pub fn system_input(_attr: TokenStream, item: TokenStream) -> TokenStream {
         }
     };
 
+    // Read-only components are not serialized back to the world
+    let output_fields = fields
+        .iter()
+        .filter(|f| !has_attribute(f, "read_only"))
+        .collect::<Vec<_>>();
+
     // Generate the try_to_vec method
-    let try_to_vec_fields = fields.iter().map(|f| {
+    let try_to_vec_fields = output_fields.iter().map(|f| {
+        let field_name = &f.ident;
+        match ComponentKind::of(f) {
+            ComponentKind::Required | ComponentKind::Resource => quote! {
+                vec![self.#field_name.try_to_vec()?]
+            },
+            ComponentKind::ZeroCopy => quote! {
+                vec![bolt_lang::bytemuck::bytes_of(&*self.#field_name.load()?).to_vec()]
+            },
+            ComponentKind::Optional => quote! {
+                self.#field_name.as_ref().map(|component| component.try_to_vec()).transpose()?.into_iter().collect()
+            },
+            ComponentKind::Set => quote! {
+                self.#field_name.try_to_vec()?
+            },
+        }
+    });
+
+    let output_patches_fields = output_fields.iter().map(|f| {
+        let field_name = &f.ident;
+        match ComponentKind::of(f) {
+            ComponentKind::Required | ComponentKind::Resource => quote! {
+                vec![bolt_lang::diff_account(self.#field_name.as_ref(), &self.#field_name.try_to_vec()?)?]
+            },
+            ComponentKind::ZeroCopy => quote! {
+                vec![self.#field_name.changes()?]
+            },
+            ComponentKind::Optional => quote! {
+                self.#field_name.as_ref().map(|component| bolt_lang::diff_account(component.as_ref(), &component.try_to_vec()?)).transpose()?.into_iter().collect()
+            },
+            ComponentKind::Set => quote! {
+                self.#field_name.iter().map(|component| bolt_lang::diff_account(component.as_ref(), &component.try_to_vec()?)).collect::<Result<Vec<_>>>()?
+            },
+        }
+    });
+    let number_of_outputs = output_fields.len();
+    let writes_zero_copy = output_fields
+        .iter()
+        .any(|f| matches!(ComponentKind::of(f), ComponentKind::ZeroCopy));
+
+    // Components are read in order, component sets take a variable number of accounts
+    // The written back components must be writable, and the read-only components not
+    // Every component must belong to the world the system is applied to, read-only ones included
+    let try_from_fields = fields.iter().map(|f| {
         let field_name = &f.ident;
-        quote! {
-            self.#field_name.try_to_vec()?
+        let component_type = unwrap_component_type(&f.ty);
+        let written_back = !has_attribute(f, "read_only");
+        let account = quote! {
+            {
+                let account = remaining_accounts.get(index).ok_or_else(|| ErrorCode::ConstraintAccountIsNone)?;
+                bolt_lang::check_component_access(account, #written_back, batched)?;
+                account
+            }
+        };
+        match ComponentKind::of(f) {
+            ComponentKind::Required => quote! {
+                let #field_name: Account<#component_type> = Account::try_from(#account)?;
+                bolt_lang::check_component_world(&#field_name.bolt_metadata.world, world)?;
+                index += 1;
+            },
+            ComponentKind::Resource => {
+                let field_type = &f.ty;
+                quote! {
+                    let #field_name: Account<#field_type> = Account::try_from(#account)?;
+                    bolt_lang::check_resource::<#field_type>(&#field_name.key(), world.key)?;
+                    bolt_lang::check_component_world(&#field_name.bolt_metadata.world, world)?;
+                    index += 1;
+                }
+            }
+            ComponentKind::ZeroCopy if has_attribute(f, "read_only") => quote! {
+                let #field_name: AccountLoader<#component_type> = AccountLoader::try_from(#account)?;
+                bolt_lang::check_component_world(&#field_name.load()?.bolt_metadata.world, world)?;
+                index += 1;
+            },
+            // The component program owns the account, so the system mutates a copy of it
+            ComponentKind::ZeroCopy => quote! {
+                let #field_name: ZeroCopyComponent<#component_type> = ZeroCopyComponent::try_from(#account)?;
+                bolt_lang::check_component_world(&#field_name.load()?.bolt_metadata.world, world)?;
+                index += 1;
+            },
+            ComponentKind::Optional => quote! {
+                let #field_name = {
+                    let account = remaining_accounts.get(index).ok_or_else(|| ErrorCode::ConstraintAccountIsNone)?;
+                    if account.key() == World::id() {
+                        None
+                    } else {
+                        bolt_lang::check_component_access(account, #written_back, batched)?;
+                        let component: Account<#component_type> = Account::try_from(account)?;
+                        bolt_lang::check_component_world(&component.bolt_metadata.world, world)?;
+                        Some(component)
+                    }
+                };
+                index += 1;
+            },
+            ComponentKind::Set => quote! {
+                let #field_name: ComponentSet<#component_type> = ComponentSet::try_from_accounts(remaining_accounts.get(index..).unwrap_or_default())?;
+                for component in #field_name.iter() {
+                    bolt_lang::check_component_access(component.as_ref(), #written_back, batched)?;
+                    bolt_lang::check_component_world(&component.bolt_metadata.world, world)?;
+                }
+                index += #field_name.accounts_len();
+            },
         }
     });
+    let field_names = fields.iter().map(|f| &f.ident);
 
-    let try_from_fields = fields.iter().enumerate().map(|(i, f)| {
+    let number_of_components_fields = fields.iter().map(|f| {
         let field_name = &f.ident;
-        quote! {
-            #field_name: Account::try_from(context.remaining_accounts.as_ref().get(#i).ok_or_else(|| ErrorCode::ConstraintAccountIsNone)?)?,
+        match ComponentKind::of(f) {
+            ComponentKind::Set => quote! { self.#field_name.accounts_len() },
+            _ => quote! { 1 },
         }
     });
 
-    let number_of_components = fields.len();
+    // The written back components are found at the same index as they are read
+    let output_indices_fields = fields.iter().map(|f| {
+        let field_name = &f.ident;
+        let read_only = has_attribute(f, "read_only");
+        match ComponentKind::of(f) {
+            ComponentKind::Set if read_only => quote! {
+                index += self.#field_name.accounts_len();
+            },
+            ComponentKind::Set => quote! {
+                indices.extend((index..index + self.#field_name.len()).map(|index| index as u8));
+                index += self.#field_name.accounts_len();
+            },
+            _ if read_only => quote! {
+                index += 1;
+            },
+            ComponentKind::Optional => quote! {
+                if self.#field_name.is_some() {
+                    indices.push(index as u8);
+                }
+                index += 1;
+            },
+            _ => quote! {
+                indices.push(index as u8);
+                index += 1;
+            },
+        }
+    });
 
     let output_trait = quote! {
         pub trait NumberOfComponents<'a, 'b, 'c, 'info, T> {
-            const NUMBER_OF_COMPONENTS: usize;
+            fn number_of_components(&self) -> usize;
         }
     };
 
     let output_trait_implementation = quote! {
-        impl<'a, 'b, 'c, 'info, T: bolt_lang::Bumps> NumberOfComponents<'a, 'b, 'c, 'info, T> for Context<'a, 'b, 'c, 'info, T> {
-            const NUMBER_OF_COMPONENTS: usize = #number_of_components;
+        impl<'a, 'b, 'c, 'info> NumberOfComponents<'a, 'b, 'c, 'info, #name<'info>> for Context<'a, 'b, 'c, 'info, #name<'info>> {
+            fn number_of_components(&self) -> usize {
+                self.accounts.number_of_components()
+            }
         }
     };
 
     // Generate the implementation of try_to_vec for the struct
     let output_impl = quote! {
         impl<'info> #name<'info> {
+            /// Whether a zero-copy component is written back, which is only returned as patches or
+            /// through the output buffer.
+            pub const WRITES_ZERO_COPY: bool = #writes_zero_copy;
+
             pub fn try_to_vec(&self) -> Result<Vec<Vec<u8>>> {
-                Ok(vec![#(#try_to_vec_fields,)*])
+                let outputs: [Vec<Vec<u8>>; #number_of_outputs] = [#(#try_to_vec_fields,)*];
+                Ok(outputs.into_iter().flatten().collect())
+            }
+
+            /// The serialized components and the commands of the system, which the world executes
+            /// after the component updates.
+            pub fn with_commands(&self, commands: bolt_lang::Commands) -> Result<bolt_lang::SystemOutput> {
+                Ok(bolt_lang::SystemOutput {
+                    components: self.try_to_vec()?,
+                    commands: commands.into(),
+                })
+            }
+
+            /// The ranges of the components changed by the system, in the order of the serialized
+            /// components.
+            pub fn output_patches(&self) -> Result<Vec<Vec<bolt_lang::PatchRange>>> {
+                let patches: [Vec<Vec<bolt_lang::PatchRange>>; #number_of_outputs] = [#(#output_patches_fields,)*];
+                Ok(patches.into_iter().flatten().collect())
+            }
+
+            /// The number of component accounts, which precede the extra accounts.
+            pub fn number_of_components(&self) -> usize {
+                0 #(+ #number_of_components_fields)*
+            }
+
+            /// The indices of the written back components in the accounts, in the order of the
+            /// serialized components.
+            #[allow(unused_assignments, unused_mut)]
+            pub fn output_indices(&self) -> Vec<u8> {
+                let mut indices = Vec::new();
+                let mut index = 0usize;
+                #(#output_indices_fields)*
+                indices
             }
 
-            fn try_from<'a, 'b>(context: &Context<'a, 'b, 'info, 'info, VariadicBoltComponents<'info>>) -> Result<Self> {
+            #[allow(unused_assignments)]
+            /// Reads the components from the accounts, and checks the resources against the world the
+            /// system is applied to. The components of a batch are checked with the access of the
+            /// whole batch.
+            fn try_from(authority: &AccountInfo<'info>, world: &AccountInfo<'info>, remaining_accounts: &'info [AccountInfo<'info>], batched: bool) -> Result<Self> {
+                let mut index = 0;
+                #(#try_from_fields)*
                 Ok(Self {
-                    authority: context.accounts.authority.clone(),
-                    #(#try_from_fields)*
+                    authority: authority.clone(),
+                    #(#field_names,)*
+                })
+            }
+        }
+
+        impl<'a, 'info> bolt_lang::IntoSystemOutput for &'a mut #name<'info> {
+            fn into_system_output(self) -> Result<bolt_lang::SystemOutput> {
+                Ok(bolt_lang::SystemOutput {
+                    components: self.try_to_vec()?,
+                    commands: Vec::new(),
                 })
             }
         }
@@ -141,7 +377,78 @@ pub fn system_input(_attr: TokenStream, item: TokenStream) -> TokenStream {
             #[account()]
             pub authority: AccountInfo<'info>,
         }
+
+        #[derive(Accounts)]
+        pub struct BufferedBoltComponents<'info> {
+            /// CHECK: Authority check
+            #[account()]
+            pub authority: AccountInfo<'info>,
+            /// CHECK: The output buffer, owned by the system
+            #[account(mut)]
+            pub buffer: AccountInfo<'info>,
+        }
     };
 
     TokenStream::from(output)
 }
+
+/// How a field of the system input takes its components.
+enum ComponentKind {
+    /// `T`: exactly one component.
+    Required,
+    /// `Option<T>`: one component, absent when the world program id is passed instead.
+    Optional,
+    /// `Vec<T>`: the consecutive components owned by the program of `T`.
+    Set,
+    /// `#[zero_copy] T`: exactly one zero-copy component.
+    ZeroCopy,
+    /// `#[resource] T`: the resource of the world.
+    Resource,
+}
+
+impl ComponentKind {
+    fn of(field: &Field) -> Self {
+        let zero_copy = has_attribute(field, "zero_copy");
+        if has_attribute(field, "resource") {
+            if zero_copy || wrapper(&field.ty).is_some() {
+                panic!("Resources can't be zero-copy, optional or in a set");
+            }
+            return ComponentKind::Resource;
+        }
+        match (wrapper(&field.ty), zero_copy) {
+            (None, true) => ComponentKind::ZeroCopy,
+            (Some(_), true) => panic!("Zero-copy components can't be optional or in a set"),
+            (Some(("Option", _)), false) => ComponentKind::Optional,
+            (Some(("Vec", _)), false) => ComponentKind::Set,
+            _ => ComponentKind::Required,
+        }
+    }
+}
+
+/// Checks if the field is marked with the attribute, such as `#[read_only]`.
+fn has_attribute(field: &Field, name: &str) -> bool {
+    field.attrs.iter().any(|attr| attr.path.is_ident(name))
+}
+
+/// Returns the wrapper name and the inner type of an `Option` or `Vec` field.
+fn wrapper(ty: &Type) -> Option<(&'static str, &Type)> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    let name = ["Option", "Vec"]
+        .into_iter()
+        .find(|name| segment.ident == name)?;
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => match args.args.first() {
+            Some(GenericArgument::Type(inner)) => Some((name, inner)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the component type of a field, unwrapping optional components and component sets.
+fn unwrap_component_type(ty: &Type) -> &Type {
+    wrapper(ty).map(|(_, inner)| inner).unwrap_or(ty)
+}
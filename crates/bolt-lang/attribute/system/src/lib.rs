@@ -1,13 +1,29 @@
 use proc_macro::TokenStream;
 use proc_macro2::Ident;
-use quote::{quote, ToTokens, TokenStreamExt};
+use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use syn::{
     parse_macro_input, parse_quote, visit_mut::VisitMut, Expr, FnArg, GenericArgument, ItemFn,
-    ItemMod, ItemStruct, PathArguments, ReturnType, Stmt, Type, TypePath,
+    ItemMod, ItemStruct, PathArguments, ReturnType, Stmt, Type, TypePath, Visibility,
 };
 
 #[derive(Default)]
-struct SystemTransform;
+struct SystemTransform {
+    /// The `#[arguments]` types of the module, with their encoding.
+    arguments: Vec<(Ident, ArgumentsEncoding)>,
+    /// The arguments type of `execute`, with its encoding.
+    arguments_type: Option<(Type, ArgumentsEncoding)>,
+}
+
+/// How the arguments of a system are decoded, and whether their type is described by the IDL.
+/// JSON arguments types may hold fields without the Borsh traits the IDL relies on, so they are
+/// only described when declared with `#[arguments(idl)]`.
+#[derive(Clone, Copy, Default)]
+struct ArgumentsEncoding {
+    /// Decoded with Borsh rather than JSON, with `#[arguments(borsh)]`.
+    borsh: bool,
+    /// Described by its type rather than as bytes.
+    described: bool,
+}
 
 #[derive(Default)]
 struct Extractor {
@@ -33,6 +49,13 @@ struct Extractor {
 ///     }
 /// }
 /// ```
+///
+/// The macro also adds a `bolt_system_input` instruction to the IDL, which is not called by the
+/// world. Its accounts are the (component program, component) pairs in the order `apply` expects
+/// them, followed by the extra accounts, and its argument is the arguments type of the system,
+/// whose encoding is given by the instruction docs. The JSON arguments are described as bytes,
+/// unless they are declared with `#[arguments(idl)]`. The instruction is only built with the
+/// `idl-build` feature.
 #[proc_macro_attribute]
 pub fn system(_attr: TokenStream, item: TokenStream) -> TokenStream {
     let mut ast = parse_macro_input!(item as ItemMod);
@@ -48,7 +71,7 @@ pub fn system(_attr: TokenStream, item: TokenStream) -> TokenStream {
             SystemTransform::add_variadic_execute_function(items);
         }
 
-        let mut transform = SystemTransform;
+        let mut transform = SystemTransform::default();
         transform.visit_item_mod_mut(&mut ast);
 
         // Add `#[program]` macro and try_to_vec implementation
@@ -78,7 +101,7 @@ impl SystemTransform {
 
 /// Visits the AST and modifies the system function
 impl VisitMut for SystemTransform {
-    // Modify the return instruction to return Result<Vec<u8>>
+    // Modify the return instruction to return Result<SystemOutput>
     fn visit_expr_mut(&mut self, expr: &mut Expr) {
         match expr {
             Expr::ForLoop(for_loop_expr) => {
@@ -108,7 +131,7 @@ impl VisitMut for SystemTransform {
                 }
                 _ => {
                     parse_quote! {
-                        #inner_variable.try_to_vec()
+                        bolt_lang::IntoSystemOutput::into_system_output(#inner_variable)
                     }
                 }
             };
@@ -120,16 +143,16 @@ impl VisitMut for SystemTransform {
         }
     }
 
-    // Modify the return type of the system function to Result<Vec<u8>,*>
+    // Modify the return type of the system function to Result<SystemOutput>
     fn visit_item_fn_mut(&mut self, item_fn: &mut ItemFn) {
         if item_fn.sig.ident == "execute" {
             // Ensure execute has lifetimes and a fully-qualified Context
             Self::inject_lifetimes_and_context(item_fn);
-            // Modify the return type to Result<Vec<u8>> if necessary
+            // Modify the return type to Result<SystemOutput> if necessary
             if let ReturnType::Type(_, type_box) = &item_fn.sig.output {
                 if let Type::Path(type_path) = &**type_box {
                     if !Self::check_is_result_vec_u8(type_path) {
-                        item_fn.sig.output = parse_quote! { -> Result<Vec<Vec<u8>>> };
+                        item_fn.sig.output = parse_quote! { -> Result<bolt_lang::SystemOutput> };
                         // Modify the return statement inside the function body
                         let block = &mut item_fn.block;
                         self.visit_stmts_mut(&mut block.stmts);
@@ -137,7 +160,7 @@ impl VisitMut for SystemTransform {
                 }
             }
             // If second argument is not Vec<u8>, modify it to be so and use parse_args
-            Self::modify_args(item_fn);
+            self.modify_args(item_fn);
         }
     }
 
@@ -149,6 +172,45 @@ impl VisitMut for SystemTransform {
             None => return,
         };
 
+        self.arguments = content
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Struct(item_struct) => {
+                    Some((&item_struct.attrs, &item_struct.vis, &item_struct.ident))
+                }
+                syn::Item::Enum(item_enum) => {
+                    Some((&item_enum.attrs, &item_enum.vis, &item_enum.ident))
+                }
+                _ => None,
+            })
+            .filter_map(|(attrs, vis, ident)| {
+                let attr = attrs.iter().find(|attr| attr.path.is_ident("arguments"))?;
+                let encoding = attr.parse_args::<Ident>().ok();
+                let borsh = encoding
+                    .as_ref()
+                    .is_some_and(|encoding| encoding == "borsh");
+                let idl = encoding.as_ref().is_some_and(|encoding| encoding == "idl");
+                // The instructions generated outside of the module only reach the public types
+                let encoding = ArgumentsEncoding {
+                    borsh,
+                    described: (borsh || idl) && !matches!(vis, Visibility::Inherited),
+                };
+                Some((ident.clone(), encoding))
+            })
+            .collect();
+
+        let system_input = content.iter().find_map(|item| match item {
+            syn::Item::Struct(item_struct)
+                if item_struct
+                    .attrs
+                    .iter()
+                    .any(|attr| attr.path.is_ident("system_input")) =>
+            {
+                Some(item_struct.clone())
+            }
+            _ => None,
+        });
+
         let mut extra_accounts_struct_name = None;
 
         for item in content.iter_mut() {
@@ -167,7 +229,7 @@ impl VisitMut for SystemTransform {
                         .iter()
                         .any(|attr| attr.path.is_ident("extra_accounts"))
                     {
-                        extra_accounts_struct_name = Some(&item_struct.ident);
+                        extra_accounts_struct_name = Some(item_struct.ident.clone());
                         break;
                     }
                 }
@@ -175,7 +237,7 @@ impl VisitMut for SystemTransform {
             }
         }
 
-        if let Some(struct_name) = extra_accounts_struct_name {
+        if let Some(struct_name) = &extra_accounts_struct_name {
             let initialize_extra_accounts = quote! {
             #[automatically_derived]
                 pub fn init_extra_accounts(_ctx: Context<#struct_name>) -> Result<()> {
@@ -184,6 +246,15 @@ impl VisitMut for SystemTransform {
             };
             content.push(syn::parse2(initialize_extra_accounts).unwrap());
         }
+
+        // The system input is described by an instruction, which adds it to the IDL
+        if let Some(system_input) = system_input {
+            content.extend(Self::system_input_idl(
+                &system_input,
+                extra_accounts_struct_name.as_ref(),
+                self.arguments_type.as_ref(),
+            ));
+        }
     }
 }
 
@@ -266,11 +337,161 @@ impl SystemTransform {
             }
         }
     }
+    // Generate the bolt_system_input instruction, whose accounts are the ordered (component program,
+    // component) pairs expected by the world, followed by the extra accounts
+    fn system_input_idl(
+        system_input: &ItemStruct,
+        extra_accounts: Option<&Ident>,
+        arguments_type: Option<&(Type, ArgumentsEncoding)>,
+    ) -> Vec<syn::Item> {
+        let fields = match &system_input.fields {
+            syn::Fields::Named(fields) => &fields.named,
+            _ => return vec![],
+        };
+        // Resources follow the components of the entities
+        let fields = fields
+            .iter()
+            .filter(|f| !has_attribute(f, "resource"))
+            .chain(fields.iter().filter(|f| has_attribute(f, "resource")));
+
+        let accounts = fields.map(|f| {
+            let field_name = f.ident.as_ref().unwrap();
+            let program_name = format_ident!("{}_program", field_name);
+            let (wrapper, component_type) = match wrapper(&f.ty) {
+                Some((wrapper, inner)) => (Some(wrapper), inner),
+                None => (None, &f.ty),
+            };
+            let mut description = match wrapper {
+                Some("Option") => format!(
+                    "The optional `{}` component, the world program id when absent",
+                    field_name
+                ),
+                Some(_) => format!(
+                    "The `{}` component set, repeated with its program for each component and ended by the world program id in place of a component",
+                    field_name
+                ),
+                None if has_attribute(f, "resource") => {
+                    format!("The `{}` resource of the world", field_name)
+                }
+                None => format!("The `{}` component", field_name),
+            };
+            if has_attribute(f, "read_only") {
+                description.push_str(", read-only");
+            }
+            let program_doc = format!(" The program of the `{}` component", field_name);
+            let component_doc = format!(" {}", description);
+            let account_type = match wrapper {
+                Some("Option") => quote! { Option<AccountInfo<'info>> },
+                _ => quote! { AccountInfo<'info> },
+            };
+            let constraints = if has_attribute(f, "read_only") {
+                quote! { #[account()] }
+            } else {
+                quote! { #[account(mut)] }
+            };
+            quote! {
+                #[doc = #program_doc]
+                /// CHECK: Only describes the system input
+                #[account(address = <#component_type as anchor_lang::Owner>::owner())]
+                pub #program_name: #account_type,
+                #[doc = #component_doc]
+                /// CHECK: Only describes the system input
+                #constraints
+                pub #field_name: #account_type,
+            }
+        });
+        let extra_accounts = extra_accounts.map(|extra_accounts| {
+            quote! {
+                pub extra_accounts: #extra_accounts<'info>,
+            }
+        });
+        let (arguments_type, arguments_doc) = match arguments_type {
+            Some((arguments_type, encoding)) => (
+                if encoding.described {
+                    arguments_type.to_token_stream()
+                } else {
+                    quote! { Vec<u8> }
+                },
+                if encoding.borsh {
+                    " The arguments are encoded with Borsh."
+                } else {
+                    " The arguments are encoded with JSON."
+                },
+            ),
+            None => (
+                quote! { Vec<u8> },
+                " The arguments are passed as raw bytes.",
+            ),
+        };
+
+        vec![
+            syn::parse2(quote! {
+                #[cfg(feature = "idl-build")]
+                #[derive(Accounts)]
+                pub struct BoltSystemInput<'info> {
+                    #(#accounts)*
+                    #extra_accounts
+                }
+            })
+            .unwrap(),
+            syn::parse2(quote! {
+                #[doc = #arguments_doc]
+                #[cfg(feature = "idl-build")]
+                #[automatically_derived]
+                pub fn bolt_system_input(_ctx: Context<BoltSystemInput>, args: #arguments_type) -> Result<()> {
+                    let _ = args;
+                    Ok(())
+                }
+            })
+            .unwrap(),
+        ]
+    }
+
     fn add_variadic_execute_function(content: &mut Vec<syn::Item>) {
         content.push(syn::parse2(quote! {
             pub fn bolt_execute<'a, 'b, 'info>(ctx: Context<'a, 'b, 'info, 'info, VariadicBoltComponents<'info>>, args: Vec<u8>) -> Result<Vec<Vec<u8>>> {
-                let mut components = Components::try_from(&ctx)?;
-                let bumps = ComponentsBumps {};
+                bolt_lang::check_zero_copy_output(Components::WRITES_ZERO_COPY)?;
+                let mut components = Components::try_from(&ctx.accounts.authority, bolt_lang::world_account(ctx.remaining_accounts)?, ctx.remaining_accounts, false)?;
+                let bumps = ComponentsBumps::default();
+                let context = Context::new(ctx.program_id, &mut components, ctx.remaining_accounts, bumps);
+                bolt_lang::output_components(execute(context, args)?)
+            }
+        }).unwrap());
+        content.push(syn::parse2(quote! {
+            pub fn bolt_execute_patch<'a, 'b, 'info>(ctx: Context<'a, 'b, 'info, 'info, VariadicBoltComponents<'info>>, args: Vec<u8>) -> Result<Vec<Vec<bolt_lang::PatchRange>>> {
+                let mut components = Components::try_from(&ctx.accounts.authority, bolt_lang::world_account(ctx.remaining_accounts)?, ctx.remaining_accounts, false)?;
+                let bumps = ComponentsBumps::default();
+                let context = Context::new(ctx.program_id, &mut components, ctx.remaining_accounts, bumps);
+                bolt_lang::output_components(execute(context, args)?)?;
+                components.output_patches()
+            }
+        }).unwrap());
+        content.push(syn::parse2(quote! {
+            pub fn bolt_execute_buffered<'a, 'b, 'info>(ctx: Context<'a, 'b, 'info, 'info, BufferedBoltComponents<'info>>, args: Vec<u8>) -> Result<()> {
+                let mut components = Components::try_from(&ctx.accounts.authority, bolt_lang::world_account(ctx.remaining_accounts)?, ctx.remaining_accounts, false)?;
+                let bumps = ComponentsBumps::default();
+                let context = Context::new(ctx.program_id, &mut components, ctx.remaining_accounts, bumps);
+                let results = bolt_lang::output_components(execute(context, args)?)?;
+                bolt_lang::write_output_buffer(&ctx.accounts.buffer, &results)
+            }
+        }).unwrap());
+        content.push(syn::parse2(quote! {
+            pub fn bolt_execute_batched<'a, 'b, 'info>(ctx: Context<'a, 'b, 'info, 'info, VariadicBoltComponents<'info>>, args: Vec<u8>, pending: Vec<bolt_lang::PendingComponent>) -> Result<Vec<bolt_lang::PendingComponent>> {
+                bolt_lang::check_zero_copy_output(Components::WRITES_ZERO_COPY)?;
+                bolt_lang::with_pending_accounts(&ctx.accounts.authority, ctx.remaining_accounts, pending, |authority, remaining_accounts| {
+                    let mut components = Components::try_from(&authority, bolt_lang::world_account(remaining_accounts)?, remaining_accounts, true)?;
+                    let bumps = ComponentsBumps::default();
+                    let context = Context::new(ctx.program_id, &mut components, remaining_accounts, bumps);
+                    let results = bolt_lang::output_components(execute(context, args)?)?;
+                    bolt_lang::batched_outputs(components.output_indices(), results)
+                })
+            }
+        }).unwrap());
+        content.push(syn::parse2(quote! {
+            pub fn bolt_execute_with_commands<'a, 'b, 'info>(ctx: Context<'a, 'b, 'info, 'info, VariadicBoltComponents<'info>>, args: Vec<u8>) -> Result<bolt_lang::SystemOutput> {
+                bolt_lang::check_zero_copy_output(Components::WRITES_ZERO_COPY)?;
+                let mut components = Components::try_from(&ctx.accounts.authority, bolt_lang::world_account(ctx.remaining_accounts)?, ctx.remaining_accounts, false)?;
+                let bumps = ComponentsBumps::default();
                 let context = Context::new(ctx.program_id, &mut components, ctx.remaining_accounts, bumps);
                 execute(context, args)
             }
@@ -350,7 +571,7 @@ impl SystemTransform {
         None
     }
 
-    fn modify_args(item_fn: &mut ItemFn) {
+    fn modify_args(&mut self, item_fn: &mut ItemFn) {
         if item_fn.sig.inputs.len() >= 2 {
             let second_arg = &mut item_fn.sig.inputs[1];
             let is_vec_u8 = if let FnArg::Typed(syn::PatType { ty, .. }) = second_arg {
@@ -377,9 +598,28 @@ impl SystemTransform {
                             Ident::new(&new_ident_name, proc_macro2::Span::call_site());
                     }
                     let arg_name = pat_type.pat.to_token_stream();
+                    let encoding = match &*pat_type.ty {
+                        Type::Path(type_path) => {
+                            type_path.path.segments.last().and_then(|segment| {
+                                self.arguments
+                                    .iter()
+                                    .find(|(ident, _)| *ident == segment.ident)
+                                    .map(|(_, encoding)| *encoding)
+                            })
+                        }
+                        _ => None,
+                    }
+                    .unwrap_or_default();
+                    self.arguments_type = Some(((*pat_type.ty).clone(), encoding));
                     pat_type.ty = Box::new(syn::parse_quote! { Vec<u8> });
-                    let parse_stmt: Stmt = parse_quote! {
-                        let #arg_original_name = parse_args::<#original_type>(&#arg_name);
+                    let parse_stmt: Stmt = if encoding.borsh {
+                        parse_quote! {
+                            let #arg_original_name = parse_borsh_args::<#original_type>(&#arg_name)?;
+                        }
+                    } else {
+                        parse_quote! {
+                            let #arg_original_name = parse_args::<#original_type>(&#arg_name)?;
+                        }
                     };
                     item_fn.block.stmts.insert(0, parse_stmt);
                 }
@@ -422,3 +662,26 @@ impl VisitMut for Extractor {
         }
     }
 }
+
+/// Checks if the field is marked with the attribute, such as `#[read_only]`.
+fn has_attribute(field: &syn::Field, name: &str) -> bool {
+    field.attrs.iter().any(|attr| attr.path.is_ident(name))
+}
+
+/// Returns the wrapper name and the inner type of an `Option` or `Vec` field.
+fn wrapper(ty: &Type) -> Option<(&'static str, &Type)> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    let name = ["Option", "Vec"]
+        .into_iter()
+        .find(|name| segment.ident == name)?;
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => match args.args.first() {
+            Some(GenericArgument::Type(inner)) => Some((name, inner)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,29 @@
+use crate::BoltError;
+use anchor_lang::prelude::*;
+
+/// Ensures a component written back by the system is passed as writable, and a read-only
+/// component as non-writable. The world program writes the outputs to the writable components in
+/// order, so a mismatch would write the output of a component to another one. In a batch, the
+/// world program writes the outputs by their index instead, and a component read by a system may
+/// be written by another system of the batch, so a read-only component may be writable.
+pub fn check_component_access(
+    component: &AccountInfo,
+    written_back: bool,
+    batched: bool,
+) -> Result<()> {
+    if component.is_writable != written_back && (written_back || !batched) {
+        return Err(BoltError::InvalidComponentAccess.into());
+    }
+    Ok(())
+}
+
+/// Ensures a component, written back or read-only, is bound to the world the system is applied
+/// to, from the world of its metadata, which the zero-copy components store in their own
+/// metadata type. The components created before world binding are bound to the world of their entity by
+/// their migration.
+pub fn check_component_world(component_world: &Pubkey, world: &AccountInfo) -> Result<()> {
+    if component_world != world.key {
+        return Err(BoltError::InvalidWorld.into());
+    }
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use crate::BoltError;
+use anchor_lang::prelude::*;
+use bolt_system::PendingComponent;
+use std::cell::{RefCell, RefMut};
+use std::rc::Rc;
+
+/// A distinct account of a batched system, whose lamports and data are borrowed for as long as
+/// the system runs.
+struct BorrowedAccount<'a, 'info> {
+    account: &'a AccountInfo<'info>,
+    lamports: RefMut<'a, &'info mut u64>,
+    data: BorrowedData<'a, 'info>,
+}
+
+/// The data of the account, or the pending component replacing it, with its length.
+enum BorrowedData<'a, 'info> {
+    Account(RefMut<'a, &'info mut [u8]>),
+    Pending(Vec<u64>, usize),
+}
+
+/// Runs a batched system with the accounts holding a component updated earlier in the batch
+/// replaced by an account holding the pending component, so that the system reads the state the
+/// batch left the component in. The other accounts borrow the lamports and the data of the
+/// accounts in place, and each pending component is copied once, after the discriminator of its
+/// account, into a buffer which lives as long as the system runs.
+pub fn with_pending_accounts<'info, R>(
+    authority: &AccountInfo<'info>,
+    accounts: &'info [AccountInfo<'info>],
+    pending: Vec<PendingComponent>,
+    system: impl for<'x> FnOnce(AccountInfo<'x>, &'x [AccountInfo<'x>]) -> Result<R>,
+) -> Result<R> {
+    if pending.is_empty() {
+        return system(authority.clone(), accounts);
+    }
+    let mut buffers = Vec::with_capacity(pending.len());
+    for component in pending {
+        let account = accounts
+            .get(component.index as usize)
+            .ok_or(ErrorCode::AccountNotEnoughKeys)?;
+        let len = 8 + component.data.len();
+        // Zero-copy components are loaded from 8 bytes aligned data, like the account data
+        let mut words = vec![0u64; len.div_ceil(8)];
+        let data = &mut bytemuck::cast_slice_mut::<u64, u8>(&mut words)[..len];
+        data[..8].copy_from_slice(
+            account
+                .try_borrow_data()?
+                .get(..8)
+                .ok_or(ErrorCode::AccountDiscriminatorNotFound)?,
+        );
+        data[8..].copy_from_slice(&component.data);
+        buffers.push((account, words, len));
+    }
+
+    // An account passed several times shares its lamports and its data, so it is borrowed once
+    let originals = std::iter::once(authority)
+        .chain(accounts.iter())
+        .collect::<Vec<_>>();
+    let mut borrowed: Vec<BorrowedAccount> = Vec::new();
+    let mut positions = Vec::with_capacity(originals.len());
+    for original in &originals {
+        if let Some(position) = borrowed
+            .iter()
+            .position(|borrowed| Rc::ptr_eq(&borrowed.account.lamports, &original.lamports))
+        {
+            positions.push(position);
+            continue;
+        }
+        let data = match buffers
+            .iter()
+            .rposition(|(account, _, _)| Rc::ptr_eq(&account.lamports, &original.lamports))
+        {
+            Some(index) => {
+                let (_, words, len) = buffers.swap_remove(index);
+                BorrowedData::Pending(words, len)
+            }
+            None => BorrowedData::Account(original.try_borrow_mut_data()?),
+        };
+        positions.push(borrowed.len());
+        borrowed.push(BorrowedAccount {
+            account: original,
+            lamports: original.try_borrow_mut_lamports()?,
+            data,
+        });
+    }
+
+    let shadows = borrowed
+        .iter_mut()
+        .map(|borrowed| {
+            let data: &mut [u8] = match &mut borrowed.data {
+                BorrowedData::Account(data) => data,
+                BorrowedData::Pending(words, len) => {
+                    &mut bytemuck::cast_slice_mut::<u64, u8>(words)[..*len]
+                }
+            };
+            AccountInfo {
+                key: borrowed.account.key,
+                lamports: Rc::new(RefCell::new(&mut **borrowed.lamports)),
+                data: Rc::new(RefCell::new(data)),
+                owner: borrowed.account.owner,
+                rent_epoch: borrowed.account.rent_epoch,
+                is_signer: borrowed.account.is_signer,
+                is_writable: borrowed.account.is_writable,
+                executable: borrowed.account.executable,
+            }
+        })
+        .collect::<Vec<_>>();
+    let accounts = originals
+        .iter()
+        .zip(positions)
+        .map(|(original, position)| AccountInfo {
+            is_signer: original.is_signer,
+            is_writable: original.is_writable,
+            ..shadows[position].clone()
+        })
+        .collect::<Vec<_>>();
+    system(accounts[0].clone(), &accounts[1..])
+}
+
+/// Pairs the serialized components of a batched system with the indices of the written back
+/// components in its accounts, from which the world program writes them back.
+pub fn batched_outputs(indices: Vec<u8>, results: Vec<Vec<u8>>) -> Result<Vec<PendingComponent>> {
+    if indices.len() != results.len() {
+        return Err(BoltError::InvalidSystemOutput.into());
+    }
+    Ok(indices
+        .into_iter()
+        .zip(results)
+        .map(|(index, data)| PendingComponent { index, data })
+        .collect())
+}
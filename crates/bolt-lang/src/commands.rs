@@ -0,0 +1,97 @@
+use crate::{BoltError, ComponentTraits};
+use anchor_lang::prelude::*;
+use bolt_system::SystemOutput;
+use world::{Entity, SystemCommand};
+
+/// The structural commands of a system, executed by the world after the component updates: the
+/// components to destroy, the components to initialize and the entities to create.
+#[derive(Default, Clone, Debug)]
+pub struct Commands(Vec<SystemCommand>);
+
+impl Commands {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Destroys the component of the entity.
+    pub fn destroy<T: ComponentTraits + Owner>(
+        &mut self,
+        entity: Pubkey,
+        instance: Option<Vec<u8>>,
+    ) -> &mut Self {
+        let component = component_pda::<T>(&entity, instance.as_deref());
+        self.0.push(SystemCommand::Destroy {
+            component_program: T::owner(),
+            entity,
+            component,
+            instance,
+        });
+        self
+    }
+
+    /// Initializes the component of the entity with the given value. Its metadata is set by the
+    /// initialization, whatever the value holds.
+    pub fn initialize<T: ComponentTraits + Owner + AnchorSerialize>(
+        &mut self,
+        entity: Pubkey,
+        instance: Option<Vec<u8>>,
+        component: &T,
+    ) -> Result<&mut Self> {
+        let data = component.try_to_vec()?;
+        self.0.push(SystemCommand::Initialize {
+            component_program: T::owner(),
+            entity,
+            component: component_pda::<T>(&entity, instance.as_deref()),
+            instance,
+            data,
+            space: T::size() as u64,
+        });
+        Ok(self)
+    }
+
+    /// Creates the entity of the world derived from the extra seed, and returns its key.
+    pub fn create_entity(&mut self, world_id: u64, extra_seed: Vec<u8>) -> Pubkey {
+        let entity = Entity::pda_with_seed(world_id, &extra_seed).0;
+        self.0.push(SystemCommand::CreateEntity { extra_seed });
+        entity
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<Commands> for Vec<SystemCommand> {
+    fn from(commands: Commands) -> Self {
+        commands.0
+    }
+}
+
+/// Converts the value returned by a system into its output. The system macro calls it on the
+/// value of `Ok(...)`, which is either the system input or the output of `with_commands`.
+pub trait IntoSystemOutput {
+    fn into_system_output(self) -> Result<SystemOutput>;
+}
+
+impl IntoSystemOutput for SystemOutput {
+    fn into_system_output(self) -> Result<SystemOutput> {
+        Ok(self)
+    }
+}
+
+/// The serialized components of the output, for the entrypoints which don't execute commands.
+pub fn output_components(output: SystemOutput) -> Result<Vec<Vec<u8>>> {
+    if !output.commands.is_empty() {
+        return Err(BoltError::UnsupportedCommands.into());
+    }
+    Ok(output.components)
+}
+
+/// The component of the entity, derived from the entity and the instance seed.
+fn component_pda<T: ComponentTraits + Owner>(entity: &Pubkey, instance: Option<&[u8]>) -> Pubkey {
+    Pubkey::find_program_address(
+        &[T::seed(), entity.as_ref(), instance.unwrap_or_default()],
+        &T::owner(),
+    )
+    .0
+}
@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+use std::collections::BTreeSet;
+
+/// A variable number of components of the same type, used for the `Vec<T>` fields of a system
+/// input. The set takes the consecutive component accounts up to the world program id, which
+/// the world passes in place of a component to end the set, as it does for an absent optional
+/// component.
+///
+/// The default component type only exists for the IDL generation, which refers to the composite
+/// accounts without their generics.
+pub struct ComponentSet<'info, T: AccountSerialize + AccountDeserialize + Clone = IdlComponent> {
+    components: Vec<Account<'info, T>>,
+}
+
+impl<'info, T: AccountSerialize + AccountDeserialize + Owner + Clone> ComponentSet<'info, T> {
+    /// Takes the components from the front of the accounts, up to the world program id delimiter.
+    pub fn try_from_accounts(accounts: &'info [AccountInfo<'info>]) -> Result<Self> {
+        let len = accounts
+            .iter()
+            .position(|account| account.key == &world::ID)
+            .ok_or(ErrorCode::AccountNotEnoughKeys)?;
+        let components = accounts[..len]
+            .iter()
+            .map(Account::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { components })
+    }
+
+    /// The number of accounts taken by the set, including the delimiter.
+    pub fn accounts_len(&self) -> usize {
+        self.components.len() + 1
+    }
+}
+
+impl<'info, T: AccountSerialize + AccountDeserialize + Clone> ComponentSet<'info, T> {
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Account<'info, T>> {
+        self.components.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Account<'info, T>> {
+        self.components.iter_mut()
+    }
+
+    /// The keys of the component accounts, in the order of the serialized components.
+    pub fn keys(&self) -> Vec<Pubkey> {
+        self.components
+            .iter()
+            .map(|component| component.key())
+            .collect()
+    }
+}
+
+impl<T: AccountSerialize + AccountDeserialize + AnchorSerialize + Clone> ComponentSet<'_, T> {
+    pub fn try_to_vec(&self) -> Result<Vec<Vec<u8>>> {
+        self.components
+            .iter()
+            .map(|component| Ok(component.try_to_vec()?))
+            .collect()
+    }
+}
+
+impl<'info, T: AccountSerialize + AccountDeserialize + Clone> std::ops::Index<usize>
+    for ComponentSet<'info, T>
+{
+    type Output = Account<'info, T>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.components[index]
+    }
+}
+
+impl<T: AccountSerialize + AccountDeserialize + Clone> std::ops::IndexMut<usize>
+    for ComponentSet<'_, T>
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.components[index]
+    }
+}
+
+impl<'a, 'info, T: AccountSerialize + AccountDeserialize + Clone> IntoIterator
+    for &'a ComponentSet<'info, T>
+{
+    type Item = &'a Account<'info, T>;
+    type IntoIter = std::slice::Iter<'a, Account<'info, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.iter()
+    }
+}
+
+impl<'a, 'info, T: AccountSerialize + AccountDeserialize + Clone> IntoIterator
+    for &'a mut ComponentSet<'info, T>
+{
+    type Item = &'a mut Account<'info, T>;
+    type IntoIter = std::slice::IterMut<'a, Account<'info, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.iter_mut()
+    }
+}
+
+/// The bumps of a [`ComponentSet`], which has none.
+#[derive(Debug, Default)]
+pub struct ComponentSetBumps {}
+
+impl<'info, T: AccountSerialize + AccountDeserialize + Owner + Clone>
+    Accounts<'info, ComponentSetBumps> for ComponentSet<'info, T>
+{
+    fn try_accounts(
+        _program_id: &Pubkey,
+        accounts: &mut &'info [AccountInfo<'info>],
+        _ix_data: &[u8],
+        _bumps: &mut ComponentSetBumps,
+        _reallocs: &mut BTreeSet<Pubkey>,
+    ) -> Result<Self> {
+        let set = Self::try_from_accounts(accounts)?;
+        *accounts = &accounts[set.accounts_len()..];
+        Ok(set)
+    }
+}
+
+impl<'info, T: AccountSerialize + AccountDeserialize + Owner + Clone> AccountsExit<'info>
+    for ComponentSet<'info, T>
+{
+}
+
+impl<'info, T: AccountSerialize + AccountDeserialize + Clone> ToAccountInfos<'info>
+    for ComponentSet<'info, T>
+{
+    fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+        self.components
+            .iter()
+            .map(|component| component.to_account_info())
+            .collect()
+    }
+}
+
+impl<T: AccountSerialize + AccountDeserialize + Clone> ToAccountMetas for ComponentSet<'_, T> {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        self.components
+            .iter()
+            .flat_map(|component| component.to_account_metas(is_signer))
+            .collect()
+    }
+}
+
+#[cfg(feature = "idl-build")]
+impl ComponentSet<'_> {
+    /// The components of a set are variable, and are not part of the IDL accounts.
+    pub fn __anchor_private_gen_idl_accounts(
+        _accounts: &mut std::collections::BTreeMap<String, anchor_lang::idl::types::IdlAccount>,
+        _types: &mut std::collections::BTreeMap<String, anchor_lang::idl::types::IdlTypeDef>,
+    ) -> Vec<anchor_lang::idl::types::IdlInstructionAccountItem> {
+        Vec::new()
+    }
+}
+
+/// Stands for the component type of a [`ComponentSet`] in the IDL generation.
+#[doc(hidden)]
+#[derive(Clone)]
+pub struct IdlComponent;
+
+impl AccountSerialize for IdlComponent {}
+
+impl AccountDeserialize for IdlComponent {
+    fn try_deserialize_unchecked(_buf: &mut &[u8]) -> Result<Self> {
+        Ok(Self)
+    }
+}
+
+/// Client accounts of a [`ComponentSet`], used by the accounts generated for the system inputs.
+pub mod __client_accounts_component_set {
+    use super::*;
+
+    #[derive(AnchorSerialize)]
+    pub struct ComponentSet {
+        pub components: Vec<Pubkey>,
+    }
+
+    impl ToAccountMetas for ComponentSet {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            self.components
+                .iter()
+                .map(|component| AccountMeta::new(*component, is_signer.unwrap_or(false)))
+                .collect()
+        }
+    }
+}
+
+/// CPI accounts of a [`ComponentSet`], used by the accounts generated for the system inputs.
+pub mod __cpi_client_accounts_component_set {
+    use super::*;
+
+    pub struct ComponentSet<'info> {
+        pub components: Vec<AccountInfo<'info>>,
+    }
+
+    impl ToAccountMetas for ComponentSet<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            self.components
+                .iter()
+                .map(|component| {
+                    AccountMeta::new(*component.key, is_signer.unwrap_or(component.is_signer))
+                })
+                .collect()
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for ComponentSet<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            self.components.clone()
+        }
+    }
+}
@@ -8,4 +8,37 @@ pub enum BoltError {
     /// Returned if the wrong authority attempts to sign for an instruction
     #[msg("Invalid caller: must be called from a CPI instruction")]
     InvalidCaller,
+    /// Returned if a component patch is out of bounds or produces invalid data
+    #[msg("Invalid component patch")]
+    InvalidPatch,
+    /// Returned if a component is already at the schema version it is migrated to
+    #[msg("The component is already migrated")]
+    AlreadyMigrated,
+    /// Returned if the system outputs don't fit in the output buffer
+    #[msg("The output buffer is too small for the system outputs")]
+    OutputBufferTooSmall,
+    /// Returned if the entity or the component belongs to another world
+    #[msg("The entity or the component belongs to another world")]
+    InvalidWorld,
+    /// Returned if a resource is not derived from its world
+    #[msg("The component is not the resource of the world")]
+    InvalidResource,
+    /// Returned if the system arguments can't be decoded
+    #[msg("Invalid system arguments")]
+    InvalidArguments,
+    /// Returned if a system issues commands outside of `bolt_execute_with_commands`
+    #[msg("The system commands are only executed by apply_commands")]
+    UnsupportedCommands,
+    /// Returned if a written back component is read-only, or a read-only component is writable
+    #[msg("The written back components must be writable, and the read-only components not")]
+    InvalidComponentAccess,
+    /// Returned if a component stored with a previous layout is updated without being migrated
+    #[msg("The component must be migrated first")]
+    NotMigrated,
+    /// Returned if the system outputs don't match the components it writes back
+    #[msg("The system outputs don't match the written back components")]
+    InvalidSystemOutput,
+    /// Returned if a zero-copy component is written back through the return data
+    #[msg("Zero-copy components are written back with apply_patch or apply_buffered")]
+    ZeroCopyOutput,
 }
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+/// Emitted by a component program when a component is initialized. Resources have no entity.
+#[event]
+pub struct ComponentInitialized {
+    pub program: Pubkey,
+    pub component: Pubkey,
+    pub entity: Option<Pubkey>,
+    pub world: Pubkey,
+}
+
+/// Emitted by a component program when a component is updated or patched. The entity is read from
+/// the metadata of the component, and is `None` for the resources and the components created
+/// before it was recorded. The data holds the updated component for the components declared with
+/// `emit_data`.
+#[event]
+pub struct ComponentUpdated {
+    pub program: Pubkey,
+    pub component: Pubkey,
+    pub entity: Option<Pubkey>,
+    pub world: Pubkey,
+    pub data: Option<Vec<u8>>,
+}
+
+/// Emitted by a component program when a component is destroyed.
+#[event]
+pub struct ComponentDestroyed {
+    pub program: Pubkey,
+    pub component: Pubkey,
+    pub entity: Pubkey,
+}
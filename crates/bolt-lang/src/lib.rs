@@ -21,9 +21,10 @@ pub use bolt_attribute_bolt_system::system;
 pub use bolt_attribute_bolt_system_input::system_input;
 
 pub use bolt_system;
+pub use bolt_system::{PatchRange, PendingComponent, SystemOutput};
 pub use world;
 pub use world::program::World;
-pub use world::Entity;
+pub use world::{Entity, SystemCommand};
 
 pub use ephemeral_rollups_sdk::anchor::{DelegationProgram, MagicProgram};
 pub use ephemeral_rollups_sdk::consts::{MAGIC_CONTEXT_ID, MAGIC_PROGRAM_ID};
@@ -32,6 +33,7 @@ pub use ephemeral_rollups_sdk::cpi::{
 };
 pub use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
 
+pub use bytemuck;
 pub use serde;
 
 use std::str;
@@ -40,25 +42,72 @@ use std::str::FromStr;
 mod errors;
 pub use crate::errors::BoltError;
 
+mod events;
+pub use crate::events::{ComponentDestroyed, ComponentInitialized, ComponentUpdated};
+
+mod patch;
+pub use crate::patch::{apply_patch, diff, diff_account, patch_bytes};
+
+mod output_buffer;
+pub use crate::output_buffer::write_output_buffer;
+
+mod zero_copy;
+pub use crate::zero_copy::{
+    check_zero_copy_output, ZeroCopyComponent, ZeroCopyComponentBumps,
+    __client_accounts_zero_copy_component, __cpi_client_accounts_zero_copy_component,
+};
+
+mod batch;
+pub use crate::batch::{batched_outputs, with_pending_accounts};
+
+mod resize;
+pub use crate::resize::{resize_account, resize_component};
+
+mod migration;
+pub use crate::migration::{read_migration_source, write_component};
+
+mod resource;
+pub use crate::resource::{check_resource, world_account};
+
+mod access;
+pub use crate::access::{check_component_access, check_component_world};
+
+mod commands;
+pub use crate::commands::{output_components, Commands, IntoSystemOutput};
+
+mod component_set;
+pub use crate::component_set::{
+    ComponentSet, ComponentSetBumps, __client_accounts_component_set,
+    __cpi_client_accounts_component_set,
+};
+
 /// Export of the solana_program crate.
 pub mod solana_program {
     pub use anchor_lang::solana_program::*;
 }
 
-/// Parses the arguments from a byte array.
-pub fn parse_args<T: serde::de::DeserializeOwned>(args_p: &[u8]) -> T {
-    let args_string = str::from_utf8(args_p).expect("Failed to convert to string");
-    let args: T = serde_json::from_str(args_string)
-        .unwrap_or_else(|_| panic!("Failed to deserialize args: {:?}", args_string));
-    args
+/// Parses the JSON arguments from a byte array.
+pub fn parse_args<T: serde::de::DeserializeOwned>(args_p: &[u8]) -> Result<T> {
+    let args_string = str::from_utf8(args_p).map_err(|_| BoltError::InvalidArguments)?;
+    let args: T = serde_json::from_str(args_string).map_err(|_| {
+        msg!("Failed to deserialize args: {:?}", args_string);
+        BoltError::InvalidArguments
+    })?;
+    Ok(args)
+}
+
+/// Parses the Borsh arguments from a byte array.
+pub fn parse_borsh_args<T: AnchorDeserialize>(args_p: &[u8]) -> Result<T> {
+    T::try_from_slice(args_p).map_err(|_| BoltError::InvalidArguments.into())
 }
 
 // Useful traits for the components
 
-/// Trait used to add the seed and size functions to the component.
+/// Trait used to add the seed, size and version functions to the component.
 pub trait ComponentTraits {
     fn seed() -> &'static [u8];
     fn size() -> usize;
+    fn version() -> u8;
 }
 
 /// Allows to deserialize a component AccountInfo into a struct.
@@ -69,9 +118,88 @@ pub trait ComponentDeserialize: Sized {
 }
 
 /// Metadata for the component.
-#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Default, Copy, Clone)]
+#[derive(InitSpace, AnchorSerialize, Default, Copy, Clone)]
 pub struct BoltMetadata {
     pub authority: Pubkey,
+    /// The schema version of the component, 0 for the components created before versioning.
+    pub version: u8,
+    /// The world of the entity the component belongs to, the default key for the components
+    /// created before world binding.
+    pub world: Pubkey,
+    /// The entity the component belongs to, the default key for the resources and for the
+    /// components created before it was recorded.
+    pub entity: Pubkey,
+}
+
+impl AnchorDeserialize for BoltMetadata {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let authority = Pubkey::deserialize_reader(reader)?;
+        // The metadata is the last field of a component, the components created before
+        // versioning end with the authority, the ones created before world binding with the
+        // version, and the ones created before the entity with the world
+        let mut version = [0u8; 1];
+        let version = match reader.read(&mut version)? {
+            0 => 0,
+            _ => version[0],
+        };
+        let world = read_optional_pubkey(reader)?;
+        let entity = read_optional_pubkey(reader)?;
+        Ok(Self {
+            authority,
+            version,
+            world,
+            entity,
+        })
+    }
+}
+
+/// Reads a trailing key of the metadata, the default key when the component ends before it.
+fn read_optional_pubkey<R: std::io::Read>(reader: &mut R) -> std::io::Result<Pubkey> {
+    let mut key = [0u8; 32];
+    match reader.read(&mut key[..1])? {
+        0 => Ok(Pubkey::default()),
+        _ => {
+            reader.read_exact(&mut key[1..])?;
+            Ok(Pubkey::new_from_array(key))
+        }
+    }
+}
+
+/// Returns the upgrade authority of the program, read from its program data account.
+pub fn program_upgrade_authority(
+    program_id: &Pubkey,
+    program_data: &AccountInfo,
+) -> Result<Pubkey> {
+    let program_data_address = Pubkey::find_program_address(
+        &[program_id.as_ref()],
+        &solana_program::bpf_loader_upgradeable::id(),
+    )
+    .0;
+    if !program_data_address.eq(program_data.key) {
+        return Err(BoltError::InvalidAuthority.into());
+    }
+
+    let program_account_data = program_data.try_borrow_data()?;
+    if let solana_program::bpf_loader_upgradeable::UpgradeableLoaderState::ProgramData {
+        upgrade_authority_address,
+        ..
+    } = bincode::deserialize(&program_account_data).map_err(|_| BoltError::InvalidAuthority)?
+    {
+        upgrade_authority_address.ok_or_else(|| BoltError::InvalidAuthority.into())
+    } else {
+        Err(BoltError::InvalidAuthority.into())
+    }
+}
+
+/// Metadata for the zero-copy components, padded to keep the components free of padding bytes.
+#[zero_copy]
+#[derive(Default)]
+pub struct ZeroCopyBoltMetadata {
+    pub authority: Pubkey,
+    pub version: u8,
+    pub _padding: [u8; 7],
+    pub world: Pubkey,
+    pub entity: Pubkey,
 }
 
 /// Wrapper method to create a pubkey from a string
@@ -0,0 +1,42 @@
+use crate::{resize_account, BoltMetadata};
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+/// Reads a component stored with a previous schema: the discriminator of `T`, followed by the
+/// fields of `Old` and the metadata.
+pub fn read_migration_source<T: Discriminator, Old: AnchorDeserialize>(
+    component: &AccountInfo,
+) -> Result<(Old, BoltMetadata)> {
+    let data = component.try_borrow_data()?;
+    if !data.starts_with(T::DISCRIMINATOR) {
+        return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+    let mut data = &data[T::DISCRIMINATOR.len()..];
+    let old = Old::deserialize(&mut data).map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+    let metadata =
+        BoltMetadata::deserialize(&mut data).map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+    Ok((old, metadata))
+}
+
+/// Writes the component to the account, growing it to the serialized component or `min_len`,
+/// whichever is larger.
+pub fn write_component<'info, T: AccountSerialize>(
+    component: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    value: &T,
+    min_len: usize,
+) -> Result<()> {
+    let mut serialized = Vec::new();
+    value.try_serialize(&mut serialized)?;
+    resize_account(
+        component,
+        payer,
+        system_program,
+        serialized.len().max(min_len),
+    )?;
+    let mut data = component.try_borrow_mut_data()?;
+    data[..serialized.len()].copy_from_slice(&serialized);
+    data[serialized.len()..].fill(0);
+    Ok(())
+}
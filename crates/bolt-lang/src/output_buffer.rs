@@ -0,0 +1,13 @@
+use crate::BoltError;
+use anchor_lang::prelude::*;
+
+/// Writes the serialized components to the output buffer of the system, which the world program
+/// reads back after the execution. The outputs are bounded by the size of the buffer rather than
+/// the return data. The buffer is owned by the system, since only the owner of an account can
+/// write its data.
+pub fn write_output_buffer(buffer: &AccountInfo, outputs: &Vec<Vec<u8>>) -> Result<()> {
+    let mut data = buffer.try_borrow_mut_data()?;
+    outputs
+        .serialize(&mut &mut data[..])
+        .map_err(|_| BoltError::OutputBufferTooSmall.into())
+}
@@ -0,0 +1,86 @@
+use crate::BoltError;
+use anchor_lang::prelude::*;
+use bolt_system::PatchRange;
+
+/// Unchanged bytes shorter than this are folded into the surrounding ranges, since every range
+/// carries an 8 bytes overhead (offset and length).
+const MIN_GAP: usize = 8;
+
+/// Computes the ranges of `after` which differ from `before`.
+pub fn diff(before: &[u8], after: &[u8]) -> Vec<PatchRange> {
+    let mut ranges: Vec<PatchRange> = Vec::new();
+    let mut index = 0;
+    while index < after.len() {
+        if before.get(index) == Some(&after[index]) {
+            index += 1;
+            continue;
+        }
+        let start = index;
+        let mut end = index + 1;
+        let mut gap = 0;
+        while end < after.len() && gap < MIN_GAP {
+            if before.get(end) == Some(&after[end]) {
+                gap += 1;
+            } else {
+                gap = 0;
+            }
+            end += 1;
+        }
+        let end = end - gap;
+        ranges.push(PatchRange {
+            offset: start as u32,
+            data: after[start..end].to_vec(),
+        });
+        index = end;
+    }
+    ranges
+}
+
+/// Diffs the serialized component returned by a system against the data of its account.
+pub fn diff_account(account: &AccountInfo, after: &[u8]) -> Result<Vec<PatchRange>> {
+    let data = account.try_borrow_data()?;
+    Ok(diff(data.get(8..).unwrap_or_default(), after))
+}
+
+/// Applies the patch to the data of the component account and deserializes the result.
+/// The ranges past the end of the data grow it; the caller resizes the account to fit the result.
+pub fn apply_patch<T: AnchorDeserialize>(
+    account: &AccountInfo,
+    ranges: &[PatchRange],
+) -> Result<T> {
+    let mut data = account
+        .try_borrow_data()?
+        .get(8..)
+        .ok_or(BoltError::InvalidPatch)?
+        .to_vec();
+    let end = patch_end(ranges)?;
+    if end > data.len() {
+        data.resize(end, 0);
+    }
+    patch_bytes(&mut data, ranges)?;
+    T::deserialize(&mut data.as_slice()).map_err(|_| BoltError::InvalidPatch.into())
+}
+
+/// Returns the end of the furthest range of the patch.
+fn patch_end(ranges: &[PatchRange]) -> Result<usize> {
+    ranges.iter().try_fold(0, |end, range| {
+        let range_end = (range.offset as usize)
+            .checked_add(range.data.len())
+            .ok_or(BoltError::InvalidPatch)?;
+        Ok(end.max(range_end))
+    })
+}
+
+/// Applies the patch in place, to the bytes of a zero-copy component.
+pub fn patch_bytes(data: &mut [u8], ranges: &[PatchRange]) -> Result<()> {
+    for range in ranges {
+        let start = range.offset as usize;
+        let end = start
+            .checked_add(range.data.len())
+            .ok_or(BoltError::InvalidPatch)?;
+        data.get_mut(start..end)
+            .ok_or(BoltError::InvalidPatch)?
+            .copy_from_slice(&range.data);
+    }
+    Ok(())
+}
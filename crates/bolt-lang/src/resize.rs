@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+/// Grows the account to `len` bytes, keeping it rent exempt with the lamports of the payer. An
+/// account is never shrunk below its high-water mark, so that the rent it holds is never paid out
+/// to whoever happens to update it.
+pub fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    len: usize,
+) -> Result<()> {
+    if len <= account.data_len() {
+        return Ok(());
+    }
+    let rent = Rent::get()?.minimum_balance(len);
+    let lamports = account.lamports();
+    if rent > lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent - lamports,
+        )?;
+    }
+    account.realloc(len, true)?;
+    Ok(())
+}
+
+/// Grows a component account to `len` bytes, or `min_len` if larger, with the payer and the system
+/// program passed as the remaining accounts of an update. An account which does not need to grow
+/// is left as is.
+pub fn resize_component<'info>(
+    component: &AccountInfo<'info>,
+    len: usize,
+    min_len: usize,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let len = len.max(min_len);
+    if len <= component.data_len() {
+        return Ok(());
+    }
+    match remaining_accounts {
+        [payer, system_program, ..] => resize_account(component, payer, system_program, len),
+        _ => Err(ErrorCode::AccountNotEnoughKeys.into()),
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{BoltError, ComponentTraits};
+use anchor_lang::prelude::*;
+
+/// Ensures the component is the resource of its world, derived from the world rather than from
+/// an entity.
+pub fn check_resource<T: ComponentTraits + Owner>(
+    component: &Pubkey,
+    world: &Pubkey,
+) -> Result<()> {
+    let resource = Pubkey::find_program_address(&[T::seed(), world.as_ref()], &T::owner()).0;
+    if resource != *component {
+        return Err(BoltError::InvalidResource.into());
+    }
+    Ok(())
+}
+
+/// The world the system is applied to, which the world program passes after the components and
+/// the extra accounts of the system.
+pub fn world_account<'c, 'info>(
+    remaining_accounts: &'c [AccountInfo<'info>],
+) -> Result<&'c AccountInfo<'info>> {
+    remaining_accounts
+        .last()
+        .ok_or_else(|| ErrorCode::AccountNotEnoughKeys.into())
+}
@@ -0,0 +1,179 @@
+use crate::component_set::IdlComponent;
+use crate::patch::diff;
+use crate::BoltError;
+use anchor_lang::prelude::*;
+use anchor_lang::ZeroCopy;
+use bolt_system::PatchRange;
+use std::cell::{Ref, RefCell, RefMut};
+use std::collections::BTreeSet;
+use std::marker::PhantomData;
+
+/// A zero-copy component written by a system. The component program owns the account, so the
+/// system mutates a copy of the component, loaded like an `AccountLoader`. The copy is diffed
+/// against the account, so that only the changed bytes are returned to the world program.
+///
+/// The default component type only exists for the IDL generation, which refers to the composite
+/// accounts without their generics.
+pub struct ZeroCopyComponent<'info, T = IdlComponent> {
+    account: AccountInfo<'info>,
+    // Zero-copy components are loaded from 8 bytes aligned data, like the account data
+    data: RefCell<Vec<u64>>,
+    component: PhantomData<T>,
+}
+
+impl<'info, T: ZeroCopy + Owner> ZeroCopyComponent<'info, T> {
+    /// Copies the component, after checking the account as `AccountLoader` does.
+    pub fn try_from(account: &AccountInfo<'info>) -> Result<Self> {
+        if account.owner != &T::owner() {
+            return Err(Error::from(ErrorCode::AccountOwnedByWrongProgram)
+                .with_pubkeys((*account.owner, T::owner())));
+        }
+        let source = account.try_borrow_data()?;
+        if !source.starts_with(T::DISCRIMINATOR) {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+        let len = Self::len();
+        let source = source
+            .get(..len)
+            .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+        let mut data = vec![0u64; len.div_ceil(8)];
+        bytemuck::cast_slice_mut::<u64, u8>(&mut data)[..len].copy_from_slice(source);
+        Ok(Self {
+            account: account.clone(),
+            data: RefCell::new(data),
+            component: PhantomData,
+        })
+    }
+
+    pub fn load(&self) -> Result<Ref<'_, T>> {
+        Ok(Ref::map(self.data.borrow(), |data| {
+            bytemuck::from_bytes(&bytemuck::cast_slice::<u64, u8>(data)[8..Self::len()])
+        }))
+    }
+
+    pub fn load_mut(&self) -> Result<RefMut<'_, T>> {
+        Ok(RefMut::map(self.data.borrow_mut(), |data| {
+            bytemuck::from_bytes_mut(&mut bytemuck::cast_slice_mut::<u64, u8>(data)[8..Self::len()])
+        }))
+    }
+
+    /// The bytes of the component, after the discriminator.
+    pub fn try_to_vec(&self) -> Result<Vec<u8>> {
+        Ok(bytemuck::bytes_of(&*self.load()?).to_vec())
+    }
+
+    /// The ranges of the component changed by the system.
+    pub fn changes(&self) -> Result<Vec<PatchRange>> {
+        let account = self.account.try_borrow_data()?;
+        Ok(diff(
+            &account[8..Self::len()],
+            bytemuck::bytes_of(&*self.load()?),
+        ))
+    }
+
+    /// The length of the discriminator and of the component.
+    fn len() -> usize {
+        8 + std::mem::size_of::<T>()
+    }
+}
+
+impl<'info, T> AsRef<AccountInfo<'info>> for ZeroCopyComponent<'info, T> {
+    fn as_ref(&self) -> &AccountInfo<'info> {
+        &self.account
+    }
+}
+
+/// Ensures a system writing back a zero-copy component doesn't return it whole through the return
+/// data, which a zero-copy component usually outgrows. Such a system returns its changes with
+/// `bolt_execute_patch`, or writes its outputs to the buffer with `bolt_execute_buffered`.
+pub fn check_zero_copy_output(writes_zero_copy: bool) -> Result<()> {
+    if writes_zero_copy {
+        return Err(BoltError::ZeroCopyOutput.into());
+    }
+    Ok(())
+}
+
+/// The bumps of a [`ZeroCopyComponent`], which has none.
+#[derive(Debug, Default)]
+pub struct ZeroCopyComponentBumps {}
+
+impl<'info, T: ZeroCopy + Owner> Accounts<'info, ZeroCopyComponentBumps>
+    for ZeroCopyComponent<'info, T>
+{
+    fn try_accounts(
+        _program_id: &Pubkey,
+        accounts: &mut &'info [AccountInfo<'info>],
+        _ix_data: &[u8],
+        _bumps: &mut ZeroCopyComponentBumps,
+        _reallocs: &mut BTreeSet<Pubkey>,
+    ) -> Result<Self> {
+        let (account, rest) = accounts
+            .split_first()
+            .ok_or(ErrorCode::AccountNotEnoughKeys)?;
+        *accounts = rest;
+        Self::try_from(account)
+    }
+}
+
+impl<'info, T> AccountsExit<'info> for ZeroCopyComponent<'info, T> {}
+
+impl<'info, T> ToAccountInfos<'info> for ZeroCopyComponent<'info, T> {
+    fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+        vec![self.account.clone()]
+    }
+}
+
+impl<T> ToAccountMetas for ZeroCopyComponent<'_, T> {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        self.account.to_account_metas(is_signer)
+    }
+}
+
+#[cfg(feature = "idl-build")]
+impl ZeroCopyComponent<'_> {
+    /// The components of a system input are described by its `bolt_system_input` instruction.
+    pub fn __anchor_private_gen_idl_accounts(
+        _accounts: &mut std::collections::BTreeMap<String, anchor_lang::idl::types::IdlAccount>,
+        _types: &mut std::collections::BTreeMap<String, anchor_lang::idl::types::IdlTypeDef>,
+    ) -> Vec<anchor_lang::idl::types::IdlInstructionAccountItem> {
+        Vec::new()
+    }
+}
+
+/// Client accounts of a [`ZeroCopyComponent`], used by the accounts generated for the system
+/// inputs.
+pub mod __client_accounts_zero_copy_component {
+    use super::*;
+
+    #[derive(AnchorSerialize)]
+    pub struct ZeroCopyComponent {
+        pub component: Pubkey,
+    }
+
+    impl ToAccountMetas for ZeroCopyComponent {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            vec![AccountMeta::new(self.component, is_signer.unwrap_or(false))]
+        }
+    }
+}
+
+/// CPI accounts of a [`ZeroCopyComponent`], used by the accounts generated for the system inputs.
+pub mod __cpi_client_accounts_zero_copy_component {
+    use super::*;
+
+    pub struct ZeroCopyComponent<'info> {
+        pub component: AccountInfo<'info>,
+    }
+
+    impl ToAccountMetas for ZeroCopyComponent<'_> {
+        fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+            self.component.to_account_metas(is_signer)
+        }
+    }
+
+    impl<'info> ToAccountInfos<'info> for ZeroCopyComponent<'info> {
+        fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+            vec![self.component.clone()]
+        }
+    }
+}
@@ -2,6 +2,15 @@ use proc_macro2::Ident;
 use syn::{DeriveInput, Field, Type, Visibility};
 
 pub fn add_bolt_metadata(input: &mut DeriveInput) {
+    add_metadata_field(input, "BoltMetadata");
+}
+
+/// Adds the metadata of a zero-copy component, which keeps the component free of padding bytes.
+pub fn add_zero_copy_bolt_metadata(input: &mut DeriveInput) {
+    add_metadata_field(input, "ZeroCopyBoltMetadata");
+}
+
+fn add_metadata_field(input: &mut DeriveInput, metadata_type: &str) {
     let authority_field: Field = Field {
         attrs: vec![],
         vis: Visibility::Public(syn::VisPublic {
@@ -11,7 +20,7 @@ pub fn add_bolt_metadata(input: &mut DeriveInput) {
         colon_token: Some(Default::default()),
         ty: Type::Path(syn::TypePath {
             qself: None,
-            path: syn::Path::from(Ident::new("BoltMetadata", proc_macro2::Span::call_site())),
+            path: syn::Path::from(Ident::new(metadata_type, proc_macro2::Span::call_site())),
         }),
     };
     if let syn::Data::Struct(ref mut data) = input.data {
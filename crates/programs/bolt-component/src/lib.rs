@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use bolt_system::PatchRange;
 
 declare_id!("CmP2djJgABZ4cRokm4ndxuq6LerqpNHLBsaUv2XKEJua");
 
@@ -6,11 +7,15 @@ declare_id!("CmP2djJgABZ4cRokm4ndxuq6LerqpNHLBsaUv2XKEJua");
 pub mod bolt_component {
     use super::*;
 
-    pub fn initialize(_ctx: Context<Initialize>) -> Result<()> {
+    pub fn initialize(_ctx: Context<Initialize>, _instance: Option<Vec<u8>>) -> Result<()> {
         Ok(())
     }
 
-    pub fn destroy(_ctx: Context<Destroy>) -> Result<()> {
+    pub fn initialize_resource(_ctx: Context<InitializeResource>) -> Result<()> {
+        Ok(())
+    }
+
+    pub fn destroy(_ctx: Context<Destroy>, _instance: Option<Vec<u8>>) -> Result<()> {
         Ok(())
     }
 
@@ -22,6 +27,25 @@ pub mod bolt_component {
         Ok(())
     }
 
+    pub fn migrate(_ctx: Context<Migrate>, _instance: Option<Vec<u8>>) -> Result<()> {
+        Ok(())
+    }
+
+    pub fn set_authority(_ctx: Context<SetAuthority>, _new_authority: Pubkey) -> Result<()> {
+        Ok(())
+    }
+
+    pub fn patch(_ctx: Context<Update>, _ranges: Vec<PatchRange>) -> Result<()> {
+        Ok(())
+    }
+
+    pub fn patch_with_session(
+        _ctx: Context<UpdateWithSession>,
+        _ranges: Vec<PatchRange>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
     #[derive(Accounts)]
     pub struct Update<'info> {
         #[account(mut)]
@@ -30,9 +54,12 @@ pub mod bolt_component {
         #[account()]
         /// CHECK: The authority of the component
         pub authority: Signer<'info>,
-        #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
-        /// CHECK: The instruction sysvar
-        pub instruction_sysvar_account: AccountInfo<'info>,
+        #[account()]
+        /// CHECK: The world signer of the CPI
+        pub cpi_auth: Signer<'info>,
+        #[account()]
+        /// CHECK: The world of the component
+        pub world: AccountInfo<'info>,
     }
 
     #[derive(Accounts)]
@@ -43,12 +70,15 @@ pub mod bolt_component {
         #[account()]
         /// CHECK: The authority of the component
         pub authority: Signer<'info>,
-        #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
-        /// CHECK: The instruction sysvar
-        pub instruction_sysvar_account: AccountInfo<'info>,
+        #[account()]
+        /// CHECK: The world signer of the CPI
+        pub cpi_auth: Signer<'info>,
         #[account()]
         /// CHECK: The session token
         pub session_token: UncheckedAccount<'info>,
+        #[account()]
+        /// CHECK: The world of the component
+        pub world: AccountInfo<'info>,
     }
 }
 
@@ -65,16 +95,40 @@ pub struct Initialize<'info> {
     #[account()]
     /// CHECK: The authority of the component
     pub authority: AccountInfo<'info>,
-    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
-    /// CHECK: The instruction sysvar
-    pub instruction_sysvar_account: AccountInfo<'info>,
+    #[account()]
+    /// CHECK: The world signer of the CPI
+    pub cpi_auth: Signer<'info>,
     pub system_program: Program<'info, System>,
+    #[account()]
+    /// CHECK: The world of the entity
+    pub world: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeResource<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: The resource to initialize
+    pub data: UncheckedAccount<'info>,
+    #[account()]
+    /// CHECK: The authority of the resource
+    pub authority: AccountInfo<'info>,
+    #[account()]
+    /// CHECK: The world signer of the CPI
+    pub cpi_auth: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    #[account()]
+    /// CHECK: The world of the resource
+    pub world: AccountInfo<'info>,
 }
 
 #[derive(Accounts)]
 pub struct Destroy<'info> {
     #[account()]
-    pub authority: Signer<'info>,
+    /// CHECK: The authority of the component, or an authority of the world for the destroys it
+    /// authorizes
+    pub authority: AccountInfo<'info>,
     #[account(mut)]
     /// CHECK: The receiver of the component
     pub receiver: AccountInfo<'info>,
@@ -87,15 +141,33 @@ pub struct Destroy<'info> {
     #[account()]
     /// CHECK: The component program data
     pub component_program_data: AccountInfo<'info>,
-    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
-    /// CHECK: The instruction sysvar
-    pub instruction_sysvar_account: AccountInfo<'info>,
+    #[account()]
+    /// CHECK: The world signer of the CPI
+    pub cpi_auth: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct SetAuthority<'info> {
+    #[account(mut)]
+    /// CHECK: The component to transfer
+    pub component: UncheckedAccount<'info>,
+    #[account()]
+    pub authority: Signer<'info>,
+    #[account()]
+    /// CHECK: The component program data
+    pub component_program_data: AccountInfo<'info>,
+    #[account()]
+    /// CHECK: The world signer of the CPI
+    pub cpi_auth: Signer<'info>,
+}
+
 #[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Default, Copy, Clone)]
 pub struct BoltMetadata {
     pub authority: Pubkey,
+    pub version: u8,
+    pub world: Pubkey,
+    pub entity: Pubkey,
 }
 
 #[cfg(feature = "cpi")]
@@ -127,3 +199,24 @@ impl<'info> CpiContextBuilder<'info> for cpi::accounts::UpdateWithSession<'info>
         CpiContext::new(cpi_program, self)
     }
 }
+
+#[derive(Accounts)]
+pub struct Migrate<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: The component to migrate
+    pub component: UncheckedAccount<'info>,
+    #[account()]
+    /// CHECK: The entity of the component
+    pub entity: AccountInfo<'info>,
+    #[account()]
+    pub authority: Signer<'info>,
+    #[account()]
+    /// CHECK: The component program data
+    pub component_program_data: AccountInfo<'info>,
+    #[account()]
+    /// CHECK: The world signer of the CPI
+    pub cpi_auth: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
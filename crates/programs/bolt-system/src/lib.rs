@@ -8,11 +8,108 @@ pub mod bolt_system {
     pub fn bolt_execute(_ctx: Context<BoltExecute>, _args: Vec<u8>) -> Result<Vec<Vec<u8>>> {
         Ok(Vec::new())
     }
+
+    pub fn bolt_execute_patch(
+        _ctx: Context<BoltExecute>,
+        _args: Vec<u8>,
+    ) -> Result<Vec<Vec<PatchRange>>> {
+        Ok(Vec::new())
+    }
+
+    pub fn bolt_execute_buffered(_ctx: Context<BoltExecuteBuffered>, _args: Vec<u8>) -> Result<()> {
+        Ok(())
+    }
+
+    pub fn bolt_execute_with_commands(
+        _ctx: Context<BoltExecute>,
+        _args: Vec<u8>,
+    ) -> Result<SystemOutput> {
+        Ok(SystemOutput::default())
+    }
+
+    pub fn bolt_execute_batched(
+        _ctx: Context<BoltExecute>,
+        _args: Vec<u8>,
+        _pending: Vec<PendingComponent>,
+    ) -> Result<Vec<PendingComponent>> {
+        Ok(Vec::new())
+    }
 }
 
+/// The accounts of the system entrypoints. The remaining accounts are the components, followed by
+/// the extra accounts and by the world the system is applied to. The world is passed last, so that
+/// the systems built before it, which read the components and the extra accounts from the front of
+/// the remaining accounts, keep their account layout.
 #[derive(Accounts, Clone)]
 pub struct BoltExecute<'info> {
     /// CHECK: authority check
     #[account()]
     pub authority: AccountInfo<'info>,
 }
+
+#[derive(Accounts, Clone)]
+pub struct BoltExecuteBuffered<'info> {
+    /// CHECK: authority check
+    #[account()]
+    pub authority: AccountInfo<'info>,
+    /// CHECK: The output buffer, owned by the system so that it can write the outputs to it
+    #[account(mut)]
+    pub buffer: AccountInfo<'info>,
+}
+
+/// A range of bytes changed by a system, relative to the component data (after the discriminator).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PatchRange {
+    pub offset: u32,
+    pub data: Vec<u8>,
+}
+
+/// A component updated by a system of a batch, whose update is written back at the end of the
+/// batch. The data is the serialized component after the discriminator, and `index` the position
+/// of its account in the remaining accounts of the system. A batched system returns the components
+/// it writes back, and the following systems read the account at `index` with this data, in place
+/// of the data of the account.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PendingComponent {
+    pub index: u8,
+    pub data: Vec<u8>,
+}
+
+/// The output of a system: the serialized components, in the order of the writable components,
+/// and the structural commands. Only `bolt_execute_with_commands` returns commands, the other
+/// entrypoints fail if the system issues any.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct SystemOutput {
+    pub components: Vec<Vec<u8>>,
+    pub commands: Vec<SystemCommand>,
+}
+
+/// A structural command returned by a system along with its component updates, and executed by
+/// the world after the updates. The accounts of the commands are passed in the extra accounts of
+/// the system.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum SystemCommand {
+    /// Destroys the component of the entity, its rent goes to the vault of the world. Only the
+    /// components of the world, whose authority is the world program, are destroyed.
+    Destroy {
+        component_program: Pubkey,
+        entity: Pubkey,
+        component: Pubkey,
+        instance: Option<Vec<u8>>,
+    },
+    /// Initializes the component of the entity, paid by the vault of the world, and writes its
+    /// data. The data is the serialized component, its metadata is bound to the world program
+    /// and to the world. Empty data keeps the default component. The space is the size of the
+    /// component account, whose rent the vault transfers before the initialization.
+    Initialize {
+        component_program: Pubkey,
+        entity: Pubkey,
+        component: Pubkey,
+        instance: Option<Vec<u8>>,
+        data: Vec<u8>,
+        space: u64,
+    },
+    /// Creates the entity derived from the extra seed, paid by the vault of the world and owned
+    /// by the world program.
+    CreateEntity { extra_seed: Vec<u8> },
+}
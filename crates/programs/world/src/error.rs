@@ -14,4 +14,30 @@ pub enum WorldError {
     AuthorityNotFound,
     #[msg("The system is not approved in this world instance")]
     SystemNotApproved,
+    #[msg("The system patch is out of the component bounds")]
+    InvalidPatch,
+    #[msg("The output buffer must be owned by the system and not passed to it as a component or extra account")]
+    InvalidOutputBuffer,
+    #[msg("The authority doesn't have the role required by the instruction")]
+    MissingRole,
+    #[msg("The world must keep at least one admin")]
+    LastAdmin,
+    #[msg("The world is paused")]
+    WorldPaused,
+    #[msg("The world is frozen")]
+    WorldFrozen,
+    #[msg("The system is not allowed to write the component")]
+    ComponentNotAllowed,
+    #[msg("The component is not owned by the component program")]
+    ComponentOwnerMismatch,
+    #[msg("The component is passed more than once")]
+    DuplicateComponent,
+    #[msg("The entity belongs to another world")]
+    EntityWorldMismatch,
+    #[msg("The component is already initialized")]
+    ComponentAlreadyInitialized,
+    #[msg("The world already has an admin")]
+    WorldHasAdmin,
+    #[msg("The component is not derived from the entity")]
+    ComponentEntityMismatch,
 }
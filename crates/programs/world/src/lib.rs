@@ -1,8 +1,8 @@
 #![allow(clippy::manual_unwrap_or_default)]
 use anchor_lang::prelude::*;
-use bolt_component::CpiContextBuilder;
+pub use bolt_system::SystemCommand;
 use error::WorldError;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 #[cfg(not(feature = "no-entrypoint"))]
 use solana_security_txt::security_txt;
@@ -21,6 +21,74 @@ security_txt! {
 
 mod error;
 
+/// The roles of the world authorities, combined in a bitmask.
+pub mod roles {
+    /// Manages the world authorities and their roles.
+    pub const ADMIN: u8 = 1 << 0;
+    /// Approves and removes the systems of the world.
+    pub const OPERATOR: u8 = 1 << 1;
+    /// Despawns the entities of the world.
+    pub const MODERATOR: u8 = 1 << 2;
+    pub const ALL: u8 = ADMIN | OPERATOR | MODERATOR;
+}
+
+/// The PDAs of the world program which sign the CPIs into the component programs, so that the
+/// components verify the world as their caller wherever it runs in the transaction. Each component
+/// program has its own signer: a program the world invokes with the signer of its components can't
+/// act on the components of another program.
+pub mod cpi_auth {
+    use anchor_lang::prelude::*;
+
+    pub const SEED: &[u8] = b"cpi_auth";
+
+    pub fn pda(component_program: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[SEED, component_program.as_ref()], &crate::ID)
+    }
+
+    /// The seeds of the signer of the component program.
+    pub fn seeds<'a>(component_program: &'a Pubkey, bump: &'a [u8; 1]) -> [&'a [u8]; 3] {
+        [SEED, component_program.as_ref(), bump]
+    }
+}
+
+/// The authorities the world signs with for the components it acts on, derived from the world, so
+/// that the components check them against the world of their metadata. The moderator authority
+/// destroys any component of the world and hands over the components held by the world program,
+/// for the admins and moderators. The system authority only destroys the components held by the
+/// world program, for the commands of the systems.
+pub mod world_authority {
+    use anchor_lang::prelude::*;
+
+    pub const MODERATOR_SEED: &[u8] = b"moderator";
+    pub const SYSTEM_SEED: &[u8] = b"system";
+
+    pub fn moderator(world: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[MODERATOR_SEED, world.as_ref()], &crate::ID)
+    }
+
+    pub fn system(world: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[SYSTEM_SEED, world.as_ref()], &crate::ID)
+    }
+
+    /// The seeds of the authority of the world derived from `seed`.
+    pub fn seeds<'a>(seed: &'a [u8], world: &'a Pubkey, bump: &'a [u8; 1]) -> [&'a [u8]; 3] {
+        [seed, world.as_ref(), bump]
+    }
+}
+
+/// The vault of a world, a system account derived from the world. It pays the rent of the
+/// entities and components spawned by the systems, and receives the rent of the ones they destroy.
+/// The vault is funded with plain transfers, and the world admins withdraw from it with `withdraw_vault`.
+pub mod vault {
+    use anchor_lang::prelude::*;
+
+    pub const SEED: &[u8] = b"vault";
+
+    pub fn pda(world: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[SEED, world.as_ref()], &crate::ID)
+    }
+}
+
 #[program]
 pub mod world {
     use super::*;
@@ -33,33 +101,32 @@ pub mod world {
     pub fn initialize_new_world(ctx: Context<InitializeNewWorld>) -> Result<()> {
         ctx.accounts.world.set_inner(World::default());
         ctx.accounts.world.id = ctx.accounts.registry.worlds;
+        // The creator of the world is its first admin
+        ctx.accounts.world.authorities.push(*ctx.accounts.payer.key);
+        ctx.accounts.world.roles.0.push(roles::ALL);
         ctx.accounts.registry.worlds += 1;
         Ok(())
     }
 
     #[allow(unused_variables)]
-    pub fn add_authority(ctx: Context<AddAuthority>, world_id: u64) -> Result<()> {
-        if ctx.accounts.world.authorities.is_empty()
-            || (ctx
-                .accounts
-                .world
-                .authorities
-                .contains(ctx.accounts.authority.key)
-                && !ctx
-                    .accounts
-                    .world
-                    .authorities
-                    .contains(ctx.accounts.new_authority.key))
+    /// Adds an authority to the world with the given roles. An authority which is already part of
+    /// the world keeps its roles, which are changed with `set_authority_roles`.
+    pub fn add_authority(ctx: Context<AddAuthority>, world_id: u64, roles: u8) -> Result<()> {
+        check_role(&ctx.accounts.world, &ctx.accounts.authority, roles::ADMIN)?;
+        if !ctx
+            .accounts
+            .world
+            .authorities
+            .contains(ctx.accounts.new_authority.key)
         {
+            ctx.accounts.world.normalize_roles();
             ctx.accounts
                 .world
                 .authorities
                 .push(*ctx.accounts.new_authority.key);
+            ctx.accounts.world.roles.0.push(roles);
 
-            let new_space = World::space_for_authorities(
-                ctx.accounts.world.authorities.len(),
-                ctx.accounts.world.systems.len(),
-            );
+            let new_space = ctx.accounts.world.space();
 
             // Transfer to make it rent exempt
             let rent = Rent::get()?;
@@ -88,16 +155,40 @@ pub mod world {
         Ok(())
     }
 
+    /// Makes the upgrade authority of the world program the admin of a world without admin, such
+    /// as the worlds created before the roles with no authority.
     #[allow(unused_variables)]
-    pub fn remove_authority(ctx: Context<RemoveAuthority>, world_id: u64) -> Result<()> {
-        if !ctx
-            .accounts
-            .world
+    pub fn claim_world_admin(ctx: Context<ClaimWorldAdmin>, world_id: u64) -> Result<()> {
+        let world = &mut ctx.accounts.world;
+        if world
             .authorities
-            .contains(ctx.accounts.authority.key)
+            .iter()
+            .any(|authority| world.has_role(authority, roles::ADMIN))
         {
-            return Err(WorldError::InvalidAuthority.into());
+            return Err(WorldError::WorldHasAdmin.into());
+        }
+        world.normalize_roles();
+        match world
+            .authorities
+            .iter()
+            .position(|authority| authority == ctx.accounts.authority.key)
+        {
+            Some(index) => world.roles.0[index] = roles::ALL,
+            None => {
+                world.authorities.push(*ctx.accounts.authority.key);
+                world.roles.0.push(roles::ALL);
+            }
         }
+        realloc_world(
+            &ctx.accounts.world,
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+        )
+    }
+
+    #[allow(unused_variables)]
+    pub fn remove_authority(ctx: Context<RemoveAuthority>, world_id: u64) -> Result<()> {
+        check_role(&ctx.accounts.world, &ctx.accounts.authority, roles::ADMIN)?;
         if let Some(index) = ctx
             .accounts
             .world
@@ -105,12 +196,12 @@ pub mod world {
             .iter()
             .position(|&x| x == *ctx.accounts.authority_to_delete.key)
         {
+            check_admin_kept(&ctx.accounts.world, ctx.accounts.authority_to_delete.key, 0)?;
+            ctx.accounts.world.normalize_roles();
             ctx.accounts.world.authorities.remove(index);
+            ctx.accounts.world.roles.0.remove(index);
 
-            let new_space = World::space_for_authorities(
-                ctx.accounts.world.authorities.len(),
-                ctx.accounts.world.systems.len(),
-            );
+            let new_space = ctx.accounts.world.space();
 
             // Remove the extra rent
             let rent = Rent::get()?;
@@ -137,87 +228,108 @@ pub mod world {
         }
     }
 
-    pub fn approve_system(ctx: Context<ApproveSystem>) -> Result<()> {
-        if !ctx.accounts.authority.is_signer {
-            return Err(WorldError::InvalidAuthority.into());
-        }
-        if !ctx
+    #[allow(unused_variables)]
+    pub fn set_authority_roles(
+        ctx: Context<SetAuthorityRoles>,
+        world_id: u64,
+        roles: u8,
+    ) -> Result<()> {
+        check_role(&ctx.accounts.world, &ctx.accounts.authority, roles::ADMIN)?;
+        let index = ctx
             .accounts
             .world
             .authorities
-            .contains(ctx.accounts.authority.key)
-        {
-            return Err(WorldError::InvalidAuthority.into());
-        }
-        if ctx.accounts.world.permissionless {
-            ctx.accounts.world.permissionless = false;
-        }
+            .iter()
+            .position(|&x| x == *ctx.accounts.authority_to_update.key)
+            .ok_or(WorldError::AuthorityNotFound)?;
+        check_admin_kept(
+            &ctx.accounts.world,
+            ctx.accounts.authority_to_update.key,
+            roles,
+        )?;
+        ctx.accounts.world.normalize_roles();
+        ctx.accounts.world.roles.0[index] = roles;
+        realloc_world(
+            &ctx.accounts.world,
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+        )
+    }
 
-        let mut world_systems = ctx.accounts.world.systems();
-        world_systems
-            .approved_systems
-            .insert(ctx.accounts.system.key());
+    #[allow(unused_variables)]
+    pub fn set_world_state(
+        ctx: Context<ConfigureWorld>,
+        world_id: u64,
+        state: WorldState,
+    ) -> Result<()> {
+        check_role(
+            &ctx.accounts.world,
+            &ctx.accounts.authority,
+            roles::OPERATOR,
+        )?;
+        ctx.accounts.world.config.state = state;
+        realloc_world(
+            &ctx.accounts.world,
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+        )
+    }
 
-        let encoded_world_systems = world_systems.try_to_vec()?;
-        ctx.accounts.world.systems = encoded_world_systems.clone();
+    /// Sets the systems which keep running while the world is paused.
+    #[allow(unused_variables)]
+    pub fn set_paused_systems(
+        ctx: Context<ConfigureWorld>,
+        world_id: u64,
+        systems: Vec<Pubkey>,
+    ) -> Result<()> {
+        check_role(
+            &ctx.accounts.world,
+            &ctx.accounts.authority,
+            roles::OPERATOR,
+        )?;
+        ctx.accounts.world.config.paused_systems = systems;
+        realloc_world(
+            &ctx.accounts.world,
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+        )
+    }
 
-        let new_space = World::space_for_authorities(
-            ctx.accounts.world.authorities.len(),
-            encoded_world_systems.len(),
-        );
+    pub fn approve_system(ctx: Context<ApproveSystem>) -> Result<()> {
+        approve_system_impl(ctx, None)
+    }
 
-        // Transfer to make it rent exempt
-        let rent = Rent::get()?;
-        let new_minimum_balance = rent.minimum_balance(new_space);
-        let lamports_diff =
-            new_minimum_balance.saturating_sub(ctx.accounts.world.to_account_info().lamports());
-        if lamports_diff > 0 {
-            anchor_lang::solana_program::program::invoke(
-                &anchor_lang::solana_program::system_instruction::transfer(
-                    ctx.accounts.authority.key,
-                    ctx.accounts.world.to_account_info().key,
-                    lamports_diff,
-                ),
-                &[
-                    ctx.accounts.authority.to_account_info(),
-                    ctx.accounts.world.to_account_info(),
-                    ctx.accounts.system_program.to_account_info(),
-                ],
-            )?;
-        }
-        ctx.accounts
-            .world
-            .to_account_info()
-            .realloc(new_space, false)?;
-        msg!("Approved system: {:?}", world_systems);
-        Ok(())
+    /// Approves the system, allowing it to write only the components of the given programs.
+    pub fn approve_system_with_components(
+        ctx: Context<ApproveSystem>,
+        component_programs: Vec<Pubkey>,
+    ) -> Result<()> {
+        approve_system_impl(ctx, Some(component_programs.into_iter().collect()))
     }
 
     pub fn remove_system(ctx: Context<RemoveSystem>) -> Result<()> {
         if !ctx.accounts.authority.is_signer {
             return Err(WorldError::InvalidAuthority.into());
         }
-        if !ctx
-            .accounts
-            .world
-            .authorities
-            .contains(ctx.accounts.authority.key)
-        {
-            return Err(WorldError::InvalidAuthority.into());
-        }
+        check_role(
+            &ctx.accounts.world,
+            &ctx.accounts.authority,
+            roles::OPERATOR,
+        )?;
+        ctx.accounts.world.normalize_roles();
 
         let mut world_systems = ctx.accounts.world.systems();
         world_systems
             .approved_systems
             .remove(&ctx.accounts.system.key());
+        world_systems
+            .component_permissions
+            .remove(&ctx.accounts.system.key());
 
         let encoded_world_systems = world_systems.try_to_vec()?;
         ctx.accounts.world.systems = encoded_world_systems.clone();
 
-        let new_space = World::space_for_authorities(
-            ctx.accounts.world.authorities.len(),
-            encoded_world_systems.len(),
-        );
+        let new_space = ctx.accounts.world.space();
 
         if world_systems.approved_systems.is_empty() {
             ctx.accounts.world.permissionless = true;
@@ -242,7 +354,7 @@ pub mod world {
             .world
             .to_account_info()
             .realloc(new_space, false)?;
-        msg!("Approved system: {:?}", world_systems);
+        msg!("Removed system: {:?}", world_systems);
         Ok(())
     }
 
@@ -252,21 +364,234 @@ pub mod world {
             ctx.accounts.world.key() == ctx.accounts.world.pda().0,
             WorldError::WorldAccountMismatch
         );
+        check_world_state(&ctx.accounts.world, None)?;
         ctx.accounts.entity.id = ctx.accounts.world.entities;
+        ctx.accounts.entity.owner = Some(*ctx.accounts.payer.key);
+        ctx.accounts.entity.world = ctx.accounts.world.key();
         ctx.accounts.world.entities += 1;
+        // The worlds created before the roles and the world state grow when they are written
+        realloc_world(
+            &ctx.accounts.world,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+        )
+    }
+
+    /// Binds an entity created before the entities recorded their world to the world it is
+    /// derived from, growing it to the current entity layout. The world checks reject the entity
+    /// until then.
+    #[allow(unused_variables)]
+    pub fn migrate_entity(ctx: Context<MigrateEntity>, extra_seed: Option<Vec<u8>>) -> Result<()> {
+        if ctx.accounts.entity.world != Pubkey::default() {
+            return Ok(());
+        }
+        ctx.accounts.entity.world = ctx.accounts.world.key();
+        realloc_account(
+            &ctx.accounts.entity.to_account_info(),
+            Entity::size(),
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+        )
+    }
+
+    /// Initializes the component of the entity. The instance seed tells apart several components
+    /// of the same type on the entity, `None` is the single instance derived from the entity alone.
+    pub fn initialize_component(
+        ctx: Context<InitializeComponent>,
+        instance: Option<Vec<u8>>,
+    ) -> Result<()> {
+        if !ctx.accounts.authority.is_signer && ctx.accounts.authority.key != &ID {
+            return Err(WorldError::InvalidAuthority.into());
+        }
+        check_world_state(&ctx.accounts.world, None)?;
+        // The owner of the entity signs for its components, whichever authority they are given
+        check_entity_owner(&ctx.accounts.entity, &ctx.accounts.owner)?;
+        if !ctx.accounts.data.data_is_empty() {
+            return Err(WorldError::ComponentAlreadyInitialized.into());
+        }
+        let bump = [ctx.bumps.cpi_auth];
+        let seeds = cpi_auth::seeds(ctx.accounts.component_program.key, &bump);
+        bolt_component::cpi::initialize(ctx.accounts.build(&[&seeds]), instance)?;
         Ok(())
     }
 
-    pub fn initialize_component(ctx: Context<InitializeComponent>) -> Result<()> {
+    /// Initializes the resource of the world: the single component of its type in the world,
+    /// derived from the world rather than from an entity. Resources are created by the operators.
+    pub fn initialize_resource(ctx: Context<InitializeResource>) -> Result<()> {
         if !ctx.accounts.authority.is_signer && ctx.accounts.authority.key != &ID {
             return Err(WorldError::InvalidAuthority.into());
         }
-        bolt_component::cpi::initialize(ctx.accounts.build())?;
+        check_role(&ctx.accounts.world, &ctx.accounts.payer, roles::OPERATOR)?;
+        check_world_state(&ctx.accounts.world, None)?;
+        if !ctx.accounts.data.data_is_empty() {
+            return Err(WorldError::ComponentAlreadyInitialized.into());
+        }
+        let bump = [ctx.bumps.cpi_auth];
+        let seeds = cpi_auth::seeds(ctx.accounts.component_program.key, &bump);
+        bolt_component::cpi::initialize_resource(ctx.accounts.build(&[&seeds]))?;
+        Ok(())
+    }
+
+    pub fn destroy_component(
+        ctx: Context<DestroyComponent>,
+        instance: Option<Vec<u8>>,
+    ) -> Result<()> {
+        check_world_state(&ctx.accounts.world, None)?;
+        // Components are destroyed by the owner of the entity or by the world moderators, for
+        // whom the world signs with its moderator authority
+        let moderator = ctx
+            .accounts
+            .world
+            .has_role(ctx.accounts.authority.key, roles::MODERATOR);
+        if !moderator {
+            check_entity_owner(&ctx.accounts.entity, &ctx.accounts.authority)?;
+        }
+        let world = ctx.accounts.world.key();
+        let bump = [ctx.bumps.cpi_auth];
+        let seeds = cpi_auth::seeds(ctx.accounts.component_program.key, &bump);
+        let moderator_bump = [ctx.bumps.moderator_authority];
+        let moderator_seeds =
+            world_authority::seeds(world_authority::MODERATOR_SEED, &world, &moderator_bump);
+        bolt_component::cpi::destroy(
+            ctx.accounts.build(moderator, &[&seeds, &moderator_seeds]),
+            instance,
+        )?;
+        Ok(())
+    }
+
+    /// Closes the entity, after destroying the components passed as (component program, component
+    /// program data, component, signer of the component program) quadruples in the remaining
+    /// accounts. The components follow the authority rules of `destroy_component`. The instance
+    /// seeds are given in the order of the quadruples, the components without an entry are single
+    /// instances.
+    #[allow(unused_variables)]
+    pub fn destroy_entity<'info>(
+        ctx: Context<'_, '_, '_, 'info, DestroyEntity<'info>>,
+        extra_seed: Option<Vec<u8>>,
+        instances: Vec<Option<Vec<u8>>>,
+    ) -> Result<()> {
+        check_world_state(&ctx.accounts.world, None)?;
+        // Entities are despawned by their owner or by the world moderators
+        let moderator = ctx
+            .accounts
+            .world
+            .has_role(ctx.accounts.authority.key, roles::MODERATOR);
+        if !moderator {
+            match ctx.accounts.entity.owner {
+                Some(owner) if owner == *ctx.accounts.authority.key => {}
+                _ => return Err(WorldError::InvalidAuthority.into()),
+            }
+        }
+        if ctx.remaining_accounts.len() % 4 != 0 {
+            return Err(ErrorCode::AccountNotEnoughKeys.into());
+        }
+        let world = ctx.accounts.world.key();
+        let moderator_bump = [ctx.bumps.moderator_authority];
+        let moderator_seeds =
+            world_authority::seeds(world_authority::MODERATOR_SEED, &world, &moderator_bump);
+        let mut instances = instances.into_iter();
+        for accounts in ctx.remaining_accounts.chunks(4) {
+            let [component_program, component_program_data, component, cpi_auth] = accounts else {
+                unreachable!();
+            };
+            if component.owner != component_program.key {
+                return Err(WorldError::ComponentOwnerMismatch.into());
+            }
+            let cpi_auth = CpiAuth::new(cpi_auth.clone(), component_program.key)?;
+            bolt_component::cpi::destroy(
+                ctx.accounts.build(
+                    component_program.clone(),
+                    component_program_data.clone(),
+                    component.clone(),
+                    cpi_auth.account.clone(),
+                    moderator,
+                    &[&cpi_auth.seeds(), &moderator_seeds],
+                ),
+                instances.next().flatten(),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Transfers the entity to a new owner. The components passed as (component program,
+    /// component program data, component, signer of the component program) quadruples in the
+    /// remaining accounts are handed over to the new owner as well, each derived from the entity
+    /// with the seeds of `components`. The components must be held by the owner, or by the world
+    /// program when the owner is a world admin or moderator, for whom the world signs with its
+    /// moderator authority. An entity without owner is handed out by the world admins and
+    /// moderators.
+    pub fn transfer_entity<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferEntity<'info>>,
+        components: Vec<TransferredComponent>,
+    ) -> Result<()> {
+        let world = &ctx.accounts.world;
+        let privileged = world.has_role(ctx.accounts.owner.key, roles::ADMIN)
+            || world.has_role(ctx.accounts.owner.key, roles::MODERATOR);
+        match ctx.accounts.entity.owner {
+            Some(_) => check_entity_owner(&ctx.accounts.entity, &ctx.accounts.owner)?,
+            None if !privileged => return Err(WorldError::MissingRole.into()),
+            None => {}
+        }
+        if ctx.remaining_accounts.len() != components.len() * 4 {
+            return Err(ErrorCode::AccountNotEnoughKeys.into());
+        }
+        let entity = ctx.accounts.entity.key();
+        let world = ctx.accounts.world.key();
+        let moderator_bump = [ctx.bumps.moderator_authority];
+        let moderator_seeds =
+            world_authority::seeds(world_authority::MODERATOR_SEED, &world, &moderator_bump);
+        for (accounts, seeds) in ctx.remaining_accounts.chunks(4).zip(components) {
+            let [component_program, component_program_data, component, cpi_auth] = accounts else {
+                unreachable!();
+            };
+            let (pda, _) = Pubkey::find_program_address(
+                &[
+                    &seeds.seed,
+                    entity.as_ref(),
+                    seeds.instance.as_deref().unwrap_or_default(),
+                ],
+                component_program.key,
+            );
+            if pda != *component.key {
+                return Err(WorldError::ComponentEntityMismatch.into());
+            }
+            if component.owner != component_program.key {
+                return Err(WorldError::ComponentOwnerMismatch.into());
+            }
+            let cpi_auth = CpiAuth::new(cpi_auth.clone(), component_program.key)?;
+            bolt_component::cpi::set_authority(
+                ctx.accounts.build(
+                    component_program.clone(),
+                    component_program_data.clone(),
+                    component.clone(),
+                    cpi_auth.account.clone(),
+                    privileged,
+                    &[&cpi_auth.seeds(), &moderator_seeds],
+                ),
+                *ctx.accounts.new_owner.key,
+            )?;
+        }
+        ctx.accounts.entity.owner = Some(*ctx.accounts.new_owner.key);
+        Ok(())
+    }
+
+    pub fn set_component_authority(
+        ctx: Context<SetComponentAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        let bump = [ctx.bumps.cpi_auth];
+        let seeds = cpi_auth::seeds(ctx.accounts.component_program.key, &bump);
+        bolt_component::cpi::set_authority(ctx.accounts.build(&[&seeds]), new_authority)?;
         Ok(())
     }
 
-    pub fn destroy_component(ctx: Context<DestroyComponent>) -> Result<()> {
-        bolt_component::cpi::destroy(ctx.accounts.build())?;
+    pub fn migrate_component(
+        ctx: Context<MigrateComponent>,
+        instance: Option<Vec<u8>>,
+    ) -> Result<()> {
+        let bump = [ctx.bumps.cpi_auth];
+        let seeds = cpi_auth::seeds(ctx.accounts.component_program.key, &bump);
+        bolt_component::cpi::migrate(ctx.accounts.build(&[&seeds]), instance)?;
         Ok(())
     }
 
@@ -274,7 +599,7 @@ pub mod world {
         ctx: Context<'_, '_, '_, 'info, Apply<'info>>,
         args: Vec<u8>,
     ) -> Result<()> {
-        let (pairs, results) = apply_impl(
+        let (pairs, results, cpi_auths) = apply_impl(
             &ctx.accounts.authority,
             &ctx.accounts.world,
             &ctx.accounts.bolt_system,
@@ -283,12 +608,16 @@ pub mod world {
             ctx.remaining_accounts.to_vec(),
         )?;
         for ((program, component), result) in pairs.into_iter().zip(results.into_iter()) {
+            let cpi_auth = CpiAuth::of(&cpi_auths, program.key)?;
             bolt_component::cpi::update(
                 build_update_context(
                     program,
                     component,
                     ctx.accounts.authority.clone(),
-                    ctx.accounts.instruction_sysvar_account.clone(),
+                    cpi_auth.account.clone(),
+                    ctx.accounts.world.to_account_info(),
+                    ctx.accounts.system_program.clone(),
+                    &[&cpi_auth.seeds()],
                 ),
                 result,
             )?;
@@ -302,13 +631,11 @@ pub mod world {
         #[account()]
         pub bolt_system: UncheckedAccount<'info>,
         /// CHECK: authority check
-        #[account()]
+        #[account(mut)]
         pub authority: Signer<'info>,
-        /// CHECK: instruction sysvar check
-        #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
-        pub instruction_sysvar_account: UncheckedAccount<'info>,
         #[account()]
         pub world: Account<'info, World>,
+        pub system_program: Program<'info, System>,
     }
 
     impl<'info> Apply<'info> {
@@ -327,7 +654,7 @@ pub mod world {
         ctx: Context<'_, '_, '_, 'info, ApplyWithSession<'info>>,
         args: Vec<u8>,
     ) -> Result<()> {
-        let (pairs, results) = apply_impl(
+        let (pairs, results, cpi_auths) = apply_impl(
             &ctx.accounts.authority,
             &ctx.accounts.world,
             &ctx.accounts.bolt_system,
@@ -336,13 +663,17 @@ pub mod world {
             ctx.remaining_accounts.to_vec(),
         )?;
         for ((program, component), result) in pairs.into_iter().zip(results.into_iter()) {
+            let cpi_auth = CpiAuth::of(&cpi_auths, program.key)?;
             bolt_component::cpi::update_with_session(
                 build_update_context_with_session(
                     program,
                     component,
                     ctx.accounts.authority.clone(),
-                    ctx.accounts.instruction_sysvar_account.clone(),
+                    cpi_auth.account.clone(),
+                    ctx.accounts.world.to_account_info(),
                     ctx.accounts.session_token.clone(),
+                    ctx.accounts.system_program.clone(),
+                    &[&cpi_auth.seeds()],
                 ),
                 result,
             )?;
@@ -356,13 +687,11 @@ pub mod world {
         #[account()]
         pub bolt_system: UncheckedAccount<'info>,
         /// CHECK: authority check
-        #[account()]
+        #[account(mut)]
         pub authority: Signer<'info>,
-        /// CHECK: instruction sysvar check
-        #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
-        pub instruction_sysvar_account: UncheckedAccount<'info>,
         #[account()]
         pub world: Account<'info, World>,
+        pub system_program: Program<'info, System>,
         #[account()]
         /// CHECK: The session token
         pub session_token: UncheckedAccount<'info>,
@@ -379,73 +708,1372 @@ pub mod world {
             CpiContext::new(cpi_program, cpi_accounts)
         }
     }
-}
 
-#[allow(clippy::type_complexity)]
-fn apply_impl<'info>(
-    authority: &Signer<'info>,
-    world: &Account<'info, World>,
-    bolt_system: &UncheckedAccount<'info>,
-    cpi_context: CpiContext<'_, '_, '_, 'info, bolt_system::cpi::accounts::BoltExecute<'info>>,
-    args: Vec<u8>,
-    mut remaining_accounts: Vec<AccountInfo<'info>>,
-) -> Result<(Vec<(AccountInfo<'info>, AccountInfo<'info>)>, Vec<Vec<u8>>)> {
-    if !authority.is_signer && authority.key != &ID {
-        return Err(WorldError::InvalidAuthority.into());
-    }
-    if !world.permissionless
-        && !world
-            .systems()
-            .approved_systems
-            .contains(&bolt_system.key())
-    {
-        return Err(WorldError::SystemNotApproved.into());
+    /// Withdraws lamports from the vault of the world to the receiver.
+    pub fn withdraw_vault(ctx: Context<WithdrawVault>, amount: u64) -> Result<()> {
+        check_role(&ctx.accounts.world, &ctx.accounts.authority, roles::ADMIN)?;
+        let world_key = ctx.accounts.world.key();
+        let vault_seeds: &[&[u8]] = &[vault::SEED, world_key.as_ref(), &[ctx.bumps.vault]];
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                ctx.accounts.vault.key,
+                ctx.accounts.receiver.key,
+                amount,
+            ),
+            &[
+                ctx.accounts.vault.to_account_info(),
+                ctx.accounts.receiver.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[vault_seeds],
+        )?;
+        Ok(())
     }
 
-    let mut pairs = Vec::new();
-    while remaining_accounts.len() >= 2 {
-        let program = remaining_accounts.remove(0);
-        if program.key() == ID {
-            break;
+    /// Applies a system which issues commands along with its component updates. The commands run
+    /// after the updates, their accounts and the signers of their component programs are passed in
+    /// the extra accounts of the system. Their component programs must be listed for the system.
+    pub fn apply_commands<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApplyCommands<'info>>,
+        args: Vec<u8>,
+    ) -> Result<()> {
+        let (pairs, results, commands, cpi_auths) = apply_commands_impl(
+            &ctx.accounts.authority,
+            &ctx.accounts.world,
+            &ctx.accounts.bolt_system,
+            ctx.accounts.build(),
+            args,
+            ctx.remaining_accounts.to_vec(),
+        )?;
+        for ((program, component), result) in pairs.into_iter().zip(results.into_iter()) {
+            let cpi_auth = CpiAuth::of(&cpi_auths, program.key)?;
+            bolt_component::cpi::update(
+                build_update_context(
+                    program,
+                    component,
+                    ctx.accounts.authority.clone(),
+                    cpi_auth.account.clone(),
+                    ctx.accounts.world.to_account_info(),
+                    ctx.accounts.system_program.clone(),
+                    &[&cpi_auth.seeds()],
+                ),
+                result,
+            )?;
         }
-        let component = remaining_accounts.remove(0);
-        pairs.push((program, component));
+        execute_commands(
+            CommandAccounts {
+                bolt_system: ctx.accounts.bolt_system.key,
+                authority: &ctx.accounts.authority,
+                world: &mut ctx.accounts.world,
+                vault: &ctx.accounts.vault,
+                vault_bump: ctx.bumps.vault,
+                system_authority: &ctx.accounts.system_authority,
+                system_authority_bump: ctx.bumps.system_authority,
+                world_program: &ctx.accounts.world_program,
+                system_program: &ctx.accounts.system_program,
+            },
+            commands,
+            ctx.remaining_accounts,
+        )
     }
 
-    let mut components_accounts = pairs
-        .iter()
-        .map(|(_, component)| component)
-        .cloned()
-        .collect::<Vec<_>>();
-    components_accounts.append(&mut remaining_accounts);
-    let remaining_accounts = components_accounts;
-
-    let results = bolt_system::cpi::bolt_execute(
-        cpi_context.with_remaining_accounts(remaining_accounts),
-        args,
-    )?
-    .get();
+    #[derive(Accounts)]
+    pub struct ApplyCommands<'info> {
+        /// CHECK: bolt system program check
+        #[account()]
+        pub bolt_system: UncheckedAccount<'info>,
+        /// CHECK: authority check
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account(mut)]
+        pub world: Account<'info, World>,
+        #[account(mut, seeds = [vault::SEED, world.key().as_ref()], bump)]
+        pub vault: SystemAccount<'info>,
+        #[account(seeds = [world_authority::SYSTEM_SEED, world.key().as_ref()], bump)]
+        /// CHECK: The authority the world signs with for the destroys of the commands
+        pub system_authority: UncheckedAccount<'info>,
+        /// The authority of the components spawned by the commands
+        pub world_program: Program<'info, crate::program::World>,
+        pub system_program: Program<'info, System>,
+    }
 
-    if results.len() != pairs.len() {
-        return Err(WorldError::InvalidSystemOutput.into());
+    impl<'info> ApplyCommands<'info> {
+        pub fn build(
+            &self,
+        ) -> CpiContext<'_, '_, '_, 'info, bolt_system::cpi::accounts::BoltExecute<'info>> {
+            let cpi_program = self.bolt_system.to_account_info();
+            let cpi_accounts = bolt_system::cpi::accounts::BoltExecute {
+                authority: self.authority.to_account_info(),
+            };
+            CpiContext::new(cpi_program, cpi_accounts)
+        }
     }
-    Ok((pairs, results))
-}
 
-#[derive(Accounts)]
-pub struct InitializeRegistry<'info> {
-    #[account(init, payer = payer, space = Registry::size(), seeds = [Registry::seed()], bump)]
-    pub registry: Account<'info, Registry>,
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
+    pub fn apply_commands_with_session<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApplyCommandsWithSession<'info>>,
+        args: Vec<u8>,
+    ) -> Result<()> {
+        let (pairs, results, commands, cpi_auths) = apply_commands_impl(
+            &ctx.accounts.authority,
+            &ctx.accounts.world,
+            &ctx.accounts.bolt_system,
+            ctx.accounts.build(),
+            args,
+            ctx.remaining_accounts.to_vec(),
+        )?;
+        for ((program, component), result) in pairs.into_iter().zip(results.into_iter()) {
+            let cpi_auth = CpiAuth::of(&cpi_auths, program.key)?;
+            bolt_component::cpi::update_with_session(
+                build_update_context_with_session(
+                    program,
+                    component,
+                    ctx.accounts.authority.clone(),
+                    cpi_auth.account.clone(),
+                    ctx.accounts.world.to_account_info(),
+                    ctx.accounts.session_token.clone(),
+                    ctx.accounts.system_program.clone(),
+                    &[&cpi_auth.seeds()],
+                ),
+                result,
+            )?;
+        }
+        execute_commands(
+            CommandAccounts {
+                bolt_system: ctx.accounts.bolt_system.key,
+                authority: &ctx.accounts.authority,
+                world: &mut ctx.accounts.world,
+                vault: &ctx.accounts.vault,
+                vault_bump: ctx.bumps.vault,
+                system_authority: &ctx.accounts.system_authority,
+                system_authority_bump: ctx.bumps.system_authority,
+                world_program: &ctx.accounts.world_program,
+                system_program: &ctx.accounts.system_program,
+            },
+            commands,
+            ctx.remaining_accounts,
+        )
+    }
+
+    #[derive(Accounts)]
+    pub struct ApplyCommandsWithSession<'info> {
+        /// CHECK: bolt system program check
+        #[account()]
+        pub bolt_system: UncheckedAccount<'info>,
+        /// CHECK: authority check
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account(mut)]
+        pub world: Account<'info, World>,
+        #[account(mut, seeds = [vault::SEED, world.key().as_ref()], bump)]
+        pub vault: SystemAccount<'info>,
+        #[account(seeds = [world_authority::SYSTEM_SEED, world.key().as_ref()], bump)]
+        /// CHECK: The authority the world signs with for the destroys of the commands
+        pub system_authority: UncheckedAccount<'info>,
+        /// The authority of the components spawned by the commands
+        pub world_program: Program<'info, crate::program::World>,
+        pub system_program: Program<'info, System>,
+        #[account()]
+        /// CHECK: The session token
+        pub session_token: UncheckedAccount<'info>,
+    }
+
+    impl<'info> ApplyCommandsWithSession<'info> {
+        pub fn build(
+            &self,
+        ) -> CpiContext<'_, '_, '_, 'info, bolt_system::cpi::accounts::BoltExecute<'info>> {
+            let cpi_program = self.bolt_system.to_account_info();
+            let cpi_accounts = bolt_system::cpi::accounts::BoltExecute {
+                authority: self.authority.to_account_info(),
+            };
+            CpiContext::new(cpi_program, cpi_accounts)
+        }
+    }
+
+    pub fn apply_patch<'info>(
+        ctx: Context<'_, '_, '_, 'info, Apply<'info>>,
+        args: Vec<u8>,
+    ) -> Result<()> {
+        let (pairs, patches, cpi_auths) = apply_patch_impl(
+            &ctx.accounts.authority,
+            &ctx.accounts.world,
+            &ctx.accounts.bolt_system,
+            ctx.accounts.build(),
+            args,
+            ctx.remaining_accounts.to_vec(),
+        )?;
+        for ((program, component), ranges) in pairs.into_iter().zip(patches.into_iter()) {
+            if ranges.is_empty() {
+                continue;
+            }
+            let cpi_auth = CpiAuth::of(&cpi_auths, program.key)?;
+            bolt_component::cpi::patch(
+                build_update_context(
+                    program,
+                    component,
+                    ctx.accounts.authority.clone(),
+                    cpi_auth.account.clone(),
+                    ctx.accounts.world.to_account_info(),
+                    ctx.accounts.system_program.clone(),
+                    &[&cpi_auth.seeds()],
+                ),
+                ranges,
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn apply_patch_with_session<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApplyWithSession<'info>>,
+        args: Vec<u8>,
+    ) -> Result<()> {
+        let (pairs, patches, cpi_auths) = apply_patch_impl(
+            &ctx.accounts.authority,
+            &ctx.accounts.world,
+            &ctx.accounts.bolt_system,
+            ctx.accounts.build(),
+            args,
+            ctx.remaining_accounts.to_vec(),
+        )?;
+        for ((program, component), ranges) in pairs.into_iter().zip(patches.into_iter()) {
+            if ranges.is_empty() {
+                continue;
+            }
+            let cpi_auth = CpiAuth::of(&cpi_auths, program.key)?;
+            bolt_component::cpi::patch_with_session(
+                build_update_context_with_session(
+                    program,
+                    component,
+                    ctx.accounts.authority.clone(),
+                    cpi_auth.account.clone(),
+                    ctx.accounts.world.to_account_info(),
+                    ctx.accounts.session_token.clone(),
+                    ctx.accounts.system_program.clone(),
+                    &[&cpi_auth.seeds()],
+                ),
+                ranges,
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn apply_buffered<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApplyBuffered<'info>>,
+        args: Vec<u8>,
+    ) -> Result<()> {
+        let (pairs, results, cpi_auths) = apply_buffered_impl(
+            &ctx.accounts.authority,
+            &ctx.accounts.world,
+            &ctx.accounts.bolt_system,
+            ctx.accounts.build(),
+            args,
+            ctx.remaining_accounts.to_vec(),
+        )?;
+        for ((program, component), result) in pairs.into_iter().zip(results.into_iter()) {
+            let cpi_auth = CpiAuth::of(&cpi_auths, program.key)?;
+            bolt_component::cpi::update(
+                build_update_context(
+                    program,
+                    component,
+                    ctx.accounts.authority.clone(),
+                    cpi_auth.account.clone(),
+                    ctx.accounts.world.to_account_info(),
+                    ctx.accounts.system_program.clone(),
+                    &[&cpi_auth.seeds()],
+                ),
+                result,
+            )?;
+        }
+        Ok(())
+    }
+
+    #[derive(Accounts)]
+    pub struct ApplyBuffered<'info> {
+        /// CHECK: bolt system program check
+        #[account()]
+        pub bolt_system: UncheckedAccount<'info>,
+        /// CHECK: authority check
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account()]
+        pub world: Account<'info, World>,
+        pub system_program: Program<'info, System>,
+        /// CHECK: The output buffer, checked to be owned by the system and to be none of its accounts
+        #[account(mut)]
+        pub buffer: UncheckedAccount<'info>,
+    }
+
+    impl<'info> ApplyBuffered<'info> {
+        pub fn build(
+            &self,
+        ) -> CpiContext<'_, '_, '_, 'info, bolt_system::cpi::accounts::BoltExecuteBuffered<'info>>
+        {
+            let cpi_program = self.bolt_system.to_account_info();
+            let cpi_accounts = bolt_system::cpi::accounts::BoltExecuteBuffered {
+                authority: self.authority.to_account_info(),
+                buffer: self.buffer.to_account_info(),
+            };
+            CpiContext::new(cpi_program, cpi_accounts)
+        }
+    }
+
+    pub fn apply_buffered_with_session<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApplyBufferedWithSession<'info>>,
+        args: Vec<u8>,
+    ) -> Result<()> {
+        let (pairs, results, cpi_auths) = apply_buffered_impl(
+            &ctx.accounts.authority,
+            &ctx.accounts.world,
+            &ctx.accounts.bolt_system,
+            ctx.accounts.build(),
+            args,
+            ctx.remaining_accounts.to_vec(),
+        )?;
+        for ((program, component), result) in pairs.into_iter().zip(results.into_iter()) {
+            let cpi_auth = CpiAuth::of(&cpi_auths, program.key)?;
+            bolt_component::cpi::update_with_session(
+                build_update_context_with_session(
+                    program,
+                    component,
+                    ctx.accounts.authority.clone(),
+                    cpi_auth.account.clone(),
+                    ctx.accounts.world.to_account_info(),
+                    ctx.accounts.session_token.clone(),
+                    ctx.accounts.system_program.clone(),
+                    &[&cpi_auth.seeds()],
+                ),
+                result,
+            )?;
+        }
+        Ok(())
+    }
+
+    #[derive(Accounts)]
+    pub struct ApplyBufferedWithSession<'info> {
+        /// CHECK: bolt system program check
+        #[account()]
+        pub bolt_system: UncheckedAccount<'info>,
+        /// CHECK: authority check
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account()]
+        pub world: Account<'info, World>,
+        pub system_program: Program<'info, System>,
+        /// CHECK: The output buffer, checked to be owned by the system and to be none of its accounts
+        #[account(mut)]
+        pub buffer: UncheckedAccount<'info>,
+        #[account()]
+        /// CHECK: The session token
+        pub session_token: UncheckedAccount<'info>,
+    }
+
+    impl<'info> ApplyBufferedWithSession<'info> {
+        pub fn build(
+            &self,
+        ) -> CpiContext<'_, '_, '_, 'info, bolt_system::cpi::accounts::BoltExecuteBuffered<'info>>
+        {
+            let cpi_program = self.bolt_system.to_account_info();
+            let cpi_accounts = bolt_system::cpi::accounts::BoltExecuteBuffered {
+                authority: self.authority.to_account_info(),
+                buffer: self.buffer.to_account_info(),
+            };
+            CpiContext::new(cpi_program, cpi_accounts)
+        }
+    }
+
+    pub fn apply_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApplyBatch<'info>>,
+        systems: Vec<BatchedSystem>,
+    ) -> Result<()> {
+        let authority = ctx.accounts.authority.clone();
+        let world = ctx.accounts.world.to_account_info();
+        let system_program = ctx.accounts.system_program.clone();
+        apply_batch_impl(
+            &ctx.accounts.authority,
+            &ctx.accounts.world,
+            systems,
+            ctx.remaining_accounts,
+            |program, component, result, cpi_auth| {
+                bolt_component::cpi::update(
+                    build_update_context(
+                        program,
+                        component,
+                        authority.clone(),
+                        cpi_auth.account.clone(),
+                        world.clone(),
+                        system_program.clone(),
+                        &[&cpi_auth.seeds()],
+                    ),
+                    result,
+                )
+            },
+        )
+    }
+
+    #[derive(Accounts)]
+    pub struct ApplyBatch<'info> {
+        /// CHECK: authority check
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account()]
+        pub world: Account<'info, World>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn apply_batch_with_session<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApplyBatchWithSession<'info>>,
+        systems: Vec<BatchedSystem>,
+    ) -> Result<()> {
+        let authority = ctx.accounts.authority.clone();
+        let world = ctx.accounts.world.to_account_info();
+        let system_program = ctx.accounts.system_program.clone();
+        let session_token = ctx.accounts.session_token.clone();
+        apply_batch_impl(
+            &ctx.accounts.authority,
+            &ctx.accounts.world,
+            systems,
+            ctx.remaining_accounts,
+            |program, component, result, cpi_auth| {
+                bolt_component::cpi::update_with_session(
+                    build_update_context_with_session(
+                        program,
+                        component,
+                        authority.clone(),
+                        cpi_auth.account.clone(),
+                        world.clone(),
+                        session_token.clone(),
+                        system_program.clone(),
+                        &[&cpi_auth.seeds()],
+                    ),
+                    result,
+                )
+            },
+        )
+    }
+
+    #[derive(Accounts)]
+    pub struct ApplyBatchWithSession<'info> {
+        /// CHECK: authority check
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account()]
+        pub world: Account<'info, World>,
+        pub system_program: Program<'info, System>,
+        #[account()]
+        /// CHECK: The session token
+        pub session_token: UncheckedAccount<'info>,
+    }
+}
+
+fn approve_system_impl(
+    ctx: Context<ApproveSystem>,
+    component_programs: Option<BTreeSet<Pubkey>>,
+) -> Result<()> {
+    if !ctx.accounts.authority.is_signer {
+        return Err(WorldError::InvalidAuthority.into());
+    }
+    check_role(
+        &ctx.accounts.world,
+        &ctx.accounts.authority,
+        roles::OPERATOR,
+    )?;
+    ctx.accounts.world.normalize_roles();
+    if ctx.accounts.world.permissionless {
+        ctx.accounts.world.permissionless = false;
+    }
+
+    let mut world_systems = ctx.accounts.world.systems();
+    world_systems
+        .approved_systems
+        .insert(ctx.accounts.system.key());
+    // Systems approved without component programs write any component
+    match component_programs {
+        Some(component_programs) => world_systems
+            .component_permissions
+            .insert(ctx.accounts.system.key(), component_programs),
+        None => world_systems
+            .component_permissions
+            .remove(&ctx.accounts.system.key()),
+    };
+
+    let encoded_world_systems = world_systems.try_to_vec()?;
+    ctx.accounts.world.systems = encoded_world_systems.clone();
+
+    let new_space = ctx.accounts.world.space();
+
+    // Transfer to make it rent exempt
+    let rent = Rent::get()?;
+    let new_minimum_balance = rent.minimum_balance(new_space);
+    let lamports_diff =
+        new_minimum_balance.saturating_sub(ctx.accounts.world.to_account_info().lamports());
+    if lamports_diff > 0 {
+        anchor_lang::solana_program::program::invoke(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                ctx.accounts.authority.key,
+                ctx.accounts.world.to_account_info().key,
+                lamports_diff,
+            ),
+            &[
+                ctx.accounts.authority.to_account_info(),
+                ctx.accounts.world.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    }
+    ctx.accounts
+        .world
+        .to_account_info()
+        .realloc(new_space, false)?;
+    msg!("Approved system: {:?}", world_systems);
+    Ok(())
+}
+
+#[allow(clippy::type_complexity)]
+fn apply_impl<'info>(
+    authority: &Signer<'info>,
+    world: &Account<'info, World>,
+    bolt_system: &UncheckedAccount<'info>,
+    cpi_context: CpiContext<'_, '_, '_, 'info, bolt_system::cpi::accounts::BoltExecute<'info>>,
+    args: Vec<u8>,
+    mut remaining_accounts: Vec<AccountInfo<'info>>,
+) -> Result<(
+    Vec<(AccountInfo<'info>, AccountInfo<'info>)>,
+    Vec<Vec<u8>>,
+    Vec<CpiAuth<'info>>,
+)> {
+    check_authority(authority)?;
+    check_world_state(world, Some(bolt_system.key))?;
+    let world_systems = world.systems();
+    check_system_approval(world, &world_systems, bolt_system.key)?;
+
+    let pairs = take_component_pairs(&mut remaining_accounts)?;
+    check_component_pairs(&pairs)?;
+    check_component_permissions(&world_systems, bolt_system.key, &pairs)?;
+    let cpi_auths = take_cpi_auths(&mut remaining_accounts, &pairs)?;
+    let results = execute_system(cpi_context, args, &pairs, &remaining_accounts, world)?;
+    emit_system_applied(world, bolt_system.key);
+    Ok((writable_pairs(pairs), results, cpi_auths))
+}
+
+#[allow(clippy::type_complexity)]
+fn apply_commands_impl<'info>(
+    authority: &Signer<'info>,
+    world: &Account<'info, World>,
+    bolt_system: &UncheckedAccount<'info>,
+    cpi_context: CpiContext<'_, '_, '_, 'info, bolt_system::cpi::accounts::BoltExecute<'info>>,
+    args: Vec<u8>,
+    mut remaining_accounts: Vec<AccountInfo<'info>>,
+) -> Result<(
+    Vec<(AccountInfo<'info>, AccountInfo<'info>)>,
+    Vec<Vec<u8>>,
+    Vec<SystemCommand>,
+    Vec<CpiAuth<'info>>,
+)> {
+    check_authority(authority)?;
+    check_world_state(world, Some(bolt_system.key))?;
+    let world_systems = world.systems();
+    check_system_approval(world, &world_systems, bolt_system.key)?;
+
+    let pairs = take_component_pairs(&mut remaining_accounts)?;
+    check_component_pairs(&pairs)?;
+    check_component_permissions(&world_systems, bolt_system.key, &pairs)?;
+    let cpi_auths = take_cpi_auths(&mut remaining_accounts, &pairs)?;
+    let output = bolt_system::cpi::bolt_execute_with_commands(
+        cpi_context.with_remaining_accounts(system_accounts(&pairs, &remaining_accounts, world)),
+        args,
+    )?
+    .get();
+    check_output_count(&output.components, &pairs)?;
+    emit_system_applied(world, bolt_system.key);
+    Ok((
+        writable_pairs(pairs),
+        output.components,
+        output.commands,
+        cpi_auths,
+    ))
+}
+
+#[allow(clippy::type_complexity)]
+fn apply_patch_impl<'info>(
+    authority: &Signer<'info>,
+    world: &Account<'info, World>,
+    bolt_system: &UncheckedAccount<'info>,
+    cpi_context: CpiContext<'_, '_, '_, 'info, bolt_system::cpi::accounts::BoltExecute<'info>>,
+    args: Vec<u8>,
+    mut remaining_accounts: Vec<AccountInfo<'info>>,
+) -> Result<(
+    Vec<(AccountInfo<'info>, AccountInfo<'info>)>,
+    Vec<Vec<bolt_system::PatchRange>>,
+    Vec<CpiAuth<'info>>,
+)> {
+    check_authority(authority)?;
+    check_world_state(world, Some(bolt_system.key))?;
+    let world_systems = world.systems();
+    check_system_approval(world, &world_systems, bolt_system.key)?;
+
+    let pairs = take_component_pairs(&mut remaining_accounts)?;
+    check_component_pairs(&pairs)?;
+    check_component_permissions(&world_systems, bolt_system.key, &pairs)?;
+    let cpi_auths = take_cpi_auths(&mut remaining_accounts, &pairs)?;
+    let patches = bolt_system::cpi::bolt_execute_patch(
+        cpi_context.with_remaining_accounts(system_accounts(&pairs, &remaining_accounts, world)),
+        args,
+    )?
+    .get();
+
+    let pairs = writable_pairs(pairs);
+    if patches.len() != pairs.len() {
+        return Err(WorldError::InvalidSystemOutput.into());
+    }
+    for ((_, component), ranges) in pairs.iter().zip(patches.iter()) {
+        check_patch_bounds(component, ranges)?;
+    }
+    emit_system_applied(world, bolt_system.key);
+    Ok((pairs, patches, cpi_auths))
+}
+
+/// Executes the system with an output buffer, an account owned by the system which holds the
+/// serialized components instead of the return data, and reads the outputs back.
+///
+/// The buffer is owned by the system rather than by the world: only the owner of an account can
+/// write its data, and the system can't call back into the world to write on its behalf. The
+/// world checks instead that the system owns the buffer, so that no one else wrote the outputs,
+/// and that the buffer is none of the accounts passed to the system.
+#[allow(clippy::type_complexity)]
+fn apply_buffered_impl<'info>(
+    authority: &Signer<'info>,
+    world: &Account<'info, World>,
+    bolt_system: &UncheckedAccount<'info>,
+    cpi_context: CpiContext<
+        '_,
+        '_,
+        '_,
+        'info,
+        bolt_system::cpi::accounts::BoltExecuteBuffered<'info>,
+    >,
+    args: Vec<u8>,
+    mut remaining_accounts: Vec<AccountInfo<'info>>,
+) -> Result<(
+    Vec<(AccountInfo<'info>, AccountInfo<'info>)>,
+    Vec<Vec<u8>>,
+    Vec<CpiAuth<'info>>,
+)> {
+    check_authority(authority)?;
+    check_world_state(world, Some(bolt_system.key))?;
+    let world_systems = world.systems();
+    check_system_approval(world, &world_systems, bolt_system.key)?;
+
+    let pairs = take_component_pairs(&mut remaining_accounts)?;
+    check_component_pairs(&pairs)?;
+    check_component_permissions(&world_systems, bolt_system.key, &pairs)?;
+    let cpi_auths = take_cpi_auths(&mut remaining_accounts, &pairs)?;
+    let buffer = cpi_context.accounts.buffer.clone();
+    check_output_buffer(&buffer, bolt_system.key, &pairs, &remaining_accounts)?;
+    bolt_system::cpi::bolt_execute_buffered(
+        cpi_context.with_remaining_accounts(system_accounts(&pairs, &remaining_accounts, world)),
+        args,
+    )?;
+
+    // The system can't give the buffer away, but check it again before trusting the outputs
+    if buffer.owner != bolt_system.key {
+        return Err(WorldError::InvalidOutputBuffer.into());
+    }
+    let results = Vec::<Vec<u8>>::deserialize(&mut &buffer.try_borrow_data()?[..])
+        .map_err(|_| WorldError::InvalidSystemOutput)?;
+    check_output_count(&results, &pairs)?;
+    emit_system_applied(world, bolt_system.key);
+    Ok((writable_pairs(pairs), results, cpi_auths))
+}
+
+/// Checks that the output buffer is owned by the system, the only program able to write it, and
+/// that it isn't one of the components or extra accounts the system receives.
+fn check_output_buffer(
+    buffer: &AccountInfo,
+    bolt_system: &Pubkey,
+    pairs: &[(AccountInfo, AccountInfo)],
+    extras: &[AccountInfo],
+) -> Result<()> {
+    if buffer.owner != bolt_system {
+        return Err(WorldError::InvalidOutputBuffer.into());
+    }
+    let mut passed = pairs
+        .iter()
+        .map(|(_, component)| component.key)
+        .chain(extras.iter().map(|account| account.key));
+    if passed.any(|key| key == buffer.key) {
+        return Err(WorldError::InvalidOutputBuffer.into());
+    }
+    Ok(())
+}
+
+/// Emits the event of a system applied to the world.
+fn emit_system_applied(world: &World, bolt_system: &Pubkey) {
+    emit!(SystemApplied {
+        world_id: world.id,
+        system: *bolt_system,
+    });
+}
+
+/// Takes the (program, component) pairs from the front of the remaining accounts. The accounts
+/// left are the extra accounts of the system.
+///
+/// The world program id is the only delimiter of the components:
+/// - in place of a component, it marks an absent optional component, or the end of a component
+///   set, and is passed on to the system as is;
+/// - in place of a program, it ends the components, and precedes the extra accounts.
+fn take_component_pairs<'info>(
+    remaining_accounts: &mut Vec<AccountInfo<'info>>,
+) -> Result<Vec<(AccountInfo<'info>, AccountInfo<'info>)>> {
+    let mut pairs = Vec::new();
+    while !remaining_accounts.is_empty() {
+        let program = remaining_accounts.remove(0);
+        if program.key() == ID {
+            break;
+        }
+        if remaining_accounts.is_empty() {
+            return Err(ErrorCode::AccountNotEnoughKeys.into());
+        }
+        let component = remaining_accounts.remove(0);
+        pairs.push((program, component));
+    }
+    Ok(pairs)
+}
+
+/// Takes the signers of the programs of the written back components from the extra accounts,
+/// where they follow the accounts of the system. The system doesn't receive them.
+fn take_cpi_auths<'info>(
+    remaining_accounts: &mut Vec<AccountInfo<'info>>,
+    pairs: &[(AccountInfo<'info>, AccountInfo<'info>)],
+) -> Result<Vec<CpiAuth<'info>>> {
+    let cpi_auths = find_cpi_auths(
+        remaining_accounts,
+        pairs
+            .iter()
+            .filter(|(_, component)| is_written_back(component))
+            .map(|(program, _)| program.key),
+    )?;
+    remaining_accounts.retain(|account| {
+        !cpi_auths
+            .iter()
+            .any(|cpi_auth| cpi_auth.account.key == account.key)
+    });
+    Ok(cpi_auths)
+}
+
+/// Finds the signers of the component programs among the accounts, once per program.
+fn find_cpi_auths<'a, 'info>(
+    accounts: &[AccountInfo<'info>],
+    component_programs: impl Iterator<Item = &'a Pubkey>,
+) -> Result<Vec<CpiAuth<'info>>> {
+    let mut cpi_auths: Vec<CpiAuth> = Vec::new();
+    for component_program in component_programs {
+        if CpiAuth::of(&cpi_auths, component_program).is_err() {
+            cpi_auths.push(CpiAuth::find(accounts, component_program)?);
+        }
+    }
+    Ok(cpi_auths)
+}
+
+/// Ensures every component is owned by the program it is paired with, and is passed once. The
+/// world program id stands for an absent optional component.
+fn check_component_pairs(pairs: &[(AccountInfo, AccountInfo)]) -> Result<()> {
+    let mut components = BTreeSet::new();
+    for (program, component) in pairs {
+        if component.key() == ID {
+            continue;
+        }
+        if component.owner != program.key {
+            return Err(WorldError::ComponentOwnerMismatch.into());
+        }
+        if !components.insert(component.key) {
+            return Err(WorldError::DuplicateComponent.into());
+        }
+    }
+    Ok(())
+}
+
+/// Ensures every range of the patch lies within the component data, after the discriminator, or
+/// within the growth the component may take when it is resized by the patch.
+fn check_patch_bounds(component: &AccountInfo, ranges: &[bolt_system::PatchRange]) -> Result<()> {
+    let data_len = component
+        .data_len()
+        .saturating_sub(8)
+        .saturating_add(anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE);
+    for range in ranges {
+        let end = (range.offset as usize).checked_add(range.data.len());
+        if !matches!(end, Some(end) if end <= data_len) {
+            return Err(WorldError::InvalidPatch.into());
+        }
+    }
+    Ok(())
+}
+
+/// Executes an ordered list of systems, deferring the component updates until the end of the
+/// batch, where every updated component is written back once. The pending updates are passed
+/// forward instead: every system runs through `bolt_execute_batched` with the pending data of the
+/// components it reads, among its components or its extra accounts, so that it observes the output
+/// of the systems that ran before it. A component written by a system of the batch is writable in
+/// the whole transaction, so the systems return the index of each component they write, from
+/// their own declaration, rather than the world deriving it from the writable accounts. The
+/// entrypoint fails if a system issues commands: the commands would run against components whose
+/// updates are still deferred, so the systems issuing commands are applied on their own with
+/// `apply_commands`.
+fn apply_batch_impl<'info>(
+    authority: &Signer<'info>,
+    world: &Account<'info, World>,
+    systems: Vec<BatchedSystem>,
+    mut remaining_accounts: &[AccountInfo<'info>],
+    mut write_back: impl FnMut(
+        AccountInfo<'info>,
+        AccountInfo<'info>,
+        Vec<u8>,
+        &CpiAuth<'info>,
+    ) -> Result<()>,
+) -> Result<()> {
+    check_authority(authority)?;
+    let world_systems = world.systems();
+
+    let mut pending: Vec<(AccountInfo<'info>, AccountInfo<'info>, Vec<u8>)> = Vec::new();
+    for system in systems {
+        let (bolt_system, accounts) = remaining_accounts
+            .split_first()
+            .ok_or(ErrorCode::AccountNotEnoughKeys)?;
+        let components_len = 2 * system.components as usize;
+        let accounts_len = components_len + system.extra_accounts as usize;
+        if accounts.len() < accounts_len {
+            return Err(ErrorCode::AccountNotEnoughKeys.into());
+        }
+        let (accounts, rest) = accounts.split_at(accounts_len);
+        remaining_accounts = rest;
+
+        check_world_state(world, Some(bolt_system.key))?;
+        check_system_approval(world, &world_systems, bolt_system.key)?;
+
+        let (components, extra_accounts) = accounts.split_at(components_len);
+        let pairs = components
+            .chunks(2)
+            .map(|pair| (pair[0].clone(), pair[1].clone()))
+            .collect::<Vec<_>>();
+        check_component_pairs(&pairs)?;
+
+        // The accounts of the system, in the order it receives them, holding a pending update
+        let inputs = pairs
+            .iter()
+            .map(|(_, component)| component)
+            .chain(extra_accounts.iter())
+            .enumerate()
+            .filter_map(|(index, account)| {
+                pending
+                    .iter()
+                    .find(|(_, component, _)| component.key == account.key)
+                    .map(|(_, _, data)| bolt_system::PendingComponent {
+                        index: index as u8,
+                        data: data.clone(),
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        let cpi_context = CpiContext::new(
+            bolt_system.clone(),
+            bolt_system::cpi::accounts::BoltExecute {
+                authority: authority.to_account_info(),
+            },
+        );
+        let results = bolt_system::cpi::bolt_execute_batched(
+            cpi_context.with_remaining_accounts(system_accounts(&pairs, extra_accounts, world)),
+            system.args,
+            inputs,
+        )?
+        .get();
+        let written = written_pairs(&pairs, &results)?;
+        check_component_permissions(&world_systems, bolt_system.key, &written)?;
+        emit_system_applied(world, bolt_system.key);
+        for ((program, component), result) in written.into_iter().zip(results) {
+            match pending
+                .iter_mut()
+                .find(|(_, pending_component, _)| pending_component.key == component.key)
+            {
+                Some(update) => *update = (program, component, result.data),
+                None => pending.push((program, component, result.data)),
+            }
+        }
+    }
+
+    // The signers of the written component programs follow the accounts of the last system
+    let cpi_auths = find_cpi_auths(
+        remaining_accounts,
+        pending.iter().map(|(program, _, _)| program.key),
+    )?;
+    for (program, component, result) in pending {
+        let cpi_auth = CpiAuth::of(&cpi_auths, program.key)?;
+        write_back(program, component, result, cpi_auth)?;
+    }
+    Ok(())
+}
+
+fn check_authority(authority: &Signer) -> Result<()> {
+    if !authority.is_signer && authority.key != &ID {
+        return Err(WorldError::InvalidAuthority.into());
+    }
+    Ok(())
+}
+
+/// Ensures the world accepts the instruction. While the world is paused, only its paused systems
+/// run.
+fn check_world_state(world: &World, bolt_system: Option<&Pubkey>) -> Result<()> {
+    match world.config.state {
+        WorldState::Active => Ok(()),
+        WorldState::Paused
+            if bolt_system.is_some_and(|system| world.config.paused_systems.contains(system)) =>
+        {
+            Ok(())
+        }
+        WorldState::Paused => Err(WorldError::WorldPaused.into()),
+        WorldState::Frozen => Err(WorldError::WorldFrozen.into()),
+    }
+}
+
+/// Resizes the world to its serialized size, the payer tops up the rent.
+fn realloc_world<'info>(
+    world: &Account<'info, World>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    realloc_account(
+        &world.to_account_info(),
+        world.space(),
+        payer,
+        system_program,
+    )
+}
+
+/// Resizes the account, funding the rent of the new space with the payer.
+fn realloc_account<'info>(
+    account: &AccountInfo<'info>,
+    new_space: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let lamports_diff = Rent::get()?
+        .minimum_balance(new_space)
+        .saturating_sub(account.lamports());
+    if lamports_diff > 0 {
+        anchor_lang::solana_program::program::invoke(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                payer.key,
+                account.key,
+                lamports_diff,
+            ),
+            &[
+                payer.to_account_info(),
+                account.clone(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    }
+    account.realloc(new_space, false)?;
+    Ok(())
+}
+
+fn check_role(world: &World, authority: &Signer, role: u8) -> Result<()> {
+    if !world.has_role(authority.key, role) {
+        return Err(WorldError::MissingRole.into());
+    }
+    Ok(())
+}
+
+/// Ensures the world keeps at least one admin once the authority has the new roles.
+fn check_admin_kept(world: &World, authority: &Pubkey, new_roles: u8) -> Result<()> {
+    let admins = world
+        .authorities
+        .iter()
+        .filter(|key| world.has_role(key, roles::ADMIN))
+        .count();
+    if world.has_role(authority, roles::ADMIN) && new_roles & roles::ADMIN == 0 && admins == 1 {
+        return Err(WorldError::LastAdmin.into());
+    }
+    Ok(())
+}
+
+/// Ensures the authority is the owner of the entity, if it has one.
+fn check_entity_owner(entity: &Entity, authority: &Signer) -> Result<()> {
+    match entity.owner {
+        Some(owner) if owner != *authority.key => Err(WorldError::InvalidAuthority.into()),
+        _ => Ok(()),
+    }
+}
+
+fn check_system_approval(
+    world: &World,
+    world_systems: &WorldSystems,
+    bolt_system: &Pubkey,
+) -> Result<()> {
+    if !world.permissionless && !world_systems.approved_systems.contains(bolt_system) {
+        return Err(WorldError::SystemNotApproved.into());
+    }
+    Ok(())
+}
+
+/// Ensures the system may write the components it is applied to.
+fn check_component_permissions(
+    world_systems: &WorldSystems,
+    bolt_system: &Pubkey,
+    pairs: &[(AccountInfo, AccountInfo)],
+) -> Result<()> {
+    let Some(component_programs) = world_systems.component_permissions.get(bolt_system) else {
+        return Ok(());
+    };
+    for (program, component) in pairs {
+        if is_written_back(component) && !component_programs.contains(program.key) {
+            return Err(WorldError::ComponentNotAllowed.into());
+        }
+    }
+    Ok(())
+}
+
+/// Invokes the system with the components followed by the extra accounts and returns its output,
+/// one serialized component per writable input pair.
+fn execute_system<'info>(
+    cpi_context: CpiContext<'_, '_, '_, 'info, bolt_system::cpi::accounts::BoltExecute<'info>>,
+    args: Vec<u8>,
+    pairs: &[(AccountInfo<'info>, AccountInfo<'info>)],
+    extra_accounts: &[AccountInfo<'info>],
+    world: &Account<'info, World>,
+) -> Result<Vec<Vec<u8>>> {
+    let results = bolt_system::cpi::bolt_execute(
+        cpi_context.with_remaining_accounts(system_accounts(pairs, extra_accounts, world)),
+        args,
+    )?
+    .get();
+
+    check_output_count(&results, pairs)?;
+    Ok(results)
+}
+
+/// The accounts the commands of a system run with.
+struct CommandAccounts<'a, 'info> {
+    bolt_system: &'a Pubkey,
+    authority: &'a Signer<'info>,
+    world: &'a mut Account<'info, World>,
+    vault: &'a SystemAccount<'info>,
+    vault_bump: u8,
+    system_authority: &'a UncheckedAccount<'info>,
+    system_authority_bump: u8,
+    world_program: &'a Program<'info, crate::program::World>,
+    system_program: &'a Program<'info, System>,
+}
+
+/// Runs the commands issued by a system, after its component updates. The commands are authorized
+/// by the approval of the system: they act on the components of the programs listed for the
+/// system, of the entities owned by the authority or by the world program. The vault pays the rent
+/// of the spawned accounts and receives the rent of the destroyed ones.
+fn execute_commands<'info>(
+    accounts: CommandAccounts<'_, 'info>,
+    commands: Vec<SystemCommand>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    if commands.is_empty() {
+        return Ok(());
+    }
+    let world = accounts.world;
+    let world_key = world.key();
+    let vault = accounts.vault.to_account_info();
+    let vault_seeds: &[&[u8]] = &[vault::SEED, world_key.as_ref(), &[accounts.vault_bump]];
+    let system_authority_bump = [accounts.system_authority_bump];
+    let system_authority_seeds = world_authority::seeds(
+        world_authority::SYSTEM_SEED,
+        &world_key,
+        &system_authority_bump,
+    );
+    let world_systems = world.systems();
+    let component_programs = world_systems
+        .component_permissions
+        .get(accounts.bolt_system);
+    // Unlike the component updates, the commands require the component programs to be listed
+    // for the system, since the world signs the instructions of the programs the system picks
+    let check_component_program = |component_program: &Pubkey| -> Result<()> {
+        if !component_programs.is_some_and(|programs| programs.contains(component_program)) {
+            return Err(WorldError::ComponentNotAllowed.into());
+        }
+        Ok(())
+    };
+    let find_entity = |key: &Pubkey| -> Result<AccountInfo<'info>> {
+        let account = find_account(remaining_accounts, key)?;
+        if account.owner != &ID {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let entity = Entity::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        if entity.world != world_key {
+            return Err(WorldError::EntityWorldMismatch.into());
+        }
+        // The commands only reach the entities spawned by the world and the entities of the
+        // authority, the entities without owner are left to the world instructions
+        match entity.owner {
+            Some(owner) if owner == ID || owner == *accounts.authority.key => Ok(account),
+            _ => Err(WorldError::InvalidAuthority.into()),
+        }
+    };
+
+    let mut entities_created = false;
+    for command in commands {
+        match command {
+            SystemCommand::Destroy {
+                component_program,
+                entity,
+                component,
+                instance,
+            } => {
+                check_component_program(&component_program)?;
+                let entity = find_entity(&entity)?;
+                let component_program = find_account(remaining_accounts, &component_program)?;
+                let component = find_account(remaining_accounts, &component)?;
+                if component.owner != component_program.key {
+                    return Err(WorldError::ComponentOwnerMismatch.into());
+                }
+                let component_program_data = find_account(
+                    remaining_accounts,
+                    &Pubkey::find_program_address(
+                        &[component_program.key.as_ref()],
+                        &anchor_lang::solana_program::bpf_loader_upgradeable::id(),
+                    )
+                    .0,
+                )?;
+                let cpi_auth = CpiAuth::find(remaining_accounts, component_program.key)?;
+                // The world signs with its system authority, which only destroys the components
+                // held by the world program, not the ones of the players
+                let cpi_accounts = bolt_component::cpi::accounts::Destroy {
+                    authority: accounts.system_authority.to_account_info(),
+                    receiver: vault.clone(),
+                    entity,
+                    component,
+                    component_program_data,
+                    cpi_auth: cpi_auth.account.clone(),
+                    system_program: accounts.system_program.to_account_info(),
+                };
+                bolt_component::cpi::destroy(
+                    CpiContext::new_with_signer(
+                        component_program,
+                        cpi_accounts,
+                        &[&cpi_auth.seeds(), &system_authority_seeds],
+                    ),
+                    instance,
+                )?;
+            }
+            SystemCommand::Initialize {
+                component_program,
+                entity,
+                component,
+                instance,
+                data,
+                space,
+            } => {
+                check_component_program(&component_program)?;
+                let entity = find_entity(&entity)?;
+                let component_program = find_account(remaining_accounts, &component_program)?;
+                let component = find_account(remaining_accounts, &component)?;
+                if !component.data_is_empty() {
+                    return Err(WorldError::ComponentAlreadyInitialized.into());
+                }
+                let cpi_auth = CpiAuth::find(remaining_accounts, component_program.key)?;
+                // The vault only signs for the system program: it funds the rent of the component
+                // first, so that the initialization leaves nothing for the payer to pay
+                let rent = Rent::get()?
+                    .minimum_balance(space as usize)
+                    .saturating_sub(component.lamports());
+                if rent > 0 {
+                    anchor_lang::solana_program::program::invoke_signed(
+                        &anchor_lang::solana_program::system_instruction::transfer(
+                            vault.key,
+                            component.key,
+                            rent,
+                        ),
+                        &[
+                            vault.clone(),
+                            component.clone(),
+                            accounts.system_program.to_account_info(),
+                        ],
+                        &[vault_seeds],
+                    )?;
+                }
+                // The spawned components belong to the world program, so that the systems of the
+                // world keep writing them
+                let cpi_accounts = bolt_component::cpi::accounts::Initialize {
+                    payer: accounts.authority.to_account_info(),
+                    data: component.clone(),
+                    entity,
+                    authority: accounts.world_program.to_account_info(),
+                    cpi_auth: cpi_auth.account.clone(),
+                    system_program: accounts.system_program.to_account_info(),
+                    world: world.to_account_info(),
+                };
+                bolt_component::cpi::initialize(
+                    CpiContext::new_with_signer(
+                        component_program.clone(),
+                        cpi_accounts,
+                        &[&cpi_auth.seeds()],
+                    ),
+                    instance,
+                )?;
+                // The funded space must be the space of the component, or the payer would have
+                // paid for the rest
+                if component.data_len() as u64 != space {
+                    return Err(WorldError::InvalidSystemOutput.into());
+                }
+                // Components spawned without data keep their default value, the update keeps the
+                // metadata set by the initialization
+                if data.is_empty() {
+                    continue;
+                }
+                bolt_component::cpi::update(
+                    build_update_context(
+                        component_program,
+                        component,
+                        accounts.authority.clone(),
+                        cpi_auth.account.clone(),
+                        world.to_account_info(),
+                        accounts.system_program.clone(),
+                        &[&cpi_auth.seeds()],
+                    ),
+                    data,
+                )?;
+            }
+            SystemCommand::CreateEntity { extra_seed } => {
+                require!(world_key == world.pda().0, WorldError::WorldAccountMismatch);
+                let (entity_key, entity_bump) = Entity::pda_with_seed(world.id, &extra_seed);
+                let entity = find_account(remaining_accounts, &entity_key)?;
+                let world_id = world.id.to_be_bytes();
+                let entity_seeds: &[&[u8]] = &[
+                    Entity::seed(),
+                    &world_id,
+                    &[0; 8],
+                    &extra_seed,
+                    &[entity_bump],
+                ];
+                anchor_lang::solana_program::program::invoke_signed(
+                    &anchor_lang::solana_program::system_instruction::create_account(
+                        vault.key,
+                        &entity_key,
+                        Rent::get()?.minimum_balance(Entity::size()),
+                        Entity::size() as u64,
+                        &ID,
+                    ),
+                    &[
+                        vault.clone(),
+                        entity.clone(),
+                        accounts.system_program.to_account_info(),
+                    ],
+                    &[vault_seeds, entity_seeds],
+                )?;
+                // The entities created by the systems belong to the world program
+                Entity {
+                    id: world.entities,
+                    owner: Some(ID),
+                    world: world_key,
+                }
+                .try_serialize(&mut &mut entity.try_borrow_mut_data()?[..])?;
+                world.entities += 1;
+                entities_created = true;
+            }
+        }
+    }
+    // The worlds created before the roles and the world state grow when they are written
+    if entities_created {
+        realloc_world(world, accounts.authority, accounts.system_program)?;
+    }
+    Ok(())
+}
+
+/// Finds the account with the key in the remaining accounts.
+fn find_account<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+    key: &Pubkey,
+) -> Result<AccountInfo<'info>> {
+    remaining_accounts
+        .iter()
+        .find(|account| account.key == key)
+        .cloned()
+        .ok_or_else(|| ErrorCode::AccountNotEnoughKeys.into())
+}
+
+/// Ensures the system returns one serialized component per written back pair.
+fn check_output_count(results: &[Vec<u8>], pairs: &[(AccountInfo, AccountInfo)]) -> Result<()> {
+    if results.len()
+        != pairs
+            .iter()
+            .filter(|(_, component)| is_written_back(component))
+            .count()
+    {
+        return Err(WorldError::InvalidSystemOutput.into());
+    }
+    Ok(())
+}
+
+/// The pairs of the components written by a batched system, from the indices it returns. Each one
+/// must be a writable component of the system, returned once.
+fn written_pairs<'info>(
+    pairs: &[(AccountInfo<'info>, AccountInfo<'info>)],
+    results: &[bolt_system::PendingComponent],
+) -> Result<Vec<(AccountInfo<'info>, AccountInfo<'info>)>> {
+    let mut indices = BTreeSet::new();
+    results
+        .iter()
+        .map(|result| {
+            let pair = pairs
+                .get(result.index as usize)
+                .filter(|(_, component)| is_written_back(component))
+                .filter(|_| indices.insert(result.index))
+                .ok_or(WorldError::InvalidSystemOutput)?;
+            Ok(pair.clone())
+        })
+        .collect()
+}
+
+/// Keeps the pairs whose component is written back.
+fn writable_pairs<'info>(
+    pairs: Vec<(AccountInfo<'info>, AccountInfo<'info>)>,
+) -> Vec<(AccountInfo<'info>, AccountInfo<'info>)> {
+    pairs
+        .into_iter()
+        .filter(|(_, component)| is_written_back(component))
+        .collect()
+}
+
+/// Components passed as non-writable are the read-only inputs of the system, and the world
+/// program id stands for an absent optional component. Neither is returned by the system.
+fn is_written_back(component: &AccountInfo) -> bool {
+    component.is_writable && component.key() != ID
+}
+
+/// The accounts passed to a system: the components followed by the extra accounts, and the world
+/// last. The systems built before the world was passed read the components and the extra accounts
+/// from the front, and ignore it.
+fn system_accounts<'info>(
+    pairs: &[(AccountInfo<'info>, AccountInfo<'info>)],
+    extra_accounts: &[AccountInfo<'info>],
+    world: &Account<'info, World>,
+) -> Vec<AccountInfo<'info>> {
+    pairs
+        .iter()
+        .map(|(_, component)| component)
+        .chain(extra_accounts.iter())
+        .cloned()
+        .chain(std::iter::once(world.to_account_info()))
+        .collect()
+}
+
+#[derive(Accounts)]
+pub struct InitializeRegistry<'info> {
+    #[account(init, payer = payer, space = Registry::size(), seeds = [Registry::seed()], bump)]
+    pub registry: Account<'info, Registry>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
 
 #[derive(Accounts)]
 pub struct InitializeNewWorld<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
-    #[account(init, payer = payer, space = World::size(), seeds = [World::seed(), &registry.worlds.to_be_bytes()], bump)]
+    #[account(init, payer = payer, space = World::space_for_authorities(1, 0), seeds = [World::seed(), &registry.worlds.to_be_bytes()], bump)]
     pub world: Account<'info, World>,
     #[account(mut, address = Registry::pda().0)]
     pub registry: Account<'info, Registry>,
@@ -465,6 +2093,23 @@ pub struct AddAuthority<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+#[instruction(world_id: u64)]
+pub struct ClaimWorldAdmin<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(mut, seeds = [World::seed(), &world_id.to_be_bytes()], bump)]
+    pub world: Account<'info, World>,
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = anchor_lang::solana_program::bpf_loader_upgradeable::ID,
+        constraint = world_program_data.upgrade_authority_address == Some(authority.key()) @ WorldError::InvalidAuthority
+    )]
+    pub world_program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 #[instruction(world_id: u64)]
 pub struct RemoveAuthority<'info> {
@@ -478,6 +2123,29 @@ pub struct RemoveAuthority<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+#[instruction(world_id: u64)]
+pub struct SetAuthorityRoles<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account()]
+    /// CHECK: authority to update check
+    pub authority_to_update: AccountInfo<'info>,
+    #[account(mut, seeds = [World::seed(), &world_id.to_be_bytes()], bump)]
+    pub world: Account<'info, World>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(world_id: u64)]
+pub struct ConfigureWorld<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(mut, seeds = [World::seed(), &world_id.to_be_bytes()], bump)]
+    pub world: Account<'info, World>,
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct ApproveSystem<'info> {
     #[account(mut)]
@@ -505,7 +2173,7 @@ pub struct RemoveSystem<'info> {
 pub struct AddEntity<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
-    #[account(init, payer = payer, space = World::size(), seeds = [Entity::seed(), &world.id.to_be_bytes(),
+    #[account(init, payer = payer, space = Entity::size(), seeds = [Entity::seed(), &world.id.to_be_bytes(),
     &match extra_seed {
         Some(ref _seed) => [0; 8],
         None => world.entities.to_be_bytes()
@@ -520,6 +2188,26 @@ pub struct AddEntity<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+#[instruction(extra_seed: Option<Vec<u8>>)]
+pub struct MigrateEntity<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, seeds = [Entity::seed(), &world.id.to_be_bytes(),
+    &match extra_seed {
+        Some(ref _seed) => [0; 8],
+        None => entity.id.to_be_bytes()
+    },
+    match extra_seed {
+        Some(ref seed) => seed,
+        None => &[],
+    }], bump)]
+    pub entity: Account<'info, Entity>,
+    #[account()]
+    pub world: Account<'info, World>,
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct InitializeComponent<'info> {
     #[account(mut)]
@@ -527,22 +2215,27 @@ pub struct InitializeComponent<'info> {
     #[account(mut)]
     /// CHECK: component data check
     pub data: AccountInfo<'info>,
-    #[account()]
+    #[account(has_one = world @ WorldError::EntityWorldMismatch)]
     pub entity: Account<'info, Entity>,
+    /// The owner of the entity, any signer for an entity without owner
+    pub owner: Signer<'info>,
     /// CHECK: component program check
     pub component_program: AccountInfo<'info>,
     /// CHECK: authority check
     pub authority: AccountInfo<'info>,
-    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
-    /// CHECK: instruction sysvar check
-    pub instruction_sysvar_account: UncheckedAccount<'info>,
+    #[account(seeds = [cpi_auth::SEED, component_program.key().as_ref()], bump)]
+    /// CHECK: The signer of the CPI into the component program
+    pub cpi_auth: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
+    #[account()]
+    pub world: Account<'info, World>,
 }
 
 impl<'info> InitializeComponent<'info> {
-    pub fn build(
+    pub fn build<'a>(
         &self,
-    ) -> CpiContext<'_, '_, '_, 'info, bolt_component::cpi::accounts::Initialize<'info>> {
+        signer_seeds: &'a [&'a [&'a [u8]]],
+    ) -> CpiContext<'a, 'a, 'a, 'info, bolt_component::cpi::accounts::Initialize<'info>> {
         let cpi_program = self.component_program.to_account_info();
 
         let cpi_accounts = bolt_component::cpi::accounts::Initialize {
@@ -550,10 +2243,50 @@ impl<'info> InitializeComponent<'info> {
             data: self.data.to_account_info(),
             entity: self.entity.to_account_info(),
             authority: self.authority.to_account_info(),
-            instruction_sysvar_account: self.instruction_sysvar_account.to_account_info(),
+            cpi_auth: self.cpi_auth.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            world: self.world.to_account_info(),
+        };
+        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds)
+    }
+}
+
+#[derive(Accounts)]
+pub struct InitializeResource<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: resource data check
+    pub data: AccountInfo<'info>,
+    /// CHECK: component program check
+    pub component_program: AccountInfo<'info>,
+    /// CHECK: authority check
+    pub authority: AccountInfo<'info>,
+    #[account(seeds = [cpi_auth::SEED, component_program.key().as_ref()], bump)]
+    /// CHECK: The signer of the CPI into the component program
+    pub cpi_auth: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    #[account()]
+    pub world: Account<'info, World>,
+}
+
+impl<'info> InitializeResource<'info> {
+    pub fn build<'a>(
+        &self,
+        signer_seeds: &'a [&'a [&'a [u8]]],
+    ) -> CpiContext<'a, 'a, 'a, 'info, bolt_component::cpi::accounts::InitializeResource<'info>>
+    {
+        let cpi_program = self.component_program.to_account_info();
+
+        let cpi_accounts = bolt_component::cpi::accounts::InitializeResource {
+            payer: self.payer.to_account_info(),
+            data: self.data.to_account_info(),
+            authority: self.authority.to_account_info(),
+            cpi_auth: self.cpi_auth.to_account_info(),
             system_program: self.system_program.to_account_info(),
+            world: self.world.to_account_info(),
         };
-        CpiContext::new(cpi_program, cpi_accounts)
+        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds)
     }
 }
 
@@ -568,33 +2301,228 @@ pub struct DestroyComponent<'info> {
     pub component_program: AccountInfo<'info>,
     /// CHECK: component program data check
     pub component_program_data: AccountInfo<'info>,
-    #[account()]
+    #[account(has_one = world @ WorldError::EntityWorldMismatch)]
     pub entity: Account<'info, Entity>,
-    #[account(mut)]
+    #[account(mut, owner = component_program.key() @ WorldError::ComponentOwnerMismatch)]
     /// CHECK: component data check
     pub component: UncheckedAccount<'info>,
-    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
-    /// CHECK: instruction sysvar check
-    pub instruction_sysvar_account: UncheckedAccount<'info>,
+    #[account(seeds = [cpi_auth::SEED, component_program.key().as_ref()], bump)]
+    /// CHECK: The signer of the CPI into the component program
+    pub cpi_auth: UncheckedAccount<'info>,
+    #[account(seeds = [world_authority::MODERATOR_SEED, world.key().as_ref()], bump)]
+    /// CHECK: The authority the world signs with for its moderators
+    pub moderator_authority: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
+    #[account()]
+    pub world: Account<'info, World>,
 }
 
 impl<'info> DestroyComponent<'info> {
-    pub fn build(
+    /// The world signs with its moderator authority for the moderators.
+    pub fn build<'a>(
         &self,
-    ) -> CpiContext<'_, '_, '_, 'info, bolt_component::cpi::accounts::Destroy<'info>> {
+        moderator: bool,
+        signer_seeds: &'a [&'a [&'a [u8]]],
+    ) -> CpiContext<'a, 'a, 'a, 'info, bolt_component::cpi::accounts::Destroy<'info>> {
         let cpi_program = self.component_program.to_account_info();
 
         let cpi_accounts = bolt_component::cpi::accounts::Destroy {
-            authority: self.authority.to_account_info(),
+            authority: if moderator {
+                self.moderator_authority.to_account_info()
+            } else {
+                self.authority.to_account_info()
+            },
+            receiver: self.receiver.to_account_info(),
+            entity: self.entity.to_account_info(),
+            component: self.component.to_account_info(),
+            component_program_data: self.component_program_data.to_account_info(),
+            cpi_auth: self.cpi_auth.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        };
+        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds)
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(extra_seed: Option<Vec<u8>>)]
+pub struct DestroyEntity<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: receiver check
+    pub receiver: AccountInfo<'info>,
+    #[account(mut, close = receiver, seeds = [Entity::seed(), &world.id.to_be_bytes(),
+    &match extra_seed {
+        Some(ref _seed) => [0; 8],
+        None => entity.id.to_be_bytes()
+    },
+    match extra_seed {
+        Some(ref seed) => seed,
+        None => &[],
+    }], bump)]
+    pub entity: Account<'info, Entity>,
+    #[account()]
+    pub world: Account<'info, World>,
+    #[account(seeds = [world_authority::MODERATOR_SEED, world.key().as_ref()], bump)]
+    /// CHECK: The authority the world signs with for its moderators
+    pub moderator_authority: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DestroyEntity<'info> {
+    /// The world signs with its moderator authority for the moderators.
+    pub fn build<'a>(
+        &self,
+        component_program: AccountInfo<'info>,
+        component_program_data: AccountInfo<'info>,
+        component: AccountInfo<'info>,
+        cpi_auth: AccountInfo<'info>,
+        moderator: bool,
+        signer_seeds: &'a [&'a [&'a [u8]]],
+    ) -> CpiContext<'a, 'a, 'a, 'info, bolt_component::cpi::accounts::Destroy<'info>> {
+        let cpi_accounts = bolt_component::cpi::accounts::Destroy {
+            authority: if moderator {
+                self.moderator_authority.to_account_info()
+            } else {
+                self.authority.to_account_info()
+            },
             receiver: self.receiver.to_account_info(),
             entity: self.entity.to_account_info(),
+            component,
+            component_program_data,
+            cpi_auth,
+            system_program: self.system_program.to_account_info(),
+        };
+        CpiContext::new_with_signer(component_program, cpi_accounts, signer_seeds)
+    }
+}
+
+#[derive(Accounts)]
+pub struct WithdrawVault<'info> {
+    pub authority: Signer<'info>,
+    #[account()]
+    pub world: Account<'info, World>,
+    #[account(mut, seeds = [vault::SEED, world.key().as_ref()], bump)]
+    pub vault: SystemAccount<'info>,
+    /// CHECK: Any account receiving the lamports
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct TransferEntity<'info> {
+    #[account()]
+    pub owner: Signer<'info>,
+    /// CHECK: The new owner of the entity
+    pub new_owner: AccountInfo<'info>,
+    #[account(mut, has_one = world @ WorldError::EntityWorldMismatch)]
+    pub entity: Account<'info, Entity>,
+    #[account()]
+    pub world: Account<'info, World>,
+    #[account(seeds = [world_authority::MODERATOR_SEED, world.key().as_ref()], bump)]
+    /// CHECK: The authority the world signs with for its admins and moderators
+    pub moderator_authority: UncheckedAccount<'info>,
+}
+
+impl<'info> TransferEntity<'info> {
+    /// The world signs with its moderator authority for the admins and moderators, so that the
+    /// components held by the world program are handed over as well.
+    pub fn build<'a>(
+        &self,
+        component_program: AccountInfo<'info>,
+        component_program_data: AccountInfo<'info>,
+        component: AccountInfo<'info>,
+        cpi_auth: AccountInfo<'info>,
+        privileged: bool,
+        signer_seeds: &'a [&'a [&'a [u8]]],
+    ) -> CpiContext<'a, 'a, 'a, 'info, bolt_component::cpi::accounts::SetAuthority<'info>> {
+        let cpi_accounts = bolt_component::cpi::accounts::SetAuthority {
+            component,
+            authority: self.owner.to_account_info(),
+            component_program_data,
+            cpi_auth,
+        };
+        let cpi_context =
+            CpiContext::new_with_signer(component_program, cpi_accounts, signer_seeds);
+        if privileged {
+            cpi_context.with_remaining_accounts(vec![self.moderator_authority.to_account_info()])
+        } else {
+            cpi_context
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct SetComponentAuthority<'info> {
+    #[account()]
+    pub authority: Signer<'info>,
+    /// CHECK: component program check
+    pub component_program: AccountInfo<'info>,
+    /// CHECK: component program data check
+    pub component_program_data: AccountInfo<'info>,
+    #[account(mut, owner = component_program.key() @ WorldError::ComponentOwnerMismatch)]
+    /// CHECK: component data check
+    pub component: UncheckedAccount<'info>,
+    #[account(seeds = [cpi_auth::SEED, component_program.key().as_ref()], bump)]
+    /// CHECK: The signer of the CPI into the component program
+    pub cpi_auth: UncheckedAccount<'info>,
+}
+
+impl<'info> SetComponentAuthority<'info> {
+    pub fn build<'a>(
+        &self,
+        signer_seeds: &'a [&'a [&'a [u8]]],
+    ) -> CpiContext<'a, 'a, 'a, 'info, bolt_component::cpi::accounts::SetAuthority<'info>> {
+        let cpi_program = self.component_program.to_account_info();
+
+        let cpi_accounts = bolt_component::cpi::accounts::SetAuthority {
+            component: self.component.to_account_info(),
+            authority: self.authority.to_account_info(),
+            component_program_data: self.component_program_data.to_account_info(),
+            cpi_auth: self.cpi_auth.to_account_info(),
+        };
+        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds)
+    }
+}
+
+#[derive(Accounts)]
+pub struct MigrateComponent<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, owner = component_program.key() @ WorldError::ComponentOwnerMismatch)]
+    /// CHECK: component data check
+    pub component: UncheckedAccount<'info>,
+    /// CHECK: component program check
+    pub component_program: AccountInfo<'info>,
+    /// CHECK: component program data check
+    pub component_program_data: AccountInfo<'info>,
+    #[account()]
+    pub entity: Account<'info, Entity>,
+    pub authority: Signer<'info>,
+    #[account(seeds = [cpi_auth::SEED, component_program.key().as_ref()], bump)]
+    /// CHECK: The signer of the CPI into the component program
+    pub cpi_auth: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateComponent<'info> {
+    pub fn build<'a>(
+        &self,
+        signer_seeds: &'a [&'a [&'a [u8]]],
+    ) -> CpiContext<'a, 'a, 'a, 'info, bolt_component::cpi::accounts::Migrate<'info>> {
+        let cpi_program = self.component_program.to_account_info();
+
+        let cpi_accounts = bolt_component::cpi::accounts::Migrate {
+            payer: self.payer.to_account_info(),
             component: self.component.to_account_info(),
+            entity: self.entity.to_account_info(),
+            authority: self.authority.to_account_info(),
             component_program_data: self.component_program_data.to_account_info(),
-            instruction_sysvar_account: self.instruction_sysvar_account.to_account_info(),
+            cpi_auth: self.cpi_auth.to_account_info(),
             system_program: self.system_program.to_account_info(),
         };
-        CpiContext::new(cpi_program, cpi_accounts)
+        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds)
     }
 }
 
@@ -626,6 +2554,8 @@ pub struct World {
     pub authorities: Vec<Pubkey>,
     pub permissionless: bool,
     pub systems: Vec<u8>,
+    pub roles: AuthorityRoles,
+    pub config: WorldConfig,
 }
 
 impl Default for World {
@@ -636,13 +2566,39 @@ impl Default for World {
             authorities: Vec::new(),
             permissionless: true,
             systems: Vec::new(),
+            roles: AuthorityRoles::default(),
+            config: WorldConfig::default(),
         }
     }
 }
 
 impl World {
     fn space_for_authorities(auths: usize, systems_space: usize) -> usize {
-        16 + 8 + 32 * auths + 1 + 8 + systems_space
+        16 + 8 + 32 * auths + 1 + 8 + systems_space + 4 + auths + 1 + 4
+    }
+
+    pub fn space(&self) -> usize {
+        Self::space_for_authorities(self.authorities.len(), self.systems.len())
+            + 32 * self.config.paused_systems.len()
+    }
+
+    /// The role bitmask of the authority, empty if it is not an authority of the world.
+    pub fn roles_of(&self, authority: &Pubkey) -> u8 {
+        self.authorities
+            .iter()
+            .position(|key| key == authority)
+            .map(|index| self.roles.0.get(index).copied().unwrap_or(roles::ALL))
+            .unwrap_or(0)
+    }
+
+    pub fn has_role(&self, authority: &Pubkey, role: u8) -> bool {
+        self.roles_of(authority) & role == role
+    }
+
+    /// Stores a role bitmask for every authority, the authorities added before the roles have
+    /// all of them.
+    fn normalize_roles(&mut self) {
+        self.roles.0.resize(self.authorities.len(), roles::ALL);
     }
 
     pub fn systems(&self) -> WorldSystems {
@@ -653,14 +2609,115 @@ impl World {
     }
 }
 
-#[derive(
-    anchor_lang::prelude::borsh::BorshSerialize,
-    anchor_lang::prelude::borsh::BorshDeserialize,
-    Default,
-    Debug,
-)]
+/// The role bitmask of each world authority, in the order of the authorities.
+#[derive(AnchorSerialize, Default, Clone, Debug)]
+pub struct AuthorityRoles(pub Vec<u8>);
+
+impl AnchorDeserialize for AuthorityRoles {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        // The roles are the last field of the world, the worlds created before them end with
+        // the systems
+        let mut len = [0u8; 4];
+        if reader.read(&mut len[..1])? == 0 {
+            return Ok(Self::default());
+        }
+        reader.read_exact(&mut len[1..])?;
+        let mut roles = vec![0u8; u32::from_le_bytes(len) as usize];
+        reader.read_exact(&mut roles)?;
+        Ok(Self(roles))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WorldState {
+    #[default]
+    Active,
+    /// Only the paused systems of the world run.
+    Paused,
+    /// Nothing runs.
+    Frozen,
+}
+
+/// The state of the world, and the systems which keep running while it is paused.
+#[derive(AnchorSerialize, Default, Clone, Debug)]
+pub struct WorldConfig {
+    pub state: WorldState,
+    pub paused_systems: Vec<Pubkey>,
+}
+
+impl AnchorDeserialize for WorldConfig {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        // The worlds created before the configuration are active
+        let mut state = [0u8; 1];
+        if reader.read(&mut state)? == 0 {
+            return Ok(Self::default());
+        }
+        Ok(Self {
+            state: WorldState::deserialize(&mut state.as_slice())?,
+            paused_systems: Vec::deserialize_reader(reader)?,
+        })
+    }
+}
+
+#[derive(anchor_lang::prelude::borsh::BorshSerialize, Default, Debug)]
 pub struct WorldSystems {
     pub approved_systems: BTreeSet<Pubkey>,
+    /// The component programs each system may write. Systems without an entry write any
+    /// component.
+    pub component_permissions: BTreeMap<Pubkey, BTreeSet<Pubkey>>,
+}
+
+impl anchor_lang::prelude::borsh::BorshDeserialize for WorldSystems {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let approved_systems = BTreeSet::deserialize_reader(reader)?;
+        // The systems approved before the permissions are encoded without them
+        let mut len = [0u8; 4];
+        if reader.read(&mut len[..1])? == 0 {
+            return Ok(Self {
+                approved_systems,
+                ..Default::default()
+            });
+        }
+        reader.read_exact(&mut len[1..])?;
+        let mut component_permissions = BTreeMap::new();
+        for _ in 0..u32::from_le_bytes(len) {
+            let system = Pubkey::deserialize_reader(reader)?;
+            component_permissions.insert(system, BTreeSet::deserialize_reader(reader)?);
+        }
+        Ok(Self {
+            approved_systems,
+            component_permissions,
+        })
+    }
+}
+
+/// A system invocation of an `apply_batch` instruction.
+///
+/// The remaining accounts of the instruction hold, for every batched system and in order, the
+/// system program followed by `components` (program, component) pairs and `extra_accounts`
+/// extra accounts. The counts take the place of the world program id ending the components, while
+/// the pairs marking an absent optional component or the end of a component set are counted. The
+/// signers of the programs of the written components follow the accounts of the last system.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BatchedSystem {
+    pub components: u8,
+    pub extra_accounts: u8,
+    pub args: Vec<u8>,
+}
+
+/// A component handed over by `transfer_entity`, with the seeds it is derived from along with the
+/// entity.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TransferredComponent {
+    pub seed: Vec<u8>,
+    pub instance: Option<Vec<u8>>,
+}
+
+/// Emitted when a system is applied to the world, once per system of a batch.
+#[event]
+pub struct SystemApplied {
+    pub world_id: u64,
+    pub system: Pubkey,
 }
 
 impl World {
@@ -677,16 +2734,101 @@ impl World {
     }
 }
 
-#[account]
-#[derive(InitSpace, Default, Copy)]
+/// An entity of a world. The account traits are implemented by hand rather than with `#[account]`,
+/// so that the entities created before the owner and the world still deserialize.
+#[derive(AnchorSerialize, InitSpace, Default, Clone, Copy)]
 pub struct Entity {
     pub id: u64,
+    /// The player the entity belongs to. Entities created before ownership have no owner.
+    pub owner: Option<Pubkey>,
+    /// The world the entity belongs to. Entities created before it have the default key.
+    pub world: Pubkey,
+}
+
+impl AnchorDeserialize for Entity {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        // The entities created before the owner and the world hold their id, zero padded up to
+        // the size of a world
+        let id = u64::deserialize_reader(reader)?;
+        let mut tag = [0u8; 1];
+        let owner = match reader.read(&mut tag)? {
+            0 => None,
+            _ => Option::<Pubkey>::deserialize_reader(&mut std::io::Read::chain(
+                tag.as_slice(),
+                &mut *reader,
+            ))?,
+        };
+        let mut world = [0u8; 32];
+        let mut len = 0;
+        while len < world.len() {
+            match reader.read(&mut world[len..])? {
+                0 => break,
+                read => len += read,
+            }
+        }
+        Ok(Self {
+            id,
+            owner,
+            world: if len == world.len() {
+                Pubkey::new_from_array(world)
+            } else {
+                Pubkey::default()
+            },
+        })
+    }
+}
+
+impl Discriminator for Entity {
+    const DISCRIMINATOR: &'static [u8] = &[46, 157, 161, 161, 254, 46, 79, 24];
+}
+
+impl AccountSerialize for Entity {
+    fn try_serialize<W: std::io::Write>(&self, writer: &mut W) -> Result<()> {
+        writer
+            .write_all(Self::DISCRIMINATOR)
+            .map_err(|_| ErrorCode::AccountDidNotSerialize)?;
+        AnchorSerialize::serialize(self, writer).map_err(|_| ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+
+impl AccountDeserialize for Entity {
+    fn try_deserialize(buf: &mut &[u8]) -> Result<Self> {
+        if !buf.starts_with(Self::DISCRIMINATOR) {
+            return Err(error!(ErrorCode::AccountDiscriminatorMismatch).with_account_name("Entity"));
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        let mut data = &buf[Self::DISCRIMINATOR.len()..];
+        AnchorDeserialize::deserialize(&mut data)
+            .map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+
+impl Owner for Entity {
+    fn owner() -> Pubkey {
+        crate::ID
+    }
 }
 
 impl Entity {
     pub fn seed() -> &'static [u8] {
         b"entity"
     }
+
+    pub fn size() -> usize {
+        8 + Entity::INIT_SPACE
+    }
+
+    /// The entity of the world derived from the extra seed.
+    pub fn pda_with_seed(world_id: u64, extra_seed: &[u8]) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Entity::seed(), &world_id.to_be_bytes(), &[0; 8], extra_seed],
+            &crate::ID,
+        )
+    }
 }
 
 #[account]
@@ -703,42 +2845,98 @@ impl SystemWhitelist {
     }
 }
 
-/// Builds the context for updating a component.
-pub fn build_update_context<'info>(
+/// The signer of the CPIs into a component program, passed along the accounts of the instructions
+/// writing components of several programs.
+#[derive(Clone)]
+pub struct CpiAuth<'info> {
+    pub account: AccountInfo<'info>,
+    pub component_program: Pubkey,
+    bump: [u8; 1],
+}
+
+impl<'info> CpiAuth<'info> {
+    /// Checks the account is the signer of the component program.
+    pub fn new(account: AccountInfo<'info>, component_program: &Pubkey) -> Result<Self> {
+        let (key, bump) = cpi_auth::pda(component_program);
+        if *account.key != key {
+            return Err(ErrorCode::ConstraintSeeds.into());
+        }
+        Ok(Self {
+            account,
+            component_program: *component_program,
+            bump: [bump],
+        })
+    }
+
+    /// Finds the signer of the component program among the accounts.
+    pub fn find(accounts: &[AccountInfo<'info>], component_program: &Pubkey) -> Result<Self> {
+        let (key, bump) = cpi_auth::pda(component_program);
+        Ok(Self {
+            account: find_account(accounts, &key)?,
+            component_program: *component_program,
+            bump: [bump],
+        })
+    }
+
+    /// The signer of the component program among the signers already found.
+    fn of<'a>(cpi_auths: &'a [Self], component_program: &Pubkey) -> Result<&'a Self> {
+        cpi_auths
+            .iter()
+            .find(|cpi_auth| cpi_auth.component_program == *component_program)
+            .ok_or_else(|| ErrorCode::AccountNotEnoughKeys.into())
+    }
+
+    pub fn seeds(&self) -> [&[u8]; 3] {
+        cpi_auth::seeds(&self.component_program, &self.bump)
+    }
+}
+
+/// Builds the context for updating a component, signed by the world.
+pub fn build_update_context<'a, 'b, 'c, 'info>(
     component_program: AccountInfo<'info>,
     bolt_component: AccountInfo<'info>,
     authority: Signer<'info>,
-    instruction_sysvar_account: UncheckedAccount<'info>,
-) -> CpiContext<'info, 'info, 'info, 'info, bolt_component::cpi::accounts::Update<'info>> {
+    cpi_auth: AccountInfo<'info>,
+    world: AccountInfo<'info>,
+    system_program: Program<'info, System>,
+    signer_seeds: &'a [&'b [&'c [u8]]],
+) -> CpiContext<'a, 'b, 'c, 'info, bolt_component::cpi::accounts::Update<'info>> {
     let authority = authority.to_account_info();
-    let instruction_sysvar_account = instruction_sysvar_account.to_account_info();
-    let cpi_program = component_program;
-    bolt_component::cpi::accounts::Update {
+    // The authority pays for the components which grow
+    let payer_accounts = vec![authority.clone(), system_program.to_account_info()];
+    let cpi_accounts = bolt_component::cpi::accounts::Update {
         bolt_component,
         authority,
-        instruction_sysvar_account,
-    }
-    .build_cpi_context(cpi_program)
+        cpi_auth,
+        world,
+    };
+    CpiContext::new_with_signer(component_program, cpi_accounts, signer_seeds)
+        .with_remaining_accounts(payer_accounts)
 }
 
-/// Builds the context for updating a component.
-pub fn build_update_context_with_session<'info>(
+/// Builds the context for updating a component, signed by the world.
+#[allow(clippy::too_many_arguments)]
+pub fn build_update_context_with_session<'a, 'b, 'c, 'info>(
     component_program: AccountInfo<'info>,
     bolt_component: AccountInfo<'info>,
     authority: Signer<'info>,
-    instruction_sysvar_account: UncheckedAccount<'info>,
+    cpi_auth: AccountInfo<'info>,
+    world: AccountInfo<'info>,
     session_token: UncheckedAccount<'info>,
-) -> CpiContext<'info, 'info, 'info, 'info, bolt_component::cpi::accounts::UpdateWithSession<'info>>
-{
+    system_program: Program<'info, System>,
+    signer_seeds: &'a [&'b [&'c [u8]]],
+) -> CpiContext<'a, 'b, 'c, 'info, bolt_component::cpi::accounts::UpdateWithSession<'info>> {
     let authority = authority.to_account_info();
-    let instruction_sysvar_account = instruction_sysvar_account.to_account_info();
-    let cpi_program = component_program;
     let session_token = session_token.to_account_info();
-    bolt_component::cpi::accounts::UpdateWithSession {
+    // The authority pays for the components which grow
+    let payer_accounts = vec![authority.clone(), system_program.to_account_info()];
+    let cpi_accounts = bolt_component::cpi::accounts::UpdateWithSession {
         bolt_component,
         authority,
-        instruction_sysvar_account,
+        cpi_auth,
         session_token,
-    }
-    .build_cpi_context(cpi_program)
+        world,
+    };
+    CpiContext::new_with_signer(component_program, cpi_accounts, signer_seeds)
+        .with_remaining_accounts(payer_accounts)
 }
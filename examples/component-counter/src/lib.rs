@@ -0,0 +1,9 @@
+use bolt_lang::*;
+
+declare_id!("F15UGbWxutwdwUGTBH6FRaGMpnR5d3Ff6kiP59agwyz");
+
+#[component]
+#[derive(Default)]
+pub struct Counter {
+    pub count: u64,
+}
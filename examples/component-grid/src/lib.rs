@@ -0,0 +1,8 @@
+use bolt_lang::*;
+
+declare_id!("9A97iVkq8BapbkmBdq5iRgE6gWz3vfQxYKswA6CzBZCy");
+
+#[component(zero_copy)]
+pub struct Grid {
+    pub cells: [u8; 2048],
+}
@@ -5,5 +5,5 @@ declare_id!("9yBADAhoTWCkNRB6hbfpwUgPpxyJiF9uEiWVPR6k7A4y");
 #[component]
 #[derive(Default)]
 pub struct Small {
-    // Just the BoltMetadata which is 32 bytes
+    // Just the BoltMetadata which is 97 bytes
 }
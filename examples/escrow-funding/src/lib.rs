@@ -26,7 +26,7 @@ pub mod escrow_funding {
         pub receiver: Small,
     }
 
-    #[arguments]
+    #[arguments(idl)]
     pub struct Args {
         amount: u64,
     }
@@ -0,0 +1,28 @@
+use bolt_lang::*;
+
+declare_id!("DJv8id4Mx18rnN7rK2FsJZcDrrJh3qKk4RiuaCuQh9jP");
+
+#[system]
+pub mod system_borsh_movement {
+    pub fn execute(ctx: Context<Components>, args: Args) -> Result<Components> {
+        ctx.accounts.position.x += args.dx;
+        ctx.accounts.position.y += args.dy;
+        ctx.accounts.position.z += args.dz;
+
+        Ok(ctx.accounts)
+    }
+
+    #[system_input]
+    pub struct Components {
+        #[component_id("Fn1JzzEdyb55fsyduWS94mYHizGhJZuhvjX6DVvrmGbQ")]
+        pub position: Position,
+    }
+
+    // The arguments are decoded with Borsh, and their type is part of the IDL
+    #[arguments(borsh)]
+    pub struct Args {
+        pub dx: i64,
+        pub dy: i64,
+        pub dz: i64,
+    }
+}
@@ -0,0 +1,20 @@
+use bolt_lang::*;
+use counter::Counter;
+
+declare_id!("Hy8GbqP3Teyn6JJferxHfrnXrnaX5mrDYXuongBkrj6T");
+
+#[system]
+pub mod system_counter {
+
+    pub fn execute(ctx: Context<Components>, _args: Vec<u8>) -> Result<Components> {
+        ctx.accounts.counter.count += 1;
+        Ok(ctx.accounts)
+    }
+
+    #[system_input]
+    pub struct Components {
+        // The counter of the world, rather than of an entity
+        #[resource]
+        pub counter: Counter,
+    }
+}
@@ -0,0 +1,23 @@
+use bolt_lang::*;
+use velocity::Velocity;
+
+declare_id!("B8nV59VEp8AUV5Aevsvp7jsH21p1GWTYUDBu3mURvG5o");
+
+#[system]
+pub mod system_describe {
+
+    pub fn execute(ctx: Context<Components>, args: Args) -> Result<Components> {
+        ctx.accounts.velocity.description = args.description;
+        Ok(ctx.accounts)
+    }
+
+    #[system_input]
+    pub struct Components {
+        pub velocity: Velocity,
+    }
+
+    #[arguments]
+    struct Args {
+        description: String,
+    }
+}
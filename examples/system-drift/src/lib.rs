@@ -0,0 +1,26 @@
+use bolt_lang::*;
+use position::Position;
+use velocity::Velocity;
+
+declare_id!("HSpFVFdQ8q5ZvfuzUiMs5n5EGC8L8qcT3xbaa2XUUKNN");
+
+#[system]
+pub mod system_drift {
+
+    pub fn execute(ctx: Context<Components>, _args: Vec<u8>) -> Result<Components> {
+        let step = ctx
+            .accounts
+            .velocity
+            .as_ref()
+            .map_or(1, |velocity| velocity.x);
+        ctx.accounts.position.x += step;
+        Ok(ctx.accounts)
+    }
+
+    #[system_input]
+    pub struct Components {
+        pub position: Position,
+        #[read_only]
+        pub velocity: Option<Velocity>,
+    }
+}
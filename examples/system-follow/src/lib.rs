@@ -0,0 +1,23 @@
+use bolt_lang::*;
+use position::Position;
+
+declare_id!("5fb15525CgYNroT67nJW2wwgawiVpjhGsp4RqSgviMsC");
+
+#[system]
+pub mod system_follow {
+
+    pub fn execute(ctx: Context<Components>, _args: Vec<u8>) -> Result<Components> {
+        ctx.accounts.position.x = ctx.accounts.target.x;
+        ctx.accounts.position.y = ctx.accounts.target.y;
+        ctx.accounts.position.z = ctx.accounts.target.z;
+        Ok(ctx.accounts)
+    }
+
+    #[system_input]
+    pub struct Components {
+        pub position: Position,
+        // The target is only read, and is not written back to the world
+        #[read_only]
+        pub target: Position,
+    }
+}
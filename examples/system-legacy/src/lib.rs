@@ -0,0 +1,32 @@
+use bolt_lang::*;
+use position::Position;
+
+declare_id!("J4mW9s8iq2abdTTbawT8uY8ZgTcPv5CfkttCh9N5eu9S");
+
+/// A system with the `bolt_execute` account layout of the systems deployed before the world was
+/// passed to them: the authority only, and the components at the front of the remaining accounts.
+/// It stands for these systems in the tests, which apply it to check that they keep working.
+#[program]
+pub mod system_legacy {
+    use super::*;
+
+    pub fn bolt_execute<'info>(
+        ctx: Context<'_, '_, 'info, 'info, LegacyBoltComponents<'info>>,
+        _args: Vec<u8>,
+    ) -> Result<Vec<Vec<u8>>> {
+        let account = ctx
+            .remaining_accounts
+            .first()
+            .ok_or(ErrorCode::AccountNotEnoughKeys)?;
+        let mut position = Account::<Position>::try_from(account)?;
+        position.x += 1;
+        Ok(vec![position.try_to_vec()?])
+    }
+}
+
+#[derive(Accounts)]
+pub struct LegacyBoltComponents<'info> {
+    /// CHECK: Authority check
+    #[account()]
+    pub authority: AccountInfo<'info>,
+}
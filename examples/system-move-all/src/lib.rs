@@ -0,0 +1,20 @@
+use bolt_lang::*;
+use position::Position;
+
+declare_id!("GQ6HEFmGfwKVJdfoQgc3aQgG9sDug866La4kyuLTzjdM");
+
+#[system]
+pub mod system_move_all {
+
+    pub fn execute(ctx: Context<Components>, _args: Vec<u8>) -> Result<Components> {
+        for position in ctx.accounts.positions.iter_mut() {
+            position.x += 1;
+        }
+        Ok(ctx.accounts)
+    }
+
+    #[system_input]
+    pub struct Components {
+        pub positions: Vec<Position>,
+    }
+}
@@ -0,0 +1,25 @@
+use bolt_lang::*;
+use grid::Grid;
+
+declare_id!("AYqq1FadzBiPVmT3D8gFe2p75xWErBgBLPjesm6HmZ1B");
+
+#[system]
+pub mod system_paint {
+
+    pub fn execute(ctx: Context<Components>, args: Args) -> Result<Components> {
+        ctx.accounts.grid.load_mut()?.cells[args.cell as usize] = args.color;
+        Ok(ctx.accounts)
+    }
+
+    #[system_input]
+    pub struct Components {
+        #[zero_copy]
+        pub grid: Grid,
+    }
+
+    #[arguments]
+    struct Args {
+        cell: u16,
+        color: u8,
+    }
+}
@@ -0,0 +1,46 @@
+use bolt_lang::*;
+use position::Position;
+use velocity::Velocity;
+
+declare_id!("4q8Q2jNCYfPubFtfsGCCtA9rNpm7zNnoXhFNh7eefNqp");
+
+#[system]
+pub mod system_spawn {
+
+    pub fn execute(ctx: Context<Components>, args: Args) -> Result<Components> {
+        // The velocity is spawned on, or despawned from, the entity of the position
+        let entity = ctx.accounts.position.bolt_metadata.entity;
+        let instance = Some(args.instance.into_bytes());
+        let mut commands = Commands::new();
+        match args.action {
+            Action::Spawn => {
+                let velocity = Velocity {
+                    x: 1,
+                    ..Default::default()
+                };
+                commands.initialize(entity, instance, &velocity)?;
+            }
+            Action::Despawn => {
+                commands.destroy::<Velocity>(entity, instance);
+            }
+        }
+        Ok(ctx.accounts.with_commands(commands)?)
+    }
+
+    #[system_input]
+    pub struct Components {
+        pub position: Position,
+    }
+
+    #[arguments]
+    struct Args {
+        action: Action,
+        instance: String,
+    }
+
+    #[arguments]
+    pub enum Action {
+        Spawn,
+        Despawn,
+    }
+}
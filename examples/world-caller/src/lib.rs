@@ -0,0 +1,77 @@
+use bolt_lang::*;
+
+declare_id!("k2KN9zWiNErwRRtXX2qngDsg2vVZh9tfNQS71mBd7T3");
+
+/// A game program composing the world: it applies a system through a CPI into the world, as
+/// part of an instruction of its own.
+#[program]
+pub mod world_caller {
+    use super::*;
+
+    pub fn apply_system<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ApplySystem<'info>>,
+        args: Vec<u8>,
+    ) -> Result<()> {
+        ctx.accounts.apply(ctx.remaining_accounts, args)
+    }
+
+    /// Applies a system through the world, then writes `data` to the first component with the
+    /// signer of its program passed through. Only the world signs for it, within its own CPIs, so
+    /// the program calling the world can't re-use it and the update fails.
+    pub fn apply_system_and_update<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ApplySystem<'info>>,
+        args: Vec<u8>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        ctx.accounts.apply(ctx.remaining_accounts, args)?;
+        let [component_program, component, ..] = ctx.remaining_accounts else {
+            return Err(ErrorCode::AccountNotEnoughKeys.into());
+        };
+        let (cpi_auth, _) = world::cpi_auth::pda(component_program.key);
+        let cpi_auth = ctx
+            .remaining_accounts
+            .iter()
+            .find(|account| *account.key == cpi_auth)
+            .ok_or(ErrorCode::AccountNotEnoughKeys)?;
+        let cpi_accounts = bolt_component::cpi::accounts::Update {
+            bolt_component: component.clone(),
+            authority: ctx.accounts.authority.to_account_info(),
+            cpi_auth: cpi_auth.clone(),
+            world: ctx.accounts.world.to_account_info(),
+        };
+        bolt_component::cpi::update(
+            CpiContext::new(component_program.clone(), cpi_accounts),
+            data,
+        )
+    }
+}
+
+#[derive(Accounts)]
+pub struct ApplySystem<'info> {
+    pub world_program: Program<'info, World>,
+    /// CHECK: checked by the world program
+    pub bolt_system: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: checked by the world program
+    pub world: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ApplySystem<'info> {
+    fn apply(&self, remaining_accounts: &[AccountInfo<'info>], args: Vec<u8>) -> Result<()> {
+        let cpi_accounts = world::cpi::accounts::Apply {
+            bolt_system: self.bolt_system.to_account_info(),
+            authority: self.authority.to_account_info(),
+            world: self.world.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        };
+        // The components, the extra accounts of the system and the signers of the component
+        // programs are passed through as they are
+        world::cpi::apply(
+            CpiContext::new(self.world_program.to_account_info(), cpi_accounts)
+                .with_remaining_accounts(remaining_accounts.to_vec()),
+            args,
+        )
+    }
+}